@@ -1,8 +1,10 @@
-use crate::db::database_article_data::ShortArticleData;
+use crate::application::page_archive::archive;
+use crate::db::database_article_data::{ArticleListData, ArticleListSort};
 use crate::system::server::TheState;
 use askama::Template;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::response::{Html, IntoResponse, Redirect, Response};
+use serde::Deserialize;
 use std::fs;
 use thiserror::Error;
 use tracing::{debug, info};
@@ -16,6 +18,9 @@ pub enum AdminArticleError {
     #[error("database error: {0}")]
     Database(String),
 
+    #[error("invalid sort {0}")]
+    InvalidSort(String),
+
     #[error("unauthorized")]
     Unauthorized,
 
@@ -26,21 +31,62 @@ pub enum AdminArticleError {
 #[derive(Template)]
 #[template(path = "application/admin_form_article/admin_article_template.html")]
 pub struct AdminArticlesTemplate {
-    pub articles: Vec<ShortArticleData>,
+    pub articles: Vec<ArticleListData>,
+    pub sort: ArticleListSort,
+    pub descending: bool,
     pub date: String,
     pub name_day: String,
     pub weather: String,
     pub username: String,
 }
 
+#[derive(Deserialize)]
+pub struct AdminArticlesQuery {
+    // created, updated, words or title, the newest first if missing
+    pub sort: Option<String>,
+    // asc or desc, the natural order of the column if missing
+    pub order: Option<String>,
+}
+
+impl AdminArticlesTemplate {
+    /**
+     * the column header link, clicking the current column again reverses the order
+     */
+    pub fn sort_url(&self, name: &str) -> String {
+        let descending = ArticleListSort::from_name(name).is_some_and(|sort| {
+            if sort == self.sort { !self.descending } else { sort.descending_by_default() }
+        });
+        format!("/admin_article?sort={}&order={}", name, if descending { "desc" } else { "asc" })
+    }
+
+    pub fn sort_mark(&self, name: &str) -> &'static str {
+        match (self.sort.name() == name, self.descending) {
+            (false, _) => "",
+            (true, true) => " ▼",
+            (true, false) => " ▲",
+        }
+    }
+}
+
 pub async fn show_admin_articles(
     auth_session: AuthSession,
     State(state): State<TheState>,
+    Query(query): Query<AdminArticlesQuery>,
 ) -> Result<Response, AdminArticleError> {
-    debug!("show_admin_articles()");
+    debug!("show_admin_articles: sort={:?} order={:?}", query.sort, query.order);
+    let sort = match query.sort.as_deref() {
+        None => ArticleListSort::Created,
+        Some(name) => ArticleListSort::from_name(name).ok_or_else(|| AdminArticleError::InvalidSort(name.into()))?,
+    };
+    let descending = match query.order.as_deref() {
+        None => sort.descending_by_default(),
+        Some("desc") => true,
+        Some("asc") => false,
+        Some(order) => Err(AdminArticleError::InvalidSort(order.into()))?,
+    };
     let articles = state
         .dba
-        .list_all_articles()
+        .list_all_articles(sort, descending)
         .await
         .map_err(|e| AdminArticleError::Database(e.to_string()))?;
 
@@ -52,6 +98,8 @@ pub async fn show_admin_articles(
     Ok(Html(
         AdminArticlesTemplate {
             articles,
+            sort,
+            descending,
             date: state.ds.date(),
             name_day: state.ds.name_day(),
             weather: state.ds.weather(),
@@ -75,11 +123,16 @@ pub async fn handle_delete_article(
     let category = article.category.clone();
 
     #[rustfmt::skip]
-    state.dba.delete_article(&article_file_name).await
+    state.dba.delete_article(article.uuid).await
         .map_err(|e| AdminArticleError::Database(e.to_string()))?;
 
     info!("Admin deleted article: {}", article_file_name);
 
+    // nothing to show in its homepage slots
+    #[rustfmt::skip]
+    state.dbh.delete_slots_of_article(article.uuid).await
+        .map_err(|e| AdminArticleError::Database(e.to_string()))?;
+
     // delete the html file
     let path = format!("web/{}", article.article_file_name);
     let _ = fs::remove_file(path);
@@ -101,15 +154,11 @@ pub async fn handle_delete_article(
     // Invalidate
     state.dv.index_invalidate();
     state.dv.news_invalidate();
+    state.dv.corrections_invalidate();
 
-    match category.as_str() {
-        "zahranici" => state.dv.zahranici_invalidate(),
-        "republika" => state.dv.republika_invalidate(),
-        "finance" => state.dv.finance_invalidate(),
-        "technologie" => state.dv.technologie_invalidate(),
-        "veda" => state.dv.veda_invalidate(),
-        _ => info!("Unknown category for invalidation: {}", category),
-    }
+    state.dv.category_invalidate(&category);
+    archive::invalidate_archive(&state, article.created);
+    state.dv.author_invalidate(&article.username);
 
     Ok(Redirect::to("/admin_article").into_response())
 }
@@ -0,0 +1,144 @@
+use crate::data::library;
+use crate::data::time::from_prague_local_input;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_system::{BreakingNews, Severity, SurrealSystemError};
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::State;
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::Form;
+use chrono::Utc;
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::{debug, info};
+use AdminBreakingNewsError::{ArticleNotPublished, InvalidExpires, InvalidSeverity, TextRequired};
+
+#[derive(Debug, Error)]
+pub enum AdminBreakingNewsError {
+    #[error("breaking news text is required")]
+    TextRequired,
+
+    #[error("unknown severity {0}")]
+    InvalidSeverity(String),
+
+    #[error("article {0} is not published")]
+    ArticleNotPublished(String),
+
+    #[error("invalid expiry time {0}, use a future time")]
+    InvalidExpires(String),
+
+    #[error("surreal system error {0}")]
+    BreakingNewsSurrealSystem(#[from] SurrealSystemError),
+
+    #[error("surreal article error {0}")]
+    BreakingNewsSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("render error")]
+    BreakingNewsRender(#[from] askama::Error),
+}
+
+#[derive(Deserialize)]
+pub struct BreakingNewsPayload {
+    pub text: String,
+    pub article_file_name: String,
+    pub severity: String,
+    // datetime-local input, Prague time
+    pub expires: String,
+}
+
+#[derive(Template)]
+#[template(path = "application/admin_form_breaking_news/admin_breaking_news_template.html")]
+pub struct AdminBreakingNewsTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+    pub username: String,
+    pub current: Option<BreakingNews>,
+    pub expires_display: String,
+}
+
+/**
+ * the only way to change the banner
+ * - every rendered page shows it, all HTML is rendered again
+ */
+pub async fn update_breaking_news(
+    state: &TheState,
+    news: Option<BreakingNews>,
+) -> Result<(), SurrealSystemError> {
+    match &news {
+        Some(news) => state.dbs.write_breaking_news(news.clone()).await?,
+        None => state.dbs.delete_breaking_news().await?,
+    }
+    state.ds.set_breaking_news(news);
+
+    state.dv.invalidate_index_and_categories();
+    state.dbs.invalidate_all_article().await?;
+    Ok(())
+}
+
+pub async fn show_admin_breaking_news(
+    auth_session: AuthSession,
+    State(state): State<TheState>,
+) -> Result<Response, AdminBreakingNewsError> {
+    debug!("show_admin_breaking_news()");
+    let current = state.ds.breaking_news();
+
+    let username = auth_session
+        .user
+        .map(|u| u.username)
+        .unwrap_or_else(|| "unknown".to_string());
+
+    Ok(Html(
+        AdminBreakingNewsTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            username,
+            expires_display: current.as_ref().map_or_else(String::new, |n| library::display_date_time(n.expires)),
+            current,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+pub async fn handle_set_breaking_news(
+    State(state): State<TheState>,
+    Form(payload): Form<BreakingNewsPayload>,
+) -> Result<Response, AdminBreakingNewsError> {
+    debug!("handle_set_breaking_news: {}", payload.article_file_name);
+
+    let text = payload.text.trim().to_string();
+    if text.is_empty() {
+        return Err(TextRequired);
+    }
+    let severity = Severity::from_form(&payload.severity).ok_or_else(|| InvalidSeverity(payload.severity.clone()))?;
+    let expires = from_prague_local_input(&payload.expires)
+        .filter(|expires| *expires > Utc::now())
+        .ok_or_else(|| InvalidExpires(payload.expires.clone()))?;
+
+    let article_file_name = payload.article_file_name.trim().to_string();
+    let published = state
+        .dba
+        .article_by_file_name_optional(&article_file_name)
+        .await?
+        .is_some_and(|article| article.is_published());
+    if !published {
+        return Err(ArticleNotPublished(article_file_name));
+    }
+
+    update_breaking_news(&state, Some(BreakingNews { text, article_file_name, severity, expires })).await?;
+    info!("breaking news set, expires {}", expires);
+
+    Ok(Redirect::to("/admin_breaking_news").into_response())
+}
+
+pub async fn handle_clear_breaking_news(
+    State(state): State<TheState>,
+) -> Result<Response, AdminBreakingNewsError> {
+    debug!("handle_clear_breaking_news()");
+    update_breaking_news(&state, None).await?;
+    info!("breaking news cleared");
+    Ok(Redirect::to("/admin_breaking_news").into_response())
+}
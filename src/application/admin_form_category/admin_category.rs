@@ -0,0 +1,272 @@
+use crate::application::page_category::category;
+use crate::data::slug;
+use crate::db::database_category::Category;
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::{Path, State};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::Form;
+use serde::Deserialize;
+use std::fs;
+use thiserror::Error;
+use tracing::{debug, info};
+
+#[derive(Debug, Error)]
+pub enum AdminCategoryError {
+    #[error("render error: {0}")]
+    Render(#[from] askama::Error),
+
+    #[error("database error: {0}")]
+    Database(String),
+
+    #[error("invalid category slug {0}, use only a-z, 0-9 and -")]
+    InvalidSlug(String),
+
+    #[error("category slug {0} is already used")]
+    SlugTaken(String),
+
+    #[error("category {0} still has articles")]
+    CategoryInUse(String),
+
+    #[error("category not found {0}")]
+    CategoryNotFound(String),
+}
+
+#[derive(Template)]
+#[template(path = "application/admin_form_category/admin_category_template.html")]
+pub struct AdminCategoriesTemplate {
+    pub categories: Vec<Category>,
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+    pub username: String,
+}
+
+#[derive(Template)]
+#[template(path = "application/admin_form_category/admin_category_form_template.html")]
+pub struct CategoryFormTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+    // None for a new category
+    pub category: Option<Category>,
+}
+
+#[derive(Deserialize)]
+pub struct CategoryPayload {
+    // ignored on edit, the slug is the key
+    #[serde(default)]
+    pub slug: String,
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub position: i64,
+    // checkbox, sent only if checked
+    pub in_navigation: Option<String>,
+    #[serde(default)]
+    pub homepage_label: String,
+}
+
+impl CategoryPayload {
+    fn into_category(self, slug: String) -> Category {
+        Category {
+            slug,
+            name: self.name.trim().into(),
+            title: self.title.trim().into(),
+            description: self.description.trim().replace("\r\n", "\n"),
+            position: self.position,
+            in_navigation: self.in_navigation.is_some(),
+            homepage_label: self.homepage_label.trim().into(),
+        }
+    }
+}
+
+pub async fn show_admin_categories(
+    auth_session: AuthSession,
+    State(state): State<TheState>,
+) -> Result<Response, AdminCategoryError> {
+    debug!("show_admin_categories()");
+    let categories =
+        state.dbc.all_categories().await.map_err(|e| AdminCategoryError::Database(e.to_string()))?;
+
+    let username = auth_session
+        .user
+        .map(|u| u.username)
+        .unwrap_or_else(|| "unknown".to_string());
+
+    Ok(Html(
+        AdminCategoriesTemplate {
+            categories,
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            username,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+pub async fn show_create_category_form(
+    State(state): State<TheState>,
+) -> Result<Response, AdminCategoryError> {
+    Ok(Html(
+        CategoryFormTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            category: None,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+pub async fn handle_create_category(
+    State(state): State<TheState>,
+    Form(payload): Form<CategoryPayload>,
+) -> Result<Response, AdminCategoryError> {
+    debug!("handle_create_category: {}", payload.slug);
+
+    let slug = payload.slug.trim().to_string();
+    if slug.is_empty() || slug::slugify(&slug) != slug || slug::is_reserved(&slug) {
+        return Err(AdminCategoryError::InvalidSlug(slug));
+    }
+
+    #[rustfmt::skip]
+    let existing = state.dbc.category_by_slug(&slug).await
+        .map_err(|e| AdminCategoryError::Database(e.to_string()))?;
+    // an article may already live at {slug}.html
+    #[rustfmt::skip]
+    let free = state.dba.unique_base_file_name(&slug, None).await
+        .map_err(|e| AdminCategoryError::Database(e.to_string()))?;
+    if existing.is_some() || free != slug {
+        return Err(AdminCategoryError::SlugTaken(slug));
+    }
+
+    #[rustfmt::skip]
+    state.dbc.create_category(payload.into_category(slug.clone())).await
+        .map_err(|e| AdminCategoryError::Database(e.to_string()))?;
+    info!("Admin created category: {}", slug);
+
+    invalidate_navigation(&state).await?;
+    Ok(Redirect::to("/admin_category").into_response())
+}
+
+pub async fn show_edit_category_form(
+    State(state): State<TheState>,
+    Path(slug): Path<String>,
+) -> Result<Response, AdminCategoryError> {
+    #[rustfmt::skip]
+    let category = state.dbc.category_by_slug(&slug).await
+        .map_err(|e| AdminCategoryError::Database(e.to_string()))?
+        .ok_or(AdminCategoryError::CategoryNotFound(slug))?;
+
+    Ok(Html(
+        CategoryFormTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            category: Some(category),
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+pub async fn handle_edit_category(
+    State(state): State<TheState>,
+    Path(slug): Path<String>,
+    Form(payload): Form<CategoryPayload>,
+) -> Result<Response, AdminCategoryError> {
+    debug!("handle_edit_category: {}", slug);
+
+    #[rustfmt::skip]
+    state.dbc.update_category(payload.into_category(slug.clone())).await
+        .map_err(|e| AdminCategoryError::Database(e.to_string()))?;
+    info!("Admin updated category: {}", slug);
+
+    invalidate_navigation(&state).await?;
+    Ok(Redirect::to("/admin_category").into_response())
+}
+
+pub async fn handle_delete_category(
+    State(state): State<TheState>,
+    Path(slug): Path<String>,
+) -> Result<Response, AdminCategoryError> {
+    debug!("handle_delete_category: {}", slug);
+
+    #[rustfmt::skip]
+    let in_use = state.dba.category_in_use(&slug).await
+        .map_err(|e| AdminCategoryError::Database(e.to_string()))?;
+    if in_use {
+        return Err(AdminCategoryError::CategoryInUse(slug));
+    }
+
+    #[rustfmt::skip]
+    state.dbc.delete_category(&slug).await
+        .map_err(|e| AdminCategoryError::Database(e.to_string()))?;
+    info!("Admin deleted category: {}", slug);
+
+    remove_category_pages(std::path::Path::new("web"), &slug);
+
+    invalidate_navigation(&state).await?;
+    Ok(Redirect::to("/admin_category").into_response())
+}
+
+/*
+ * only the html files of the category pages, {slug}.html, {slug}/2.html, ...
+ * - the directory is removed only if nothing else is in it
+ */
+fn remove_category_pages(web_dir: &std::path::Path, slug: &str) {
+    let _ = fs::remove_file(web_dir.join(format!("{}.html", slug)));
+    let dir = web_dir.join(slug);
+    if let Ok(entries) = fs::read_dir(&dir) {
+        for entry in entries.flatten() {
+            let page = format!("{}/{}", slug, entry.file_name().to_string_lossy());
+            if category::category_page_from_path(&page).is_some() {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+    let _ = fs::remove_dir(&dir);
+}
+
+/*
+ * every page shows the navigation
+ */
+async fn invalidate_navigation(state: &TheState) -> Result<(), AdminCategoryError> {
+    state.dv.invalidate_index_and_categories();
+    #[rustfmt::skip]
+    state.dbs.invalidate_all_article().await
+        .map_err(|e| AdminCategoryError::Database(e.to_string()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_category_pages() {
+        let web_dir = std::env::temp_dir().join(format!("axiomatik-category-{}", std::process::id()));
+        let page_dir = web_dir.join("sport");
+        fs::create_dir_all(&page_dir).unwrap();
+        for file in ["sport.html", "sport/2.html", "sport/3.html", "sport/logo.png"] {
+            fs::write(web_dir.join(file), "").unwrap();
+        }
+
+        remove_category_pages(&web_dir, "sport");
+        assert!(!web_dir.join("sport.html").exists());
+        assert!(!page_dir.join("2.html").exists());
+        assert!(!page_dir.join("3.html").exists());
+        // anything else stays, and so does the directory
+        assert!(page_dir.join("logo.png").exists());
+
+        fs::remove_file(page_dir.join("logo.png")).unwrap();
+        remove_category_pages(&web_dir, "sport");
+        assert!(!page_dir.exists());
+        fs::remove_dir_all(&web_dir).unwrap();
+    }
+}
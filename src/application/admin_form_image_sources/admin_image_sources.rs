@@ -0,0 +1,201 @@
+use crate::data::time;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::ImageCreditData;
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::{Query, State};
+use axum::response::{Html, IntoResponse, Response};
+use chrono::{Months, NaiveDate, Utc};
+use serde::Deserialize;
+use std::iter;
+use thiserror::Error;
+use tracing::debug;
+use AdminImageSourcesError::InvalidMonth;
+
+// images uploaded before the source was required
+const UNKNOWN_SOURCE: &str = "neuvedeno";
+
+#[derive(Debug, Error)]
+pub enum AdminImageSourcesError {
+    #[error("invalid month {0}, use YYYY-MM")]
+    InvalidMonth(String),
+
+    #[error("surreal article error {0}")]
+    ImageSourcesSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("render error")]
+    ImageSourcesRender(#[from] askama::Error),
+}
+
+#[derive(Deserialize)]
+pub struct ImageSourcesQuery {
+    // YYYY-MM, the current Prague month if missing
+    pub month: Option<String>,
+}
+
+/**
+ * images of one agency or license published in the month
+ */
+#[derive(Debug, PartialEq, Eq)]
+pub struct SourceReport {
+    pub source: String,
+    pub images: u32,
+    // article file names
+    pub articles: Vec<String>,
+    // photographers, each only once
+    pub credits: Vec<String>,
+}
+
+impl SourceReport {
+    pub fn credits_display(&self) -> String {
+        self.credits.join(", ")
+    }
+}
+
+#[derive(Template)]
+#[template(path = "application/admin_form_image_sources/admin_image_sources_template.html")]
+pub struct AdminImageSourcesTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+    pub username: String,
+    pub month: String,
+    pub sources: Vec<SourceReport>,
+    pub images_total: u32,
+}
+
+/**
+ * images of published Articles by source, agencies bill every used photo
+ */
+pub async fn show_admin_image_sources(
+    auth_session: AuthSession,
+    State(state): State<TheState>,
+    Query(query): Query<ImageSourcesQuery>,
+) -> Result<Response, AdminImageSourcesError> {
+    debug!("show_admin_image_sources: month={:?}", query.month);
+    let month = query
+        .month
+        .map(|month| month.trim().to_string())
+        .filter(|month| !month.is_empty())
+        .unwrap_or_else(|| time::to_prague_time(Utc::now()).format("%Y-%m").to_string());
+
+    let (from, to) = month_range(&month)
+        .and_then(|(first, after)| Some((time::prague_midnight(first)?, time::prague_midnight(after)?)))
+        .ok_or_else(|| InvalidMonth(month.clone()))?;
+    let sources = source_report(&state.dba.image_credits_between(from, to).await?);
+
+    let username = auth_session
+        .user
+        .map(|u| u.username)
+        .unwrap_or_else(|| "unknown".to_string());
+
+    Ok(Html(
+        AdminImageSourcesTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            username,
+            month,
+            images_total: sources.iter().map(|source| source.images).sum(),
+            sources,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+/*
+ * the first day of the month and of the following one
+ */
+fn month_range(month: &str) -> Option<(NaiveDate, NaiveDate)> {
+    let first = NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d").ok()?;
+    Some((first, first.checked_add_months(Months::new(1))?))
+}
+
+/**
+ * the main image and the gallery of every Article counted by source, the most used source first
+ */
+pub fn source_report(articles: &[ImageCreditData]) -> Vec<SourceReport> {
+    let mut sources: Vec<SourceReport> = Vec::new();
+    for article in articles {
+        let main = (article.image_credit.trim(), article.image_source.trim());
+        let gallery = article.images.iter().map(|image| (image.credit.trim(), image.source.trim()));
+        for (credit, source) in iter::once(main).chain(gallery) {
+            let source = if source.is_empty() { UNKNOWN_SOURCE } else { source };
+            let position = sources.iter().position(|report| report.source == source).unwrap_or_else(|| {
+                sources.push(SourceReport {
+                    source: source.to_string(),
+                    images: 0,
+                    articles: Vec::new(),
+                    credits: Vec::new(),
+                });
+                sources.len() - 1
+            });
+            let report = &mut sources[position];
+            report.images += 1;
+            if !report.articles.contains(&article.article_file_name) {
+                report.articles.push(article.article_file_name.clone());
+            }
+            if !credit.is_empty() && !report.credits.iter().any(|known| known == credit) {
+                report.credits.push(credit.to_string());
+            }
+        }
+    }
+    sources.sort_by(|a, b| b.images.cmp(&a.images).then_with(|| a.source.cmp(&b.source)));
+    sources
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::database_article_data::ArticleImage;
+
+    fn image(number: u32, credit: &str, source: &str) -> ArticleImage {
+        ArticleImage {
+            number,
+            caption: String::new(),
+            alt: String::new(),
+            credit: credit.into(),
+            source: source.into(),
+            image_50_path: String::new(),
+            image_288_path: String::new(),
+            image_440_path: String::new(),
+            image_820_path: String::new(),
+        }
+    }
+
+    fn article(file_name: &str, credit: &str, source: &str, images: Vec<ArticleImage>) -> ImageCreditData {
+        ImageCreditData {
+            article_file_name: file_name.into(),
+            image_credit: credit.into(),
+            image_source: source.into(),
+            images,
+        }
+    }
+
+    #[test]
+    fn test_month_range() {
+        let first = NaiveDate::from_ymd_opt(2025, 12, 1).unwrap();
+        let after = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        assert_eq!(month_range("2025-12"), Some((first, after)));
+        assert_eq!(month_range("2025-13"), None);
+        assert_eq!(month_range("december"), None);
+    }
+
+    #[test]
+    fn test_source_report() {
+        let articles = [
+            article("a.html", "Jan Novák", "ČTK", vec![image(1, "Petr Malý", "ČTK"), image(2, "Reuters", "Reuters")]),
+            article("b.html", "Jan Novák", " ČTK ", vec![]),
+            article("c.html", "", "", vec![]),
+        ];
+        let report = source_report(&articles);
+        assert_eq!(report.iter().map(|r| r.source.as_str()).collect::<Vec<_>>(), ["ČTK", "Reuters", "neuvedeno"]);
+        assert_eq!(report[0].images, 3);
+        assert_eq!(report[0].articles, ["a.html", "b.html"]);
+        assert_eq!(report[0].credits_display(), "Jan Novák, Petr Malý");
+        assert_eq!(report[1].images, 1);
+        assert!(report[2].credits.is_empty());
+    }
+}
@@ -6,6 +6,7 @@ use axum::response::{Html, IntoResponse, Redirect, Response};
 use axum::Form;
 use bcrypt::{hash, DEFAULT_COST};
 use serde::Deserialize;
+use std::fs;
 use thiserror::Error;
 use tracing::{debug, info};
 
@@ -20,6 +21,9 @@ pub enum AdminUserError {
     #[error("unauthorized")]
     Unauthorized,
 
+    #[error("unknown role {0}")]
+    UnknownRole(String),
+
     #[error("bcrypt error: {0}")]
     Bcrypt(#[from] bcrypt::BcryptError),
 }
@@ -47,6 +51,9 @@ pub struct CreateUserPayload {
     pub username: String,
     pub author_name: String,
     pub password: String,
+    // missing in older forms
+    #[serde(default)]
+    pub role: String,
 }
 
 pub async fn show_admin_users(
@@ -81,22 +88,30 @@ pub async fn handle_delete_user(
 ) -> Result<Response, AdminUserError> {
     debug!("handle_delete_user: {}", username);
 
-    // Check if user exists and is Editor
+    // Check if user exists and isn't an Admin
     let user_o = state
         .dbu
         .get_user_by_name(&username)
         .await
         .map_err(|e| AdminUserError::Database(e.to_string()))?;
     if let Some(user) = user_o {
-        if user.role == Role::Editor {
+        if user.role != Role::Admin {
             state
                 .dbu
                 .delete_user(&username)
                 .await
                 .map_err(|e| AdminUserError::Database(e.to_string()))?;
             info!("Admin deleted user: {}", username);
+
+            // the author page isn't served anymore
+            if !user.photo_path.is_empty() {
+                let _ = fs::remove_file(format!("web/{}", user.photo_path));
+            }
+            let _ = fs::remove_file(format!("web/autor/{}.html", username));
+            let _ = fs::remove_dir_all(format!("web/autor/{}", username));
+            state.dv.author_invalidate(&username);
         } else {
-            return Err(AdminUserError::Database("Only Editors can be deleted".to_string()));
+            return Err(AdminUserError::Database("Admins can't be deleted".to_string()));
         }
     }
 
@@ -121,14 +136,22 @@ pub async fn handle_create_user(
 ) -> Result<Response, AdminUserError> {
     debug!("handle_create_user: {}", payload.username);
 
+    // admins are created only from the command line
+    let role = if payload.role.is_empty() {
+        Role::Editor
+    } else {
+        Role::from_form(&payload.role).ok_or(AdminUserError::UnknownRole(payload.role.clone()))?
+    };
     let hashed_password = hash(&payload.password, DEFAULT_COST)?;
 
     let new_user = User {
         username: payload.username,
         author_name: payload.author_name,
         password_hash: hashed_password,
+        bio: String::new(),
+        photo_path: String::new(),
         needs_password_change: true,
-        role: Role::Editor,
+        role,
     };
 
     state.dbu.create_user(new_user).await.map_err(|e| AdminUserError::Database(e.to_string()))?;
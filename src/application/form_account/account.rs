@@ -1,18 +1,24 @@
-use crate::data::text_validator;
+use crate::data::image_extractor::{extract_image_data, ImageExtractorError};
+use crate::data::image_processor::ImageProcessorError;
+use crate::data::image_validator::{validate_image_extension, ImageValidationError};
+use crate::data::{image_processor, text_validator};
 use crate::db::database_article::SurrealArticleError;
 use crate::db::database_article_data::AccountArticleData;
+use crate::db::database_user::{Permission, SurrealUserError};
 use crate::system::router_app::AuthSession;
 use crate::system::server::TheState;
 use askama::Template;
-use axum::extract::State;
+use axum::extract::multipart::MultipartError;
+use axum::extract::{Multipart, State};
 use axum::response::{Html, IntoResponse, Redirect, Response};
 use axum::Form;
 use http::StatusCode;
 use serde::Deserialize;
+use std::fs;
 use thiserror::Error;
-use tracing::debug;
+use tracing::{debug, info};
 use validator::Validate;
-use AccountError::AccountSurreal;
+use AccountError::{AccountPhotoMissing, AccountSurreal};
 
 #[derive(Debug, Error)]
 pub enum AccountError {
@@ -24,6 +30,24 @@ pub enum AccountError {
 
     #[error("surreal account error")]
     AccountSurreal(#[from] SurrealArticleError),
+
+    #[error("surreal account user error {0}")]
+    AccountSurrealUser(#[from] SurrealUserError),
+
+    #[error("photo upload error {0}")]
+    AccountMultipart(#[from] MultipartError),
+
+    #[error("photo extraction error {0}")]
+    AccountImageExtractor(#[from] ImageExtractorError),
+
+    #[error("photo validation error {0}")]
+    AccountImageValidation(#[from] ImageValidationError),
+
+    #[error("photo processing error {0}")]
+    AccountImageProcessor(#[from] ImageProcessorError),
+
+    #[error("photo is missing")]
+    AccountPhotoMissing,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -38,6 +62,12 @@ pub struct UpdateAuthorNamePayload {
     pub author_name: String,
 }
 
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateBioPayload {
+    #[validate(length(max = 1000))]
+    pub bio: String,
+}
+
 #[derive(Template)]
 #[template(path = "application/form_account/account_template.html")]
 pub struct AccountTemplate {
@@ -46,7 +76,13 @@ pub struct AccountTemplate {
     pub name_day: String,
     pub username: String,
     pub author_name: String,
+    pub bio: String,
+    pub photo_path: String,
     pub articles: Vec<AccountArticleData>,
+    // link to the review queue
+    pub may_review: bool,
+    // link to the homepage layout
+    pub may_manage_homepage: bool,
     pub server_host: String,
     pub server_port: u16,
 }
@@ -77,7 +113,11 @@ pub async fn show_account(
                     name_day: state.ds.name_day(),
                     username: user.username,
                     author_name: user.author_name,
+                    bio: user.bio,
+                    photo_path: user.photo_path,
                     articles: account_articles,
+                    may_review: user.role.can(Permission::ReviewArticle),
+                    may_manage_homepage: user.role.can(Permission::ManageHomepage),
                     // for absolut redirects for articles on web port
                     server_host: state.config.host_hame,
                     server_port: state.config.port.web,
@@ -106,6 +146,8 @@ pub async fn handle_update_author_name(
 
         debug!("validation ok");
         let _ = state.dbu.update_user_author_name(&user.username, &payload.author_name).await;
+        // the author page shows the name
+        state.dv.author_invalidate(&user.username);
         debug!("always redirect to account");
         Redirect::to("/account").into_response()
     } else {
@@ -114,6 +156,59 @@ pub async fn handle_update_author_name(
     }
 }
 
+pub async fn handle_update_bio(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Form(payload): Form<UpdateBioPayload>,
+) -> Result<Response, AccountError> {
+    debug!("handle_update_bio()");
+
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    if payload.validate().is_err() {
+        return Ok(StatusCode::BAD_REQUEST.into_response());
+    }
+
+    let bio = payload.bio.trim().replace("\r\n", "\n");
+    state.dbu.update_user_bio(&user.username, &bio).await?;
+    state.dv.author_invalidate(&user.username);
+    Ok(Redirect::to("/account").into_response())
+}
+
+pub async fn handle_update_photo(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    mut multipart: Multipart,
+) -> Result<Response, AccountError> {
+    debug!("handle_update_photo()");
+
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let mut photo = None;
+    while let Some(field) = multipart.next_field().await? {
+        if field.name() == Some("photo") {
+            photo = Some(extract_image_data(field).await?);
+        }
+    }
+    let (photo_data, photo_ext) = photo.filter(|(data, _)| !data.is_empty()).ok_or(AccountPhotoMissing)?;
+    validate_image_extension(&photo_ext)?;
+
+    let file_base = format!("autor-{}", user.username);
+    let photo_path = image_processor::process_author_photo(&photo_data, &file_base, &photo_ext)?;
+    if !user.photo_path.is_empty() && user.photo_path != photo_path {
+        // a photo of a different extension was uploaded before
+        let _ = fs::remove_file(format!("web/{}", user.photo_path));
+    }
+
+    state.dbu.update_user_photo(&user.username, &photo_path).await?;
+    info!("photo updated: {}", user.username);
+    state.dv.author_invalidate(&user.username);
+    Ok(Redirect::to("/account").into_response())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,310 @@
+use crate::application::form_edit_article::edit_article;
+use crate::application::form_edit_article::edit_article::{may_edit, EditArticleError};
+use crate::data::text_diff;
+use crate::data::text_diff::DiffPart;
+use crate::data::text_processor;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::{Article, ArticleRevision};
+use crate::db::database_category::SurrealCategoryError;
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::{Path, State};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::Form;
+use chrono::Utc;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path;
+use surrealdb::types::Uuid;
+use thiserror::Error;
+use tracing::{debug, info};
+use ArticleRevisionsError::Forbidden;
+
+// the current version of the Article, instead of a revision id
+const CURRENT: &str = "current";
+
+#[derive(Debug, Error)]
+pub enum ArticleRevisionsError {
+    #[error("only the author or an admin may see revisions of article {0}")]
+    Forbidden(String),
+
+    #[error("surreal article error {0}")]
+    RevisionsSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("surreal category error {0}")]
+    RevisionsSurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("restore failed: {0}")]
+    RevisionsRestore(#[from] EditArticleError),
+
+    #[error("render error")]
+    RevisionsRenderError(#[from] askama::Error),
+}
+
+#[derive(Deserialize)]
+pub struct DiffPayload {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Template)]
+#[template(path = "application/form_article_revisions/article_revisions_template.html")]
+pub struct ArticleRevisionsTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+
+    pub article_file_name: String,
+    pub title: String,
+    pub revisions: Vec<ArticleRevision>,
+}
+
+#[derive(Template)]
+#[template(path = "application/form_article_revisions/article_diff_template.html")]
+pub struct ArticleDiffTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+
+    pub article_file_name: String,
+    pub from_display: String,
+    pub to_display: String,
+    pub title_diff: Vec<DiffPart>,
+    pub short_text_diff: Vec<DiffPart>,
+    pub text_diff: Vec<DiffPart>,
+}
+
+pub async fn show_article_revisions(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+) -> Result<Response, ArticleRevisionsError> {
+    debug!("show_article_revisions: {}", article_file_name);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let article = state.dba.article_by_file_name(&article_file_name).await?;
+    if !may_edit(&user, &article) {
+        return Err(Forbidden(article_file_name));
+    }
+    let revisions = state.dba.revisions_by_article(article.uuid).await?;
+
+    Ok(Html(
+        ArticleRevisionsTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+
+            article_file_name,
+            title: article.title,
+            revisions,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+/**
+ * word level diff of the processed text of two versions
+ * - a revision id, or "current" for the current Article
+ */
+pub async fn show_article_diff(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+    Form(payload): Form<DiffPayload>,
+) -> Result<Response, ArticleRevisionsError> {
+    debug!("show_article_diff: {} {} {}", article_file_name, payload.from, payload.to);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let article = state.dba.article_by_file_name(&article_file_name).await?;
+    if !may_edit(&user, &article) {
+        return Err(Forbidden(article_file_name));
+    }
+
+    let (from, from_display) = version(&state, &article, &payload.from).await?;
+    let (to, to_display) = version(&state, &article, &payload.to).await?;
+
+    Ok(Html(
+        ArticleDiffTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+
+            article_file_name,
+            from_display,
+            to_display,
+            title_diff: text_diff::diff_words(&from.title, &to.title),
+            short_text_diff: text_diff::diff_words(
+                &text_diff::strip_html(&from.short_text),
+                &text_diff::strip_html(&to.short_text),
+            ),
+            text_diff: text_diff::diff_words(
+                &text_diff::strip_html(&from.text),
+                &text_diff::strip_html(&to.text),
+            ),
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+/**
+ * Replace the Article by an older revision
+ * - the current version is kept as a new revision, so the restore can be undone
+ * - the HTML is not rendered, only invalidated
+ */
+pub async fn restore_article_revision(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path((article_file_name, revision_id)): Path<(String, String)>,
+) -> Result<Response, ArticleRevisionsError> {
+    debug!("restore_article_revision: {} {}", article_file_name, revision_id);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let article = state.dba.article_by_file_name(&article_file_name).await?;
+    if !may_edit(&user, &article) {
+        return Err(Forbidden(article_file_name));
+    }
+
+    let revision = state.dba.revision_by_id(&revision_id).await?;
+    if revision.article_uuid != article.uuid {
+        return Err(SurrealArticleError::RevisionNotFound(revision_id).into());
+    }
+
+    // links, the category and related articles may be gone since
+    let revision = revision.article;
+    let linked = state.dba.linked_articles(&text_processor::internal_links(&revision.text_raw)).await?;
+    let links = linked.into_iter().map(|a| (a.article_file_name, a.title)).collect();
+    let category_exists = state.dbc.category_by_slug(&revision.category).await?.is_some();
+    let existing = state.dba.article_file_names_by_ids(&revision.related_articles).await?;
+    let related_articles = state.dba.article_ids_by_file_names(&existing).await?;
+    let restored = restored(article.clone(), revision, &links, category_exists, related_articles);
+
+    state.dba.create_revision(article.clone(), &user.username).await?;
+    state.dba.update_article(restored.clone()).await?;
+    info!("article {} restored to revision {}", article_file_name, revision_id);
+
+    edit_article::invalidate_changed(&state, &article, &restored).await?;
+
+    Ok(Redirect::to(&format!("/revisions/{}", article_file_name)).into_response())
+}
+
+async fn version(
+    state: &TheState,
+    article: &Article,
+    revision_id: &str,
+) -> Result<(Article, String), ArticleRevisionsError> {
+    if revision_id == CURRENT {
+        return Ok((article.clone(), "aktuální verze".into()));
+    }
+    let revision = state.dba.revision_by_id(revision_id).await?;
+    if revision.article_uuid != article.uuid {
+        return Err(SurrealArticleError::RevisionNotFound(revision_id.into()).into());
+    }
+    let display = format!("{}, {}", revision.revised_display(), revision.editor);
+    Ok((revision.article, display))
+}
+
+/*
+ * content of the revision, identity, file names and publish state of the current Article
+ * - media replaced by a file of a different extension were deleted, keep the current ones then
+ * - the text is rendered again without the deleted images, a deleted category keeps the current one
+ */
+fn restored(
+    current: Article,
+    revision: Article,
+    links: &HashMap<String, String>,
+    category_exists: bool,
+    related_articles: Vec<Uuid>,
+) -> Article {
+    let exists = |p: &str| !p.is_empty() && path::Path::new("web").join(p).exists();
+    let images_exist = [
+        &revision.image_50_path,
+        &revision.image_288_path,
+        &revision.image_440_path,
+        &revision.image_820_path,
+    ]
+    .iter()
+    .all(|p| exists(p));
+    let video_exists = !revision.has_video || exists(&revision.video_path);
+    let audio_exists = !revision.has_audio || exists(&revision.audio_path);
+    // removed images were deleted
+    let images: Vec<_> =
+        revision.images.iter().filter(|image| image.paths().iter().all(|p| exists(p))).cloned().collect();
+
+    Article {
+        text: text_processor::process_text(&revision.text_raw, links, &images),
+        category: if category_exists { revision.category } else { current.category },
+        related_articles,
+
+        image_50_path: if images_exist { revision.image_50_path } else { current.image_50_path },
+        image_288_path: if images_exist { revision.image_288_path } else { current.image_288_path },
+        image_440_path: if images_exist { revision.image_440_path } else { current.image_440_path },
+        image_820_path: if images_exist { revision.image_820_path } else { current.image_820_path },
+        images,
+
+        has_video: if video_exists { revision.has_video } else { current.has_video },
+        video_path: if video_exists { revision.video_path } else { current.video_path },
+        has_audio: if audio_exists { revision.has_audio } else { current.has_audio },
+        audio_path: if audio_exists { revision.audio_path } else { current.audio_path },
+
+        uuid: current.uuid,
+        article_file_name: current.article_file_name,
+        previous_file_names: current.previous_file_names,
+        author: current.author,
+        username: current.username,
+        created: current.created,
+        // restoring is a change too
+        updated: Utc::now(),
+        publish_state: current.publish_state,
+        publish_at: current.publish_at,
+        expires_at: current.expires_at,
+        state_transitions: current.state_transitions,
+        ..revision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::database_article_data::ArticleImage;
+    use crate::trust::app::article::create_article_easy_builder::ArticleBuilder;
+
+    #[test]
+    fn test_restored() {
+        let current = ArticleBuilder::article().title("Current").category("veda").text("Current text.").build();
+        let mut revision = ArticleBuilder::article().title("Older").category("gone").text("Older text.").build();
+        revision.text_raw = "Older text.\n\n[[image:1]]\n\nSee [[linked]].".into();
+        revision.images = vec![ArticleImage {
+            number: 1,
+            caption: "Deleted".into(),
+            alt: "Deleted".into(),
+            credit: String::new(),
+            source: String::new(),
+            image_50_path: "u/test-restored-deleted_image_50.png".into(),
+            image_288_path: "u/test-restored-deleted_image_288.png".into(),
+            image_440_path: "u/test-restored-deleted_image_440.png".into(),
+            image_820_path: "u/test-restored-deleted_image_820.png".into(),
+        }];
+        revision.related_articles = vec![Uuid::new(), Uuid::new()];
+        let kept = revision.related_articles[1];
+        let links = HashMap::from([("linked.html".to_string(), "Linked".to_string())]);
+
+        let restored = restored(current.clone(), revision, &links, false, vec![kept]);
+        assert_eq!(restored.title, "Older");
+        assert!(restored.images.is_empty());
+        assert!(!restored.text.contains("<img"));
+        assert!(restored.text.contains("<p>Older text.</p>"));
+        assert!(restored.text.contains("<a href=\"/linked.html\">Linked</a>"));
+        assert_eq!(restored.category, "veda");
+        assert_eq!(restored.related_articles, [kept]);
+        assert_eq!(restored.uuid, current.uuid);
+    }
+}
@@ -2,7 +2,7 @@ use crate::application::form_change_password::change_password::ChangePasswordErr
 use crate::data::text_validator::validate_input_simple;
 use crate::db::database::SurrealError;
 use crate::db::database_user::Role::Admin;
-use crate::db::database_user::{Role, SurrealUserError};
+use crate::db::database_user::SurrealUserError;
 use crate::system::router_app::AuthSession;
 use crate::system::server::TheState;
 use askama::Template;
@@ -12,7 +12,6 @@ use axum::Form;
 use bcrypt::{hash, DEFAULT_COST};
 use serde::Deserialize;
 use thiserror::Error;
-use Role::Editor;
 
 #[derive(Debug, Error)]
 pub enum ChangePasswordError {
@@ -108,7 +107,7 @@ pub async fn handle_change_password(
 
                 match updated_user.role {
                     Admin => Ok(Redirect::to("/admin_user").into_response()),
-                    Editor => Ok(Redirect::to("/account").into_response()),
+                    _ => Ok(Redirect::to("/account").into_response()),
                 }
             }
         };
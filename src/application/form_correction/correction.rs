@@ -0,0 +1,118 @@
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::{Article, Correction};
+use crate::db::database_system::SurrealSystemError;
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::{Path, State};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::Form;
+use chrono::Utc;
+use serde::Deserialize;
+use surrealdb::types::Uuid;
+use thiserror::Error;
+use tracing::{debug, info};
+use CorrectionError::{NotPublished, TextRequired};
+
+#[derive(Debug, Error)]
+pub enum CorrectionError {
+    #[error("article {0} is not published")]
+    NotPublished(String),
+
+    #[error("correction text is required")]
+    TextRequired,
+
+    #[error("surreal article error {0}")]
+    CorrectionSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("surreal system error {0}")]
+    CorrectionSurrealSystem(#[from] SurrealSystemError),
+
+    #[error("render error")]
+    CorrectionRender(#[from] askama::Error),
+}
+
+#[derive(Deserialize)]
+pub struct CorrectionPayload {
+    pub text: String,
+}
+
+#[derive(Template)]
+#[template(path = "application/form_correction/correction_template.html")]
+pub struct CorrectionTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+    pub article_file_name: String,
+    pub title: String,
+    pub corrections: Vec<Correction>,
+}
+
+/*
+ * only a published Article is corrected, anything else is simply edited
+ */
+async fn published_article(state: &TheState, article_file_name: &str) -> Result<Article, CorrectionError> {
+    let article = state.dba.article_by_file_name(article_file_name).await?;
+    if !article.is_published() {
+        return Err(NotPublished(article_file_name.into()));
+    }
+    Ok(article)
+}
+
+pub async fn show_correction_form(
+    State(state): State<TheState>,
+    Path(article_file_name): Path<String>,
+) -> Result<Response, CorrectionError> {
+    debug!("show_correction_form: {}", article_file_name);
+    let article = published_article(&state, &article_file_name).await?;
+    let corrections = state.dba.corrections_by_article(article.uuid).await?;
+    Ok(Html(
+        CorrectionTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            article_file_name: article.article_file_name,
+            title: article.title,
+            corrections,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+/**
+ * the correction is disclosed in the Article and on opravy.html
+ */
+pub async fn handle_add_correction(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+    Form(payload): Form<CorrectionPayload>,
+) -> Result<Response, CorrectionError> {
+    debug!("handle_add_correction: {}", article_file_name);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let text = payload.text.trim();
+    if text.is_empty() {
+        return Err(TextRequired);
+    }
+    let article = published_article(&state, &article_file_name).await?;
+
+    state
+        .dba
+        .create_correction(Correction {
+            uuid: Uuid::new(),
+            article_uuid: article.uuid,
+            created: Utc::now(),
+            username: user.username.clone(),
+            text: text.into(),
+        })
+        .await?;
+    info!("correction added: {} by {}", article_file_name, user.username);
+
+    state.dbs.invalidate_article(article.article_file_name).await?;
+    state.dv.corrections_invalidate();
+    Ok(Redirect::to(&format!("/correction/{}", article_file_name)).into_response())
+}
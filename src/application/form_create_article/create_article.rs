@@ -1,14 +1,16 @@
 use crate::application::form_create_article::create_article_parser;
 use crate::application::form_create_article::create_article_parser::ArticleCreateError;
+use crate::application::page_archive::archive;
 use crate::application::page_article::article::ArticleError;
-use crate::application::page_article::article::ArticleError::CategoryFailed;
+use crate::application::page_article::article::ArticleError::{CategoryFailed, PublishForbidden};
 use crate::data::audio_processor::AudioProcessorError;
 use crate::data::image_processor::ImageProcessorError;
 use crate::data::video_processor::VideoProcessorError;
 use crate::data::{audio_processor, image_processor, video_processor};
 use crate::db::database::SurrealError;
-use crate::db::database_article_data::Article;
-use crate::db::database_user::SurrealUserError;
+use crate::db::database_article_data::{Article, ArticleImage, PublishState};
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::db::database_user::{Permission, SurrealUserError, User};
 use crate::system::router_app::AuthSession;
 use crate::system::server::TheState;
 use askama::Template;
@@ -41,6 +43,9 @@ pub enum FormArticleCreateError {
     #[error("surreal user error")]
     FormArticleSurrealUserError(#[from] SurrealUserError),
 
+    #[error("surreal category error")]
+    FormArticleSurrealCategoryError(#[from] SurrealCategoryError),
+
     #[error("render error")]
     FormArticleRenderError(#[from] askama::Error),
 }
@@ -49,6 +54,9 @@ pub enum FormArticleCreateError {
 #[template(path = "application/form_create_article/create_article_template.html")]
 pub struct FormTemplate {
     pub author_name: String,
+    pub categories: Vec<Category>,
+    // contributors only submit for review
+    pub may_publish: bool,
     pub date: String,
     pub name_day: String,
     pub weather: String,
@@ -65,9 +73,12 @@ pub async fn show_article_create_form(
             match user_o {
                 None => {}
                 Some(user) => {
+                    let categories = state.dbc.all_categories().await?;
                     return Ok(Html(
                         FormTemplate {
+                            may_publish: user.role.can(Permission::PublishArticle),
                             author_name: user.author_name,
+                            categories,
                             date: state.ds.date(),
                             name_day: state.ds.name_day(),
                             weather: state.ds.weather(),
@@ -89,17 +100,43 @@ pub async fn create_article(
 ) -> Result<impl IntoResponse, ArticleError> {
     // TODO mitigate doubled request on create button
 
+    let Some(user) = auth_session.user.clone() else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
     /*
      * Read request data
      */
-    let article_data = create_article_parser::article_data(auth_session, multipart).await?;
+    let mut article_data = create_article_parser::article_data(auth_session, multipart).await?;
+    if !may_set_state(&user, article_data.publish_state) {
+        return Err(PublishForbidden(user.username));
+    }
+
+    // another article may have the same title, media are named after the file name
+    article_data.base_file_name = state.dba.unique_base_file_name(&article_data.base_file_name, None).await?;
     let article_file_name = format!("{}.html", article_data.base_file_name.clone());
 
     /*
      * Validate
      */
+    if state.dbc.category_by_slug(&article_data.category).await?.is_none() {
+        return Err(CategoryFailed(article_data.category));
+    }
 
-    let article_db = Article::try_from(article_data.clone())?;
+    create_article_parser::validate_related_articles(&state.dba, &article_data.related_articles).await?;
+    article_data.internal_links =
+        create_article_parser::resolve_internal_links(&state.dba, &article_data.text_raw).await?;
+
+    let related_articles = state.dba.article_ids_by_file_names(&article_data.related_articles).await?;
+    let mut article_db = Article {
+        related_articles,
+        ..Article::try_from(article_data.clone())?
+    };
+    create_article_parser::validate_image_references(&article_data.text_raw, &article_db.images)?;
+    create_article_parser::validate_embeds(&article_data.text_raw)?;
+    if article_db.publish_state == PublishState::InReview {
+        article_db.record_transition(PublishState::Draft, &user.username, "");
+    }
 
     info!("is main {}", article_db.is_main);
     info!("is excl {}", article_db.is_exclusive);
@@ -112,6 +149,13 @@ pub async fn create_article(
         &article_data.base_file_name,
         &article_data.image_ext,
     )?;
+    for image in article_data.images.iter().filter(|image| image.is_new()) {
+        image_processor::process_images(
+            &image.data,
+            &ArticleImage::file_base(&article_data.base_file_name, image.number),
+            &image.ext,
+        )?;
+    }
     debug!("process images done");
 
     // process data audio
@@ -121,7 +165,8 @@ pub async fn create_article(
         // validate_audio_extension(&article_data.audio_ext)?;
         audio_processor::process_valid_audio(
             &article_data.audio_data,
-            &format!("{}.{}", article_data.base_file_name, article_data.audio_ext),
+            // same name as Article.audio_path
+            &format!("{}_audio.{}", article_data.base_file_name, article_data.audio_ext),
         )?;
         debug!("process audio done");
     }
@@ -134,24 +179,52 @@ pub async fn create_article(
 
         video_processor::process_video(
             &article_data.video_data,
-            &format!("{}.{}", article_data.base_file_name, article_data.video_ext),
+            // same name as Article.video_path
+            &format!("{}_video.{}", article_data.base_file_name, article_data.video_ext),
         )?;
         debug!("process video done");
     }
 
-    match article_data.category.as_str() {
-        "zahranici" => state.dv.zahranici_invalidate(),
-        "republika" => state.dv.republika_invalidate(),
-        "finance" => state.dv.finance_invalidate(),
-        "technologie" => state.dv.technologie_invalidate(),
-        "veda" => state.dv.veda_invalidate(),
-        cat => return Err(CategoryFailed(cat.into())),
+    /*
+     * store Article data
+     */
+    state.dba.create_article(article_db.clone()).await?;
+
+    // drafts and scheduled articles stay hidden until published
+    if article_db.is_published() {
+        publish_article(&state, &article_db).await?;
     }
 
     /*
-     * store Article data
+     * don't render anything
+     * web router manages render trigger
      */
-    state.dba.create_article(article_db).await?;
+    Ok(Redirect::to("/account").into_response())
+}
+
+/**
+ * only roles with the publish permission may publish or schedule
+ */
+pub const fn may_set_state(user: &User, publish_state: PublishState) -> bool {
+    match publish_state {
+        PublishState::Published | PublishState::Scheduled => user.role.can(Permission::PublishArticle),
+        _ => true,
+    }
+}
+
+/**
+ * the stored Article goes live
+ * - used on create and by the publish worker
+ * - the HTML is not rendered, only invalidated
+ */
+pub async fn publish_article(state: &TheState, article: &Article) -> Result<(), ArticleError> {
+    let article_file_name = article.article_file_name.clone();
+    info!("publish article {}", article_file_name);
+
+    state.dv.category_invalidate(&article.category);
+    archive::invalidate_archive(state, article.created);
+    state.dv.author_invalidate(&article.username);
+
     // create record in article views
     state.dba.increase_article_views(article_file_name.clone()).await?;
     // create a record, that article exists
@@ -162,17 +235,14 @@ pub async fn create_article(
     state.dv.news_invalidate();
 
     // invalidate related articles
-    for related_article in &article_data.related_articles {
+    for related_article in state.dba.article_file_names_by_ids(&article.related_articles).await? {
         info!("invalidate related article {}", related_article);
-        state.dbs.invalidate_article(related_article.clone()).await?;
-
-        // add bidirectional relationship
-        state.dba.add_related_article(related_article.clone(), article_file_name.clone()).await?;
+        state.dbs.invalidate_article(related_article).await?;
     }
 
-    /*
-     * don't render anything
-     * web router manages render trigger
-     */
-    Ok(Redirect::to("/account").into_response())
+    // add bidirectional relationship
+    for related_article in &article.related_articles {
+        state.dba.add_related_article(*related_article, article.uuid).await?;
+    }
+    Ok(())
 }
@@ -1,11 +1,18 @@
 use crate::data::audio_extractor::{extract_audio_data, AudioExtractorError};
 use crate::data::image_extractor::{extract_image_data, ImageExtractorError};
-use crate::data::library;
+use crate::data::slug;
+use crate::data::time::{from_prague_local_input, to_prague_time};
 use crate::data::text_extractor::{
     extract_optional_string, extract_required_string, extract_required_text, TextExtractorError,
 };
+use crate::data::text_processor;
+use crate::data::text_validator::{self, TextValidationError};
 use crate::data::video_extractor::{extract_video_data, VideoExtractorError};
+use crate::db::database_article::{DatabaseArticle, SurrealArticleError};
+use crate::db::database_article_data::{ArticleImage, ArticleSource, PublishState};
 use axum::extract::Multipart;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
 use thiserror::Error;
 use tracing::{debug, warn};
 
@@ -34,6 +41,48 @@ pub enum ArticleCreateError {
 
     #[error("user required")]
     UserRequired,
+
+    #[error("unknown publish state {0}")]
+    UnknownPublishState(String),
+
+    #[error("publish time was required for a scheduled article")]
+    PublishAtRequired,
+
+    #[error("invalid publish time {0}")]
+    InvalidPublishAt(String),
+
+    #[error("invalid expiry time {0}, use a future time")]
+    InvalidExpiresAt(String),
+
+    #[error("related article {0} not found")]
+    UnknownRelatedArticle(String),
+
+    #[error("linked article {0} not found")]
+    UnknownInternalLink(String),
+
+    #[error("image {0} of the text not found")]
+    UnknownImage(u32),
+
+    #[error("invalid embed: {0}")]
+    InvalidEmbed(TextValidationError),
+
+    #[error("image {0} needs alt text, credit and source")]
+    ImageInfoRequired(String),
+
+    #[error("source {0} needs a title")]
+    SourceTitleRequired(u32),
+
+    #[error("invalid url of source {0}: {1}")]
+    InvalidSourceUrl(u32, TextValidationError),
+
+    #[error("access date of source {0} is missing or in the future")]
+    InvalidSourceAccessed(u32),
+
+    #[error("footnote {0} of the text has no source")]
+    UnknownFootnote(u32),
+
+    #[error("related articles lookup failed: {0}")]
+    RelatedArticlesLookup(#[from] SurrealArticleError),
 }
 
 /**
@@ -45,6 +94,7 @@ pub enum ArticleCreateError {
 pub struct ArticleUpload {
     pub is_main: bool,
     pub is_exclusive: bool,
+    pub is_live_blog: bool,
     pub author: String,
     pub username: String,
 
@@ -55,6 +105,9 @@ pub struct ArticleUpload {
     pub category: String,
 
     pub image_desc: String,
+    pub image_alt: String,
+    pub image_credit: String,
+    pub image_source: String,
     pub image_ext: String,
     pub image_data: Vec<u8>,
 
@@ -66,8 +119,110 @@ pub struct ArticleUpload {
     pub audio_data: Vec<u8>,
     pub audio_ext: String,
 
+    // additional images, ordered by number
+    pub images: Vec<ImageUpload>,
+    // ordered by number, the form replaces all of them
+    pub sources: Vec<ArticleSource>,
+
     pub related_articles: Vec<String>,
+    // [[slug]] links of the text and their titles, resolved by the caller
+    pub internal_links: HashMap<String, String>,
     pub base_file_name: String,
+
+    pub publish_state: PublishState,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/**
+ * additional image of the form
+ * - fields gallery_image_N, gallery_caption_N, gallery_alt_N, gallery_credit_N, gallery_source_N
+ *   and gallery_remove_N
+ * - the file may be empty, the edit form changes only the texts then
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ImageUpload {
+    pub number: u32,
+    pub data: Vec<u8>,
+    pub ext: String,
+    pub caption: String,
+    pub alt: String,
+    pub credit: String,
+    pub source: String,
+    pub remove: bool,
+}
+
+impl ImageUpload {
+    pub const fn is_new(&self) -> bool {
+        !self.data.is_empty() && !self.remove
+    }
+
+    /*
+     * a row added in the form and left empty
+     */
+    fn is_blank(&self) -> bool {
+        self.data.is_empty()
+            && [&self.caption, &self.alt, &self.credit, &self.source].iter().all(|text| text.trim().is_empty())
+    }
+}
+
+enum GalleryField {
+    Image,
+    Caption,
+    Alt,
+    Credit,
+    Source,
+    Remove,
+}
+
+fn gallery_field(field_name: &str) -> Option<(GalleryField, u32)> {
+    let (kind, number) = field_name.strip_prefix("gallery_")?.rsplit_once('_')?;
+    let kind = match kind {
+        "image" => GalleryField::Image,
+        "caption" => GalleryField::Caption,
+        "alt" => GalleryField::Alt,
+        "credit" => GalleryField::Credit,
+        "source" => GalleryField::Source,
+        "remove" => GalleryField::Remove,
+        _ => return None,
+    };
+    Some((kind, number.parse().ok()?))
+}
+
+/*
+ * source of the form, fields source_title_N, source_url_N and source_accessed_N
+ */
+enum SourceField {
+    Title,
+    Url,
+    Accessed,
+}
+
+fn source_field(field_name: &str) -> Option<(SourceField, u32)> {
+    let (kind, number) = field_name.strip_prefix("source_")?.rsplit_once('_')?;
+    let kind = match kind {
+        "title" => SourceField::Title,
+        "url" => SourceField::Url,
+        "accessed" => SourceField::Accessed,
+        _ => return None,
+    };
+    Some((kind, number.parse().ok()?))
+}
+
+fn article_source(sources: &mut Vec<ArticleSource>, number: u32) -> &mut ArticleSource {
+    let position = sources.iter().position(|source| source.number == number).unwrap_or_else(|| {
+        sources.push(ArticleSource { number, ..ArticleSource::default() });
+        sources.len() - 1
+    });
+    &mut sources[position]
+}
+
+fn image_upload(images: &mut Vec<ImageUpload>, number: u32) -> &mut ImageUpload {
+    let position = images.iter().position(|image| image.number == number).unwrap_or_else(|| {
+        images.push(ImageUpload { number, ..ImageUpload::default() });
+        images.len() - 1
+    });
+    &mut images[position]
 }
 
 /*
@@ -89,6 +244,9 @@ pub async fn article_data(
     let mut image_data = Vec::<u8>::new();
     let mut image_data_ext = String::new();
     let mut image_desc = String::new();
+    let mut image_alt = String::new();
+    let mut image_credit = String::new();
+    let mut image_source = String::new();
     let mut category = String::new();
 
     // not required
@@ -100,7 +258,13 @@ pub async fn article_data(
     let mut audio_data_ext = String::new();
     let mut is_main = false;
     let mut is_exclusive = false;
+    let mut is_live_blog = false;
     let mut related_articles = Vec::new();
+    let mut images = Vec::new();
+    let mut sources = Vec::new();
+    let mut publish_state = PublishState::Published;
+    let mut publish_at = None;
+    let mut expires_at = None;
 
     debug!("process");
     while let Ok(Some(field)) = multipart.next_field().await {
@@ -123,10 +287,16 @@ pub async fn article_data(
                 is_exclusive = extract_required_string(field).await? == "on";
             }
 
+            "is_live_blog" => {
+                debug!("processing: is_live_blog");
+                // if present, then required
+                is_live_blog = extract_required_string(field).await? == "on";
+            }
+
             "title" => {
                 debug!("processing: title");
                 title = extract_required_string(field).await?;
-                base_file_name = library::safe_article_file_name(&title);
+                base_file_name = slug::slugify(&title);
             }
 
             "text" => {
@@ -149,7 +319,8 @@ pub async fn article_data(
                 related_articles = extract_optional_string(field)
                     .await?
                     .unwrap_or_default()
-                    .split(',')
+                    // the form asks for one per line, comma separated works too
+                    .split([',', '\n'])
                     .map(str::trim)
                     .filter(|s| !s.is_empty())
                     .map(String::from)
@@ -162,6 +333,21 @@ pub async fn article_data(
                 image_desc = extract_required_string(field).await?;
             }
 
+            "image_alt" => {
+                debug!("processing: image_alt");
+                image_alt = extract_optional_string(field).await?.unwrap_or_default().trim().into();
+            }
+
+            "image_credit" => {
+                debug!("processing: image_credit");
+                image_credit = extract_optional_string(field).await?.unwrap_or_default().trim().into();
+            }
+
+            "image_source" => {
+                debug!("processing: image_source");
+                image_source = extract_optional_string(field).await?.unwrap_or_default().trim().into();
+            }
+
             "image" => {
                 debug!("processing: image");
                 (image_data, image_data_ext) = extract_image_data(field).await?;
@@ -187,17 +373,90 @@ pub async fn article_data(
                 debug!("processing: mini_text");
                 mini_text_raw = extract_required_text(field).await?;
             }
+            "publish_state" => {
+                debug!("processing: publish_state");
+                publish_state = match extract_required_string(field).await?.as_str() {
+                    "draft" => PublishState::Draft,
+                    "review" => PublishState::InReview,
+                    "scheduled" => PublishState::Scheduled,
+                    "published" => PublishState::Published,
+                    state => Err(ArticleCreateError::UnknownPublishState(state.into()))?,
+                };
+            }
+            "publish_at" => {
+                debug!("processing: publish_at");
+                // datetime-local input, Prague time
+                let local = extract_optional_string(field).await?.unwrap_or_default();
+                if !local.trim().is_empty() {
+                    publish_at = Some(
+                        from_prague_local_input(&local)
+                            .ok_or(ArticleCreateError::InvalidPublishAt(local))?,
+                    );
+                }
+            }
+            "expires_at" => {
+                debug!("processing: expires_at");
+                // datetime-local input, Prague time, empty if the Article doesn't expire
+                let local = extract_optional_string(field).await?.unwrap_or_default();
+                if !local.trim().is_empty() {
+                    expires_at = Some(
+                        from_prague_local_input(&local)
+                            .filter(|expires| *expires > Utc::now())
+                            .ok_or(ArticleCreateError::InvalidExpiresAt(local))?,
+                    );
+                }
+            }
             _ => {
-                warn!("processing: UNKNOWN");
-                Err(ArticleCreateError::UnknownField(field_name.to_string()))?;
+                if let Some((kind, number)) = source_field(field_name) {
+                    debug!("processing: source {number}");
+                    let text = extract_optional_string(field).await?.unwrap_or_default().trim().to_string();
+                    let source = article_source(&mut sources, number);
+                    match kind {
+                        SourceField::Title => source.title = text,
+                        SourceField::Url => source.url = text,
+                        SourceField::Accessed => source.accessed = text,
+                    }
+                    continue;
+                }
+                let Some((kind, number)) = gallery_field(field_name) else {
+                    warn!("processing: UNKNOWN");
+                    return Err(ArticleCreateError::UnknownField(field_name.to_string()));
+                };
+                debug!("processing: gallery image {number}");
+                let image = image_upload(&mut images, number);
+                match kind {
+                    GalleryField::Image => (image.data, image.ext) = extract_image_data(field).await?,
+                    GalleryField::Caption => image.caption = extract_optional_string(field).await?.unwrap_or_default(),
+                    GalleryField::Alt => image.alt = extract_optional_string(field).await?.unwrap_or_default(),
+                    GalleryField::Credit => image.credit = extract_optional_string(field).await?.unwrap_or_default(),
+                    GalleryField::Source => image.source = extract_optional_string(field).await?.unwrap_or_default(),
+                    GalleryField::Remove => image.remove = extract_required_string(field).await? == "on",
+                }
             }
         }
     }
     debug!("processing finished");
+    images.sort_by_key(|image| image.number);
+    validate_image_info(&image_alt, &image_credit, &image_source, &images)?;
+    // a row emptied in the form removes the source
+    sources.retain(|source| ![&source.title, &source.url, &source.accessed].iter().all(|text| text.is_empty()));
+    sources.sort_by_key(|source| source.number);
+    validate_sources(&sources)?;
+    validate_footnotes(&text_raw, &sources)?;
+
+    // publish time matters only for a scheduled article
+    match publish_state {
+        PublishState::Scheduled if publish_at.is_none() => {
+            Err(ArticleCreateError::PublishAtRequired)?;
+        }
+        PublishState::Scheduled => {}
+        _ => publish_at = None,
+    }
 
     let ad = ArticleUpload {
         is_main,
         is_exclusive,
+        is_live_blog,
         author,
         username: user,
         title,
@@ -206,17 +465,120 @@ pub async fn article_data(
         image_data,
         image_ext: image_data_ext,
         image_desc,
+        image_alt,
+        image_credit,
+        image_source,
         video_data,
         video_ext: video_data_ext,
         has_audio,
         audio_data,
         category,
+        images,
+        sources,
         related_articles,
+        internal_links: HashMap::new(),
         base_file_name,
         has_video,
         audio_ext: audio_data_ext,
         mini_text_raw,
+        publish_state,
+        publish_at,
+        expires_at,
     };
 
     Ok(ad)
 }
+
+/*
+ * agencies bill every published photo, each kept image needs its alt text, credit and source
+ */
+fn validate_image_info(alt: &str, credit: &str, source: &str, images: &[ImageUpload]) -> Result<(), ArticleCreateError> {
+    let complete = |texts: [&str; 3]| texts.iter().all(|text| !text.trim().is_empty());
+    if !complete([alt, credit, source]) {
+        return Err(ArticleCreateError::ImageInfoRequired("main".into()));
+    }
+    images
+        .iter()
+        .filter(|image| !image.remove && !image.is_blank())
+        .find(|image| !complete([&image.alt, &image.credit, &image.source]))
+        .map_or(Ok(()), |image| Err(ArticleCreateError::ImageInfoRequired(image.number.to_string())))
+}
+
+/*
+ * each source needs a title, a web page and the day it was read
+ */
+fn validate_sources(sources: &[ArticleSource]) -> Result<(), ArticleCreateError> {
+    let today = to_prague_time(Utc::now()).date_naive();
+    for source in sources {
+        if source.title.is_empty() {
+            return Err(ArticleCreateError::SourceTitleRequired(source.number));
+        }
+        text_validator::validate_url(&source.url)
+            .map_err(|e| ArticleCreateError::InvalidSourceUrl(source.number, e))?;
+        NaiveDate::parse_from_str(&source.accessed, "%Y-%m-%d")
+            .ok()
+            .filter(|accessed| *accessed <= today)
+            .ok_or(ArticleCreateError::InvalidSourceAccessed(source.number))?;
+    }
+    Ok(())
+}
+
+/**
+ * every footnote [^N] of the text must be a source of the Article
+ */
+pub fn validate_footnotes(text_raw: &str, sources: &[ArticleSource]) -> Result<(), ArticleCreateError> {
+    text_processor::footnote_references(text_raw)
+        .into_iter()
+        .find(|number| !sources.iter().any(|source| source.number == *number))
+        .map_or(Ok(()), |unknown| Err(ArticleCreateError::UnknownFootnote(unknown)))
+}
+
+/**
+ * every related article typed in the form must exist
+ */
+pub async fn validate_related_articles(
+    dba: &DatabaseArticle,
+    related_articles: &[String],
+) -> Result<(), ArticleCreateError> {
+    let existing = dba.existing_article_file_names(related_articles).await?;
+    related_articles
+        .iter()
+        .find(|name| !existing.contains(name))
+        .map_or(Ok(()), |unknown| Err(ArticleCreateError::UnknownRelatedArticle(unknown.clone())))
+}
+
+/**
+ * every [[slug]] link in the text must exist, the link shows its title
+ */
+pub async fn resolve_internal_links(
+    dba: &DatabaseArticle,
+    text_raw: &str,
+) -> Result<HashMap<String, String>, ArticleCreateError> {
+    let names = text_processor::internal_links(text_raw);
+    let linked: HashMap<String, String> =
+        dba.linked_articles(&names).await?.into_iter().map(|a| (a.article_file_name, a.title)).collect();
+    names
+        .into_iter()
+        .find(|name| !linked.contains_key(name))
+        .map_or(Ok(linked), |unknown| Err(ArticleCreateError::UnknownInternalLink(unknown)))
+}
+
+/**
+ * every [[image:N]] and [[gallery:N,M]] of the text must be an image of the Article
+ */
+pub fn validate_image_references(text_raw: &str, images: &[ArticleImage]) -> Result<(), ArticleCreateError> {
+    text_processor::image_references(text_raw)
+        .into_iter()
+        .find(|number| !images.iter().any(|image| image.number == *number))
+        .map_or(Ok(()), |unknown| Err(ArticleCreateError::UnknownImage(unknown)))
+}
+
+/**
+ * every {{provider:id}} of the text must be a whitelisted provider with a valid id
+ */
+pub fn validate_embeds(text_raw: &str) -> Result<(), ArticleCreateError> {
+    for (provider, id) in text_processor::embeds(text_raw) {
+        text_validator::validate_embed(&provider, &id).map_err(ArticleCreateError::InvalidEmbed)?;
+    }
+    Ok(())
+}
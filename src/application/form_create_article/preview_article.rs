@@ -0,0 +1,158 @@
+use crate::application::form_create_article::create_article_parser;
+use crate::application::form_create_article::create_article_parser::{ArticleCreateError, ArticleUpload};
+use crate::application::form_edit_article::edit_article::may_edit;
+use crate::application::page_article::article;
+use crate::application::page_article::article::ArticleError;
+use crate::data::image_processor::ImageProcessorError;
+use crate::data::{image_processor, text_processor};
+use crate::db::database::SurrealError;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::{Article, ArticleImage};
+use crate::db::database_category::SurrealCategoryError;
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::{Multipart, Query, State};
+use axum::http::header;
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use surrealdb::types::Uuid;
+use thiserror::Error;
+use tracing::debug;
+use PreviewArticleError::{PreviewCategoryFailed, PreviewForbidden};
+
+#[derive(Debug, Error)]
+pub enum PreviewArticleError {
+    #[error("user may not edit article {0}")]
+    PreviewForbidden(String),
+
+    #[error("category unknown {0}")]
+    PreviewCategoryFailed(String),
+
+    #[error("article parser error {0}")]
+    PreviewArticleParser(#[from] ArticleCreateError),
+
+    #[error("image processing failed: {0}")]
+    PreviewImageProcessor(#[from] ImageProcessorError),
+
+    #[error("preview image error {0}")]
+    PreviewImageIo(#[from] std::io::Error),
+
+    #[error("database error {0}")]
+    PreviewDatabase(#[from] SurrealError),
+
+    #[error("surreal article error {0}")]
+    PreviewSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("surreal category error {0}")]
+    PreviewSurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("article error {0}")]
+    PreviewArticle(#[from] ArticleError),
+
+    #[error("render error")]
+    PreviewRender(#[from] askama::Error),
+}
+
+#[derive(Deserialize)]
+pub struct PreviewQuery {
+    // file name of the edited Article, missing for a new one
+    pub edit: Option<String>,
+}
+
+/**
+ * the Article page as it would look, from the data of the create or edit form
+ * - validated the same way as the form
+ * - nothing is stored, neither the Article nor files under web/
+ * - new images are processed in a temporary directory and inlined, new video and audio are left out
+ */
+pub async fn handle_preview_article(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Query(query): Query<PreviewQuery>,
+    multipart: Multipart,
+) -> Result<Response, PreviewArticleError> {
+    debug!("handle_preview_article: edit={:?}", query.edit);
+    let Some(user) = auth_session.user.clone() else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let existing = match query.edit {
+        Some(article_file_name) => {
+            let article = state.dba.article_by_file_name(&article_file_name).await?;
+            if !may_edit(&user, &article) {
+                return Err(PreviewForbidden(article_file_name));
+            }
+            Some(article)
+        }
+        None => None,
+    };
+
+    let mut article_data = create_article_parser::article_data(auth_session, multipart).await?;
+    if state.dbc.category_by_slug(&article_data.category).await?.is_none() {
+        return Err(PreviewCategoryFailed(article_data.category));
+    }
+    create_article_parser::validate_related_articles(&state.dba, &article_data.related_articles).await?;
+    article_data.internal_links =
+        create_article_parser::resolve_internal_links(&state.dba, &article_data.text_raw).await?;
+    // an edited Article keeps its video and audio
+    article_data.has_video = false;
+    article_data.has_audio = false;
+
+    let related_articles = state.dba.article_ids_by_file_names(&article_data.related_articles).await?;
+    let mut article = match existing {
+        Some(existing) => existing.edited(article_data.clone(), related_articles),
+        None => Article {
+            related_articles,
+            ..Article::try_from(article_data.clone())?
+        },
+    };
+    create_article_parser::validate_image_references(&article_data.text_raw, &article.images)?;
+    create_article_parser::validate_embeds(&article_data.text_raw)?;
+
+    let dir = std::env::temp_dir().join(format!("axiomatik-preview-{}", Uuid::new()));
+    fs::create_dir_all(&dir)?;
+    let inlined = inline_images(&dir, &article_data, &mut article);
+    let _ = fs::remove_dir_all(&dir);
+    inlined?;
+
+    let rendered = article::article_template(article, &state).await?.render()?;
+    let headers = [
+        (header::CACHE_CONTROL, "no-store"),
+        (header::HeaderName::from_static("x-robots-tag"), "noindex"),
+    ];
+    Ok((headers, Html(rendered)).into_response())
+}
+
+/*
+ * uploaded images replace their paths, images kept by the edit form are already under web/
+ */
+fn inline_images(dir: &Path, data: &ArticleUpload, article: &mut Article) -> Result<(), PreviewArticleError> {
+    if !data.image_data.is_empty() {
+        article.image_820_path = preview_image(dir, &data.image_data, &data.base_file_name, &data.image_ext)?;
+    }
+    for upload in data.images.iter().filter(|upload| upload.is_new()) {
+        let file_base = ArticleImage::file_base(&data.base_file_name, upload.number);
+        let path = preview_image(dir, &upload.data, &file_base, &upload.ext)?;
+        if let Some(image) = article.images.iter_mut().find(|image| image.number == upload.number) {
+            image.image_820_path = path;
+        }
+    }
+    // the text places the images
+    article.text = text_processor::process_text(&data.text_raw, &data.internal_links, &article.images);
+    Ok(())
+}
+
+/*
+ * the 820 px variant as a data URI
+ */
+fn preview_image(dir: &Path, img_data: &[u8], file_base: &str, ext: &str) -> Result<String, PreviewArticleError> {
+    image_processor::process_images_into(img_data, dir, file_base, ext)?;
+    let processed = fs::read(dir.join(format!("{}_image_820.{}", file_base, ext)))?;
+    let mime = if ext == "png" { "image/png" } else { "image/jpeg" };
+    Ok(format!("data:{};base64,{}", mime, STANDARD.encode(processed)))
+}
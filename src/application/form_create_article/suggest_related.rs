@@ -0,0 +1,71 @@
+use crate::data::similarity;
+use crate::db::database_article::SurrealArticleError;
+use crate::system::server::TheState;
+use axum::extract::State;
+use axum::{Form, Json};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+
+// older Articles are rarely related to a new one
+const CANDIDATES_LIMIT: u32 = 500;
+const SUGGESTIONS_LIMIT: usize = 5;
+
+#[derive(Debug, Error)]
+pub enum SuggestRelatedError {
+    #[error("surreal article error {0}")]
+    SuggestSurrealArticle(#[from] SurrealArticleError),
+}
+
+#[derive(Deserialize)]
+pub struct SuggestRelatedPayload {
+    pub title: String,
+    pub text: String,
+    // the edited Article is never related to itself
+    pub article_file_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SuggestionJson {
+    pub article_file_name: String,
+    pub title: String,
+    pub score: f64,
+}
+
+/*
+ * the title says most about the topic, it counts twice
+ */
+fn document(title: &str, text: &str) -> String {
+    format!("{title} {title} {text}")
+}
+
+/**
+ * published Articles most similar to the draft, best first
+ */
+pub async fn handle_suggest_related(
+    State(state): State<TheState>,
+    Form(payload): Form<SuggestRelatedPayload>,
+) -> Result<Json<Vec<SuggestionJson>>, SuggestRelatedError> {
+    debug!("handle_suggest_related: {}", payload.title);
+    let candidates: Vec<_> = state
+        .dba
+        .similar_article_candidates(CANDIDATES_LIMIT)
+        .await?
+        .into_iter()
+        .filter(|candidate| payload.article_file_name.as_ref() != Some(&candidate.article_file_name))
+        .collect();
+    let documents: Vec<String> = candidates.iter().map(|c| document(&c.title, &c.text_raw)).collect();
+
+    let ranked = similarity::rank(&document(&payload.title, &payload.text), &documents, SUGGESTIONS_LIMIT);
+    let suggestions = ranked
+        .into_iter()
+        .filter_map(|(i, score)| {
+            candidates.get(i).map(|candidate| SuggestionJson {
+                article_file_name: candidate.article_file_name.clone(),
+                title: candidate.title.clone(),
+                score,
+            })
+        })
+        .collect();
+    Ok(Json(suggestions))
+}
@@ -0,0 +1,399 @@
+use crate::application::form_create_article::create_article;
+use crate::application::form_create_article::create_article_parser;
+use crate::application::form_create_article::create_article_parser::{ArticleCreateError, ArticleUpload};
+use crate::application::page_archive::archive;
+use crate::application::page_article::article::ArticleError;
+use crate::application::page_category::category;
+use crate::data::audio_processor::AudioProcessorError;
+use crate::data::image_processor::ImageProcessorError;
+use crate::data::video_processor::VideoProcessorError;
+use crate::data::time::to_prague_local_input;
+use crate::data::{audio_processor, image_processor, video_processor};
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::{Article, ArticleImage, ArticleSource, PublishState};
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::db::database_system::SurrealSystemError;
+use crate::db::database_user::{Permission, User};
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::{Multipart, Path, State};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use std::fs;
+use thiserror::Error;
+use tracing::{debug, info};
+use EditArticleError::{CategoryFailed, Forbidden, PublishForbidden, Unpublish};
+
+#[derive(Error, Debug)]
+pub enum EditArticleError {
+    #[error("category unknown {0}")]
+    CategoryFailed(String),
+
+    #[error("only the author or a reviewer may edit article {0}")]
+    Forbidden(String),
+
+    #[error("published article {0} can't go back to a draft, review or schedule")]
+    Unpublish(String),
+
+    #[error("user {0} may only submit articles for review")]
+    PublishForbidden(String),
+
+    #[error("article edit failed: {0}")]
+    EditArticleParser(#[from] ArticleCreateError),
+
+    #[error("image processing failed: {0}")]
+    EditImageProcessor(#[from] ImageProcessorError),
+
+    #[error("audio processing failed: {0}")]
+    EditAudioProcessor(#[from] AudioProcessorError),
+
+    #[error("video processing failed: {0}")]
+    EditVideoProcessor(#[from] VideoProcessorError),
+
+    #[error("surreal article error {0}")]
+    EditSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("surreal system error {0}")]
+    EditSurrealSystem(#[from] SurrealSystemError),
+
+    #[error("surreal category error {0}")]
+    EditSurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("publish failed: {0}")]
+    EditPublish(#[from] ArticleError),
+
+    #[error("render error")]
+    EditRenderError(#[from] askama::Error),
+}
+
+#[derive(Template)]
+#[template(path = "application/form_edit_article/edit_article_template.html")]
+pub struct EditFormTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+
+    pub article_file_name: String,
+    pub author: String,
+    pub title: String,
+    pub text_raw: String,
+    pub short_text_raw: String,
+    pub image_desc: String,
+    pub image_alt: String,
+    pub image_credit: String,
+    pub image_source: String,
+    pub image_288_path: String,
+    pub images: Vec<ArticleImage>,
+    // a new image gets a number not used yet
+    pub next_image_number: u32,
+    pub sources: Vec<ArticleSource>,
+    pub next_source_number: u32,
+    pub has_video: bool,
+    pub has_audio: bool,
+    pub is_main: bool,
+    pub is_exclusive: bool,
+    pub is_live_blog: bool,
+    pub category: String,
+    pub categories: Vec<Category>,
+    pub related_articles: String,
+
+    pub is_published: bool,
+    pub may_publish: bool,
+    pub publish_state: String,
+    pub publish_at: String,
+    // empty if the Article doesn't expire
+    pub expires_at: String,
+}
+
+/*
+ * the author of the article or a reviewer
+ * - an author without the publish permission can't change a live article
+ */
+pub fn may_edit(user: &User, article: &Article) -> bool {
+    if user.role.can(Permission::EditAnyArticle) {
+        return true;
+    }
+    let live = matches!(article.publish_state, PublishState::Published | PublishState::Scheduled);
+    user.username == article.username && (!live || user.role.can(Permission::PublishArticle))
+}
+
+pub async fn show_article_edit_form(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+) -> Result<Response, EditArticleError> {
+    debug!("show_article_edit_form: {}", article_file_name);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let article = state.dba.article_by_file_name(&article_file_name).await?;
+    if !may_edit(&user, &article) {
+        return Err(Forbidden(article_file_name));
+    }
+
+    let is_published = article.is_published();
+    let related_articles = state.dba.article_file_names_by_ids(&article.related_articles).await?;
+    let categories = state.dbc.all_categories().await?;
+    Ok(Html(
+        EditFormTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+
+            article_file_name: article.article_file_name,
+            author: article.author,
+            title: article.title,
+            text_raw: article.text_raw,
+            short_text_raw: article.short_text_raw,
+            image_desc: article.image_desc,
+            image_alt: article.image_alt,
+            image_credit: article.image_credit,
+            image_source: article.image_source,
+            image_288_path: article.image_288_path,
+            next_image_number: article.images.iter().map(|image| image.number).max().unwrap_or_default() + 1,
+            images: article.images,
+            next_source_number: article.sources.iter().map(|source| source.number).max().unwrap_or_default() + 1,
+            sources: article.sources,
+            has_video: article.has_video,
+            has_audio: article.has_audio,
+            is_main: article.is_main,
+            is_exclusive: article.is_exclusive,
+            is_live_blog: article.is_live_blog,
+            category: article.category,
+            categories,
+            related_articles: related_articles.join(", "),
+
+            is_published,
+            may_publish: user.role.can(Permission::PublishArticle),
+            publish_state: match article.publish_state {
+                PublishState::Draft
+                | PublishState::ChangesRequested
+                | PublishState::Rejected
+                | PublishState::Expired => {
+                    "draft".into()
+                }
+                PublishState::InReview => "review".into(),
+                PublishState::Scheduled => "scheduled".into(),
+                PublishState::Published => "published".into(),
+            },
+            publish_at: article.publish_at.map(to_prague_local_input).unwrap_or_default(),
+            expires_at: article.expires_at.map(to_prague_local_input).unwrap_or_default(),
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+/**
+ * Update an already published Article
+ * - media are processed again only if replaced
+ * - the HTML is not rendered, only invalidated
+ */
+pub async fn edit_article(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+    multipart: Multipart,
+) -> Result<Response, EditArticleError> {
+    debug!("edit_article: {}", article_file_name);
+    let Some(user) = auth_session.user.clone() else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let article = state.dba.article_by_file_name(&article_file_name).await?;
+    if !may_edit(&user, &article) {
+        return Err(Forbidden(article_file_name));
+    }
+
+    /*
+     * Read request data
+     */
+    let mut article_data = create_article_parser::article_data(auth_session, multipart).await?;
+    if !create_article::may_set_state(&user, article_data.publish_state) {
+        return Err(PublishForbidden(user.username));
+    }
+    // the form has no state for a published Article, it stays public until it expires
+    if article.is_published() && article_data.publish_state != PublishState::Published {
+        return Err(Unpublish(article_file_name));
+    }
+    if state.dbc.category_by_slug(&article_data.category).await?.is_none() {
+        return Err(CategoryFailed(article_data.category));
+    }
+
+    // a changed title renames the Article, another article may have the same title
+    article_data.base_file_name =
+        state.dba.unique_base_file_name(&article_data.base_file_name, Some(article.uuid)).await?;
+    let base = article_data.base_file_name.clone();
+
+    create_article_parser::validate_related_articles(&state.dba, &article_data.related_articles).await?;
+    article_data.internal_links =
+        create_article_parser::resolve_internal_links(&state.dba, &article_data.text_raw).await?;
+
+    let related_articles = state.dba.article_ids_by_file_names(&article_data.related_articles).await?;
+    let old_article = article.clone();
+    let mut edited = article.edited(article_data.clone(), related_articles);
+    create_article_parser::validate_image_references(&article_data.text_raw, &edited.images)?;
+    create_article_parser::validate_embeds(&article_data.text_raw)?;
+    if edited.publish_state != old_article.publish_state {
+        edited.record_transition(old_article.publish_state, &user.username, "");
+    }
+
+    /*
+     * process only replaced media
+     */
+    if !article_data.image_data.is_empty() {
+        debug!("process images");
+        image_processor::process_images(
+            &article_data.image_data,
+            &base,
+            &article_data.image_ext,
+        )?;
+        debug!("process images done");
+    }
+
+    for image in article_data.images.iter().filter(|image| image.is_new()) {
+        debug!("process gallery image {}", image.number);
+        image_processor::process_images(&image.data, &ArticleImage::file_base(&base, image.number), &image.ext)?;
+    }
+
+    if article_data.has_audio {
+        debug!("process audio");
+        audio_processor::process_valid_audio(
+            &article_data.audio_data,
+            &format!("{}_audio.{}", base, article_data.audio_ext),
+        )?;
+        debug!("process audio done");
+    }
+
+    if article_data.has_video {
+        debug!("process video");
+        video_processor::process_video(
+            &article_data.video_data,
+            &format!("{}_video.{}", base, article_data.video_ext),
+        )?;
+        debug!("process video done");
+    }
+
+    /*
+     * store Article data, keep the previous version
+     */
+    state.dba.create_revision(old_article.clone(), &user.username).await?;
+    state.dba.update_article(edited.clone()).await?;
+    info!("article edited: {}", article_file_name);
+
+    // the stored Article doesn't point to the old files anymore
+    remove_replaced_media(&old_article, &edited, &article_data);
+
+    invalidate_changed(&state, &old_article, &edited).await?;
+
+    /*
+     * don't render anything
+     * web router manages render trigger
+     */
+    Ok(Redirect::to("/account").into_response())
+}
+
+/**
+ * invalidate everything showing a changed Article
+ * - used on edit and on restore of a revision
+ */
+pub async fn invalidate_changed(
+    state: &TheState,
+    old_article: &Article,
+    article: &Article,
+) -> Result<(), EditArticleError> {
+    let article_file_name = article.article_file_name.clone();
+    let renamed = old_article.article_file_name != article_file_name;
+
+    if !old_article.is_published() {
+        // a draft or scheduled article, nothing was rendered yet
+        if article.is_published() {
+            create_article::publish_article(state, article).await?;
+        }
+        return Ok(());
+    }
+
+    archive::invalidate_archive(state, old_article.created);
+    archive::invalidate_archive(state, article.created);
+    state.dv.author_invalidate(&article.username);
+
+    if article.is_published() && old_article.category == article.category {
+        // the article stays on the same category page
+        let position = state.dba.article_position_in_category(article).await?;
+        state.dv.category_page_invalidate(&article.category, category::category_page_of(position));
+    } else {
+        // the following articles moved by one
+        state.dv.category_invalidate(&old_article.category);
+        state.dv.category_invalidate(&article.category);
+    }
+
+    if renamed {
+        // the old URL is redirected by the web router from now on
+        let old_file_name = &old_article.article_file_name;
+        info!("article renamed {} -> {}", old_file_name, article_file_name);
+        state.dbs.delete_article_record(old_file_name).await?;
+        let _ = fs::remove_file(format!("web/{}", old_file_name));
+        state.dba.rename_article_views(old_file_name, &article_file_name).await?;
+        state.dbs.create_article_record(article_file_name.clone()).await?;
+    } else {
+        state.dbs.invalidate_article(article_file_name.clone()).await?;
+    }
+    state.dv.index_invalidate();
+    state.dv.news_invalidate();
+    // title or slug on opravy.html
+    state.dv.corrections_invalidate();
+
+    // invalidate related articles, they show the title of this one
+    let mut related_ids = old_article.related_articles.clone();
+    for related_article in &article.related_articles {
+        if !related_ids.contains(related_article) {
+            related_ids.push(*related_article);
+        }
+    }
+    for related_article in state.dba.article_file_names_by_ids(&related_ids).await? {
+        info!("invalidate related article {}", related_article);
+        state.dbs.invalidate_article(related_article).await?;
+    }
+
+    // add bidirectional relationship for newly related articles
+    for related_article in
+        article.related_articles.iter().filter(|r| !old_article.related_articles.contains(r))
+    {
+        state.dba.add_related_article(*related_article, article.uuid).await?;
+    }
+    Ok(())
+}
+
+/*
+ * remove media files the edited Article doesn't use anymore
+ */
+fn remove_replaced_media(old_article: &Article, edited: &Article, article_data: &ArticleUpload) {
+    if !article_data.image_data.is_empty() {
+        remove_replaced(&old_article.image_50_path, &edited.image_50_path);
+        remove_replaced(&old_article.image_288_path, &edited.image_288_path);
+        remove_replaced(&old_article.image_440_path, &edited.image_440_path);
+        remove_replaced(&old_article.image_820_path, &edited.image_820_path);
+    }
+    for old_image in &old_article.images {
+        let new_paths = edited.images.iter().find(|image| image.number == old_image.number).map(ArticleImage::paths);
+        for (i, old_path) in old_image.paths().iter().enumerate() {
+            remove_replaced(old_path, new_paths.map_or("", |paths| paths[i]));
+        }
+    }
+    if article_data.has_audio {
+        remove_replaced(&old_article.audio_path, &edited.audio_path);
+    }
+    if article_data.has_video {
+        remove_replaced(&old_article.video_path, &edited.video_path);
+    }
+}
+
+/*
+ * a media file of a different extension was uploaded, the old one isn't used anymore
+ */
+fn remove_replaced(old_path: &str, new_path: &str) {
+    if !old_path.is_empty() && old_path != new_path {
+        let _ = fs::remove_file(format!("web/{}", old_path));
+    }
+}
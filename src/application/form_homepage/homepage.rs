@@ -0,0 +1,246 @@
+use crate::application::page_index::index::TOP_SLOTS;
+use crate::data::library;
+use crate::data::time::from_prague_local_input;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_category::SurrealCategoryError;
+use crate::db::database_homepage::{HomepageSlot, SurrealHomepageError};
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::{Path, State};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::Form;
+use chrono::Utc;
+use serde::Deserialize;
+use surrealdb::types::Uuid;
+use thiserror::Error;
+use tracing::{debug, info};
+use HomepageError::{ArticleNotPublished, InvalidPinnedUntil, UnknownSlot};
+
+const TOP_SLOT_NAMES: [&str; TOP_SLOTS] = ["main", "second", "third"];
+const TOP_SLOT_LABELS: [&str; TOP_SLOTS] = ["Hlavní článek", "Druhý článek", "Třetí článek"];
+
+#[derive(Debug, Error)]
+pub enum HomepageError {
+    #[error("unknown homepage slot {0}")]
+    UnknownSlot(String),
+
+    #[error("article {0} is not published")]
+    ArticleNotPublished(String),
+
+    #[error("invalid pinned until {0}, use a future time")]
+    InvalidPinnedUntil(String),
+
+    #[error("surreal homepage error {0}")]
+    HomepageSurreal(#[from] SurrealHomepageError),
+
+    #[error("surreal article error {0}")]
+    HomepageSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("surreal category error {0}")]
+    HomepageSurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("render error")]
+    HomepageRender(#[from] askama::Error),
+}
+
+#[derive(Deserialize)]
+pub struct PinPayload {
+    // main, second, third or the slug of a section
+    pub slot: String,
+    pub article_file_name: String,
+    // datetime-local input, Prague time, empty until unpinned
+    #[serde(default)]
+    pub pinned_until: String,
+}
+
+pub struct SlotOption {
+    pub value: String,
+    pub label: String,
+}
+
+pub struct LayoutRow {
+    pub uuid: String,
+    pub slot_label: String,
+    pub article_file_name: String,
+    pub title: String,
+    pub pinned_until: String,
+}
+
+#[derive(Template)]
+#[template(path = "application/form_homepage/homepage_template.html")]
+pub struct HomepageTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+    pub rows: Vec<LayoutRow>,
+    pub slot_options: Vec<SlotOption>,
+}
+
+pub async fn show_homepage_layout(State(state): State<TheState>) -> Result<Response, HomepageError> {
+    debug!("show_homepage_layout()");
+    let mut slot_options: Vec<SlotOption> = TOP_SLOT_NAMES
+        .iter()
+        .zip(TOP_SLOT_LABELS)
+        .map(|(value, label)| SlotOption { value: (*value).into(), label: label.into() })
+        .collect();
+    for category in state.dbc.homepage_sections().await? {
+        slot_options.push(SlotOption { value: category.slug, label: format!("Sekce {}", category.name) });
+    }
+
+    let mut rows = Vec::new();
+    for slot in state.dbh.all_slots().await? {
+        let (article_file_name, title) = match state.dba.short_article_by_id(slot.article_uuid).await? {
+            Some(article) => (article.article_file_name, article.title),
+            None => (String::new(), "nepublikovaný článek".into()),
+        };
+        let slot_label = if slot.section.is_empty() {
+            usize::try_from(slot.position)
+                .ok()
+                .and_then(|p| TOP_SLOT_LABELS.get(p))
+                .map_or_else(String::new, |label| (*label).to_string())
+        } else {
+            format!("Sekce {}", state.dbc.category_name(&slot.section).await?)
+        };
+        rows.push(LayoutRow {
+            uuid: slot.uuid.to_string(),
+            slot_label,
+            article_file_name,
+            title,
+            pinned_until: slot
+                .pinned_until
+                .map_or_else(|| "do odepnutí".into(), library::display_date_time),
+        });
+    }
+
+    Ok(Html(
+        HomepageTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            rows,
+            slot_options,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+/**
+ * pin an Article to a slot
+ * - a top slot holds one Article, the previous one is unpinned
+ * - a section highlight is added after the existing ones
+ */
+pub async fn handle_pin(
+    State(state): State<TheState>,
+    Form(payload): Form<PinPayload>,
+) -> Result<Response, HomepageError> {
+    debug!("handle_pin: {} {}", payload.slot, payload.article_file_name);
+
+    let article_file_name = payload.article_file_name.trim().to_string();
+    let article = state
+        .dba
+        .article_by_file_name_optional(&article_file_name)
+        .await?
+        .filter(|article| article.is_published())
+        .ok_or_else(|| ArticleNotPublished(article_file_name.clone()))?;
+
+    let pinned_until = if payload.pinned_until.trim().is_empty() {
+        None
+    } else {
+        let until = from_prague_local_input(&payload.pinned_until)
+            .filter(|until| *until > Utc::now())
+            .ok_or_else(|| InvalidPinnedUntil(payload.pinned_until.clone()))?;
+        Some(until)
+    };
+
+    let all = state.dbh.all_slots().await?;
+    let (section, position) = if let Some(top) = TOP_SLOT_NAMES.iter().position(|s| *s == payload.slot) {
+        let position = i64::try_from(top).unwrap_or_default();
+        (String::new(), position)
+    } else if state.dbc.homepage_sections().await?.iter().any(|category| category.slug == payload.slot) {
+        let last = all.iter().filter(|s| s.section == payload.slot).map(|s| s.position).max();
+        (payload.slot.clone(), last.map_or(0, |p| p + 1))
+    } else {
+        return Err(UnknownSlot(payload.slot));
+    };
+
+    // an Article is pinned once in the same part of the page, a top slot holds one Article
+    for slot in all.iter().filter(|s| s.section == section) {
+        if slot.article_uuid == article.uuid || (section.is_empty() && slot.position == position) {
+            state.dbh.delete_slot(slot.uuid).await?;
+        }
+    }
+
+    state
+        .dbh
+        .create_slot(HomepageSlot {
+            uuid: Uuid::new(),
+            section,
+            position,
+            article_uuid: article.uuid,
+            pinned_until,
+        })
+        .await?;
+    info!("pinned {} to {}", article_file_name, payload.slot);
+
+    state.dv.index_invalidate();
+    Ok(Redirect::to("/homepage").into_response())
+}
+
+pub async fn handle_unpin(
+    State(state): State<TheState>,
+    Path(uuid): Path<Uuid>,
+) -> Result<Response, HomepageError> {
+    debug!("handle_unpin: {}", uuid);
+    state.dbh.delete_slot(uuid).await?;
+    state.dv.index_invalidate();
+    Ok(Redirect::to("/homepage").into_response())
+}
+
+pub async fn handle_move_up(
+    State(state): State<TheState>,
+    Path(uuid): Path<Uuid>,
+) -> Result<Response, HomepageError> {
+    move_slot(&state, uuid, true).await?;
+    Ok(Redirect::to("/homepage").into_response())
+}
+
+pub async fn handle_move_down(
+    State(state): State<TheState>,
+    Path(uuid): Path<Uuid>,
+) -> Result<Response, HomepageError> {
+    move_slot(&state, uuid, false).await?;
+    Ok(Redirect::to("/homepage").into_response())
+}
+
+/*
+ * a top Article moves to the neighbouring slot, even an empty one
+ * a section highlight swaps with the neighbouring highlight
+ */
+async fn move_slot(state: &TheState, uuid: Uuid, up: bool) -> Result<(), HomepageError> {
+    debug!("move_slot: {} up={}", uuid, up);
+    let slot = state.dbh.slot_by_id(uuid).await?;
+    let siblings: Vec<HomepageSlot> =
+        state.dbh.all_slots().await?.into_iter().filter(|s| s.section == slot.section).collect();
+
+    let target = if slot.section.is_empty() {
+        let target = if up { slot.position - 1 } else { slot.position + 1 };
+        usize::try_from(target).is_ok_and(|t| t < TOP_SLOTS).then_some(target)
+    } else {
+        let index = siblings.iter().position(|s| s.uuid == slot.uuid).unwrap_or_default();
+        let neighbour = if up { index.checked_sub(1) } else { Some(index + 1) };
+        neighbour.and_then(|i| siblings.get(i)).map(|s| s.position)
+    };
+    let Some(target) = target else {
+        // already first or last
+        return Ok(());
+    };
+
+    if let Some(other) = siblings.iter().find(|s| s.position == target) {
+        state.dbh.update_position(other.uuid, slot.position).await?;
+    }
+    state.dbh.update_position(slot.uuid, target).await?;
+
+    state.dv.index_invalidate();
+    Ok(())
+}
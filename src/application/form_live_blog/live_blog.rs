@@ -0,0 +1,187 @@
+use crate::application::form_edit_article::edit_article;
+use crate::data::image_extractor::{extract_image_data, ImageExtractorError};
+use crate::data::image_processor;
+use crate::data::image_processor::ImageProcessorError;
+use crate::data::image_validator::{validate_image_extension, ImageValidationError};
+use crate::data::text_extractor::{extract_optional_string, extract_required_text, TextExtractorError};
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::{Article, LiveBlogEntry};
+use crate::db::database_system::SurrealSystemError;
+use crate::db::database_user::User;
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::multipart::MultipartError;
+use axum::extract::{Multipart, Path, State};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use chrono::Utc;
+use surrealdb::types::Uuid;
+use thiserror::Error;
+use tracing::{debug, info};
+use LiveBlogError::{Forbidden, ImageDescriptionRequired, NotLiveBlog, NotPublished, TextRequired};
+
+#[derive(Debug, Error)]
+pub enum LiveBlogError {
+    #[error("article {0} is not a live blog")]
+    NotLiveBlog(String),
+
+    #[error("article {0} is not published yet")]
+    NotPublished(String),
+
+    #[error("user {0} may not add entries to this article")]
+    Forbidden(String),
+
+    #[error("entry text is required")]
+    TextRequired,
+
+    #[error("image description is required")]
+    ImageDescriptionRequired,
+
+    #[error("multipart error {0}")]
+    LiveBlogMultipart(#[from] MultipartError),
+
+    #[error("text extraction error {0}")]
+    LiveBlogTextExtractor(#[from] TextExtractorError),
+
+    #[error("image extraction error {0}")]
+    LiveBlogImageExtractor(#[from] ImageExtractorError),
+
+    #[error("image validation error {0}")]
+    LiveBlogImageValidation(#[from] ImageValidationError),
+
+    #[error("image processor error {0}")]
+    LiveBlogImageProcessor(#[from] ImageProcessorError),
+
+    #[error("surreal article error {0}")]
+    LiveBlogSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("surreal system error {0}")]
+    LiveBlogSurrealSystem(#[from] SurrealSystemError),
+
+    #[error("render error")]
+    LiveBlogRender(#[from] askama::Error),
+}
+
+#[derive(Template)]
+#[template(path = "application/form_live_blog/live_blog_template.html")]
+pub struct LiveBlogFormTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+    pub article_file_name: String,
+    pub title: String,
+    pub entries: Vec<LiveBlogEntry>,
+}
+
+/*
+ * whoever may edit the Article may add entries to it
+ */
+async fn live_blog_article(
+    state: &TheState,
+    user: &User,
+    article_file_name: &str,
+) -> Result<Article, LiveBlogError> {
+    let article = state.dba.article_by_file_name(article_file_name).await?;
+    if !article.is_live_blog {
+        return Err(NotLiveBlog(article_file_name.into()));
+    }
+    if !edit_article::may_edit(user, &article) {
+        return Err(Forbidden(user.username.clone()));
+    }
+    Ok(article)
+}
+
+pub async fn show_live_blog_form(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+) -> Result<Response, LiveBlogError> {
+    debug!("show_live_blog_form: {}", article_file_name);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let article = live_blog_article(&state, &user, &article_file_name).await?;
+    let entries = state.dba.live_blog_entries(article.uuid).await?;
+    Ok(Html(
+        LiveBlogFormTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            article_file_name: article.article_file_name,
+            title: article.title,
+            entries,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+/**
+ * a new entry goes live right away
+ * - only the live blog Article is rendered again
+ */
+pub async fn handle_add_entry(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+    mut multipart: Multipart,
+) -> Result<Response, LiveBlogError> {
+    debug!("handle_add_entry: {}", article_file_name);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let article = live_blog_article(&state, &user, &article_file_name).await?;
+    if !article.is_published() {
+        return Err(NotPublished(article_file_name));
+    }
+
+    let mut text_raw = String::new();
+    let mut image = None;
+    let mut image_desc = String::new();
+    while let Some(field) = multipart.next_field().await? {
+        match field.name() {
+            Some("text") => text_raw = extract_required_text(field).await?,
+            Some("image") => image = Some(extract_image_data(field).await?),
+            Some("image_desc") => image_desc = extract_optional_string(field).await?.unwrap_or_default(),
+            _ => {}
+        }
+    }
+    if text_raw.trim().is_empty() {
+        return Err(TextRequired);
+    }
+
+    let uuid = Uuid::new();
+    let image_path = match image.filter(|(data, _)| !data.is_empty()) {
+        Some((image_data, image_ext)) => {
+            validate_image_extension(&image_ext)?;
+            if image_desc.trim().is_empty() {
+                return Err(ImageDescriptionRequired);
+            }
+            // the entry has no slug, its media are named after the Article and the entry
+            let base = article.article_file_name.trim_end_matches(".html");
+            let file_base = format!("{}_live_{}", base, uuid.into_inner().simple());
+            image_processor::process_live_blog_image(&image_data, &file_base, &image_ext)?
+        }
+        None => String::new(),
+    };
+
+    state
+        .dba
+        .create_live_blog_entry(LiveBlogEntry {
+            uuid,
+            article_uuid: article.uuid,
+            created: Utc::now(),
+            author: user.author_name.clone(),
+            username: user.username.clone(),
+            text: text_raw.trim().into(),
+            image_path,
+            image_desc: image_desc.trim().into(),
+        })
+        .await?;
+    info!("live blog entry added: {} by {}", article_file_name, user.username);
+
+    state.dbs.invalidate_article(article.article_file_name).await?;
+    Ok(Redirect::to(&format!("/live-blog/{}", article_file_name)).into_response())
+}
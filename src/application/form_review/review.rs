@@ -0,0 +1,148 @@
+use crate::application::form_create_article::create_article;
+use crate::application::page_article::article::ArticleError;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::{PublishState, ReviewArticleData};
+use crate::system::router_app::AuthSession;
+use crate::system::server::TheState;
+use askama::Template;
+use axum::extract::{Path, State};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::Form;
+use chrono::Utc;
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::{debug, info};
+use ReviewError::{CommentRequired, NotInReview};
+
+#[derive(Debug, Error)]
+pub enum ReviewError {
+    #[error("article {0} is not waiting for a review")]
+    NotInReview(String),
+
+    #[error("a comment for the author is required")]
+    CommentRequired,
+
+    #[error("surreal article error {0}")]
+    ReviewSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("publish failed: {0}")]
+    ReviewPublish(#[from] ArticleError),
+
+    #[error("render error")]
+    ReviewRenderError(#[from] askama::Error),
+}
+
+#[derive(Deserialize)]
+pub struct ReviewPayload {
+    #[serde(default)]
+    pub comment: String,
+}
+
+#[derive(Template)]
+#[template(path = "application/form_review/review_template.html")]
+pub struct ReviewTemplate {
+    pub date: String,
+    pub name_day: String,
+    pub weather: String,
+    pub articles: Vec<ReviewArticleData>,
+}
+
+pub async fn show_review_queue(State(state): State<TheState>) -> Result<Response, ReviewError> {
+    debug!("show_review_queue()");
+    let articles = state.dba.articles_in_review().await?;
+    Ok(Html(
+        ReviewTemplate {
+            date: state.ds.date(),
+            name_day: state.ds.name_day(),
+            weather: state.ds.weather(),
+            articles,
+        }
+        .render()?,
+    )
+    .into_response())
+}
+
+/**
+ * the Article goes live right away
+ * - the HTML is not rendered, only invalidated
+ */
+pub async fn handle_approve(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+    Form(payload): Form<ReviewPayload>,
+) -> Result<Response, ReviewError> {
+    debug!("handle_approve: {}", article_file_name);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let mut article = state.dba.article_by_file_name(&article_file_name).await?;
+    if article.publish_state != PublishState::InReview {
+        return Err(NotInReview(article_file_name));
+    }
+
+    article.transition(PublishState::Published, &user.username, payload.comment.trim());
+    // listings are ordered by created, the Article is new from now on
+    article.created = Utc::now();
+    article.updated = article.created;
+    state.dba.update_article(article.clone()).await?;
+    info!("article approved: {} by {}", article_file_name, user.username);
+
+    create_article::publish_article(&state, &article).await?;
+    Ok(Redirect::to("/review").into_response())
+}
+
+/**
+ * back to the author, who may edit and submit again
+ */
+pub async fn handle_request_changes(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+    Form(payload): Form<ReviewPayload>,
+) -> Result<Response, ReviewError> {
+    review_decision(state, auth_session, article_file_name, payload, PublishState::ChangesRequested)
+        .await
+}
+
+pub async fn handle_reject(
+    State(state): State<TheState>,
+    auth_session: AuthSession,
+    Path(article_file_name): Path<String>,
+    Form(payload): Form<ReviewPayload>,
+) -> Result<Response, ReviewError> {
+    review_decision(state, auth_session, article_file_name, payload, PublishState::Rejected).await
+}
+
+/*
+ * the author has to know why, the comment is required
+ */
+async fn review_decision(
+    state: TheState,
+    auth_session: AuthSession,
+    article_file_name: String,
+    payload: ReviewPayload,
+    to: PublishState,
+) -> Result<Response, ReviewError> {
+    debug!("review_decision: {} {:?}", article_file_name, to);
+    let Some(user) = auth_session.user else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+
+    let comment = payload.comment.trim();
+    if comment.is_empty() {
+        return Err(CommentRequired);
+    }
+
+    let mut article = state.dba.article_by_file_name(&article_file_name).await?;
+    if article.publish_state != PublishState::InReview {
+        return Err(NotInReview(article_file_name));
+    }
+
+    article.transition(to, &user.username, comment);
+    state.dba.update_article(article).await?;
+    info!("article reviewed: {} {:?} by {}", article_file_name, to, user.username);
+
+    Ok(Redirect::to("/review").into_response())
+}
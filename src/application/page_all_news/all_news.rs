@@ -1,11 +1,15 @@
+use crate::application::page_archive::archive::ArchivePage;
 use crate::data::processor;
 use crate::data::processor::ProcessorError;
+use crate::data::time;
 use crate::db::database::SurrealError;
 use crate::db::database_article::SurrealArticleError;
 use crate::db::database_article_data::{MiniArticleData, ShortArticleData};
-use crate::db::database_system::SurrealSystemError;
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::db::database_system::{BreakingNews, SurrealSystemError};
 use crate::system::server::TheState;
 use askama::Template;
+use chrono::{Datelike, Utc};
 use thiserror::Error;
 use NewsError::CreateCategoryError;
 
@@ -25,6 +29,9 @@ pub enum NewsError {
 
     #[error("create category database system error {0}")]
     SurrealSystem(#[from] SurrealSystemError),
+
+    #[error("create category database category error {0}")]
+    SurrealCategory(#[from] SurrealCategoryError),
 }
 
 #[derive(Template)]
@@ -33,32 +40,44 @@ pub struct NewsTemplate {
     pub date: String,
     pub weather: String,
     pub name_day: String,
+    pub breaking_news: Option<BreakingNews>,
+    pub navigation: Vec<Category>,
     pub articles_most_read: Vec<MiniArticleData>,
-    pub z_republiky: Vec<ShortArticleData>,
-    pub ze_zahranici: Vec<ShortArticleData>,
-    pub technologie: Vec<ShortArticleData>,
-    pub veda: Vec<ShortArticleData>,
-    pub finance: Vec<ShortArticleData>,
+    pub sections: Vec<NewsSection>,
+    // archive of the current year
+    pub archive_url: String,
+}
+
+/*
+ * latest articles of one category
+ */
+pub struct NewsSection {
+    pub category: Category,
+    pub articles: Vec<ShortArticleData>,
 }
 
 pub async fn render_news(state: &TheState) -> Result<(), NewsError> {
     let articles_most_read: Vec<MiniArticleData> = state.dba.most_read_all_by_views().await?;
-    let z_republiky = state.dba.articles_by_category("republika", 10).await?;
-    let ze_zahranici = state.dba.articles_by_category("zahranici", 10).await?;
-    let technologie = state.dba.articles_by_category("technologie", 10).await?;
-    let veda = state.dba.articles_by_category("veda", 10).await?;
-    let finance = state.dba.articles_by_category("finance", 10).await?;
+    let navigation = state.dbc.navigation().await?;
+
+    let mut sections = Vec::new();
+    for category in &navigation {
+        let articles = state.dba.articles_by_category(&category.slug, 10).await?;
+        sections.push(NewsSection {
+            category: category.clone(),
+            articles,
+        });
+    }
 
     let news = NewsTemplate {
         date: state.ds.date(),
         weather: state.ds.weather(),
         name_day: state.ds.name_day(),
+        breaking_news: state.ds.breaking_news(),
+        navigation,
         articles_most_read,
-        z_republiky,
-        ze_zahranici,
-        technologie,
-        veda,
-        finance,
+        sections,
+        archive_url: ArchivePage::Year(time::to_prague_time(Utc::now()).year()).url(),
     };
     match news.render() {
         Ok(rendered_html) => {
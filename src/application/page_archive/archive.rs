@@ -0,0 +1,301 @@
+use crate::data::processor::ProcessorError;
+use crate::data::{library, processor, time};
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::ShortArticleData;
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::system::server::TheState;
+use askama::Template;
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use std::path::Path;
+use thiserror::Error;
+use ArchiveError::CreateArchiveError;
+
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    #[error("create archive error")]
+    CreateArchiveError,
+
+    #[error("create archive processor error {0}")]
+    ProcessorError(#[from] ProcessorError),
+
+    #[error("create archive database error {0}")]
+    SurrealArticle(#[from] SurrealArticleError),
+
+    #[error("create archive database category error {0}")]
+    SurrealCategory(#[from] SurrealCategoryError),
+}
+
+/**
+ * archive of published Articles, dates are in Prague time
+ * - /archiv/2025.html
+ * - /archiv/2025/listopad.html
+ * - /archiv/2025/11/17.html
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchivePage {
+    Year(i32),
+    Month(i32, u32),
+    Day(NaiveDate),
+}
+
+impl ArchivePage {
+    /**
+     * path without the leading slash, only the canonical URL of a page is accepted
+     */
+    pub fn from_path(path: &str) -> Option<Self> {
+        let rest = path.strip_prefix("archiv/")?.strip_suffix(".html")?;
+        let parts: Vec<&str> = rest.split('/').collect();
+        let page = match parts.as_slice() {
+            [year] => Self::Year(year.parse().ok()?),
+            [year, month] => Self::Month(year.parse().ok()?, library::czech_month_from_name(month)?),
+            [year, month, day] => Self::Day(NaiveDate::from_ymd_opt(
+                year.parse().ok()?,
+                month.parse().ok()?,
+                day.parse().ok()?,
+            )?),
+            _ => return None,
+        };
+        // one file per page, no 2025/1/5.html next to 2025/01/05.html
+        let canonical = page.url().strip_prefix('/') == Some(path);
+        (canonical && page.range().is_some()).then_some(page)
+    }
+
+    pub fn url(&self) -> String {
+        match self {
+            Self::Year(year) => format!("/archiv/{}.html", year),
+            Self::Month(year, month) => {
+                format!("/archiv/{}/{}.html", year, library::get_czech_month(*month))
+            }
+            Self::Day(date) => {
+                format!("/archiv/{}/{:02}/{:02}.html", date.year(), date.month(), date.day())
+            }
+        }
+    }
+
+    pub fn year(&self) -> i32 {
+        match self {
+            Self::Year(year) | Self::Month(year, _) => *year,
+            Self::Day(date) => date.year(),
+        }
+    }
+
+    /*
+     * the first day of the page and the first day after it
+     */
+    fn range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        match *self {
+            Self::Year(year) => Some((
+                NaiveDate::from_ymd_opt(year, 1, 1)?,
+                NaiveDate::from_ymd_opt(year.checked_add(1)?, 1, 1)?,
+            )),
+            Self::Month(year, month) => {
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                Some((first, first.checked_add_months(Months::new(1))?))
+            }
+            Self::Day(date) => Some((date, date.succ_opt()?)),
+        }
+    }
+
+    fn title(&self) -> String {
+        match self {
+            Self::Year(year) => format!("Archiv {}", year),
+            Self::Month(year, month) => {
+                format!("Archiv — {} {}", library::get_czech_month_capital(*month), year)
+            }
+            Self::Day(date) => format!(
+                "Archiv — {}. {} {}",
+                date.day(),
+                library::get_czech_month_genitive(date.month()),
+                date.year()
+            ),
+        }
+    }
+}
+
+pub struct ArchiveMonth {
+    pub name: &'static str,
+    // None if nothing was published that month
+    pub url: Option<String>,
+    pub count: usize,
+    pub current: bool,
+}
+
+pub struct ArchiveDay {
+    // 0 for the padding before the first and after the last day
+    pub day: u32,
+    // None if nothing was published that day
+    pub url: Option<String>,
+    pub current: bool,
+}
+
+#[derive(Template)]
+#[template(path = "application/page_archive/archive_template.html")]
+pub struct ArchiveTemplate {
+    pub date: String,
+    pub weather: String,
+    pub name_day: String,
+    pub navigation: Vec<Category>,
+    pub title: String,
+    pub year: i32,
+    pub year_url: String,
+    pub months: Vec<ArchiveMonth>,
+    // calendar of the month, empty on the year page
+    pub weeks: Vec<Vec<ArchiveDay>>,
+    // articles of the day, empty on the year and month page
+    pub articles: Vec<ShortArticleData>,
+}
+
+/**
+ * render the archive page
+ * - returns false and renders nothing if nothing was published in its range
+ */
+pub async fn render_archive(state: &TheState, page: ArchivePage) -> Result<bool, ArchiveError> {
+    let year = page.year();
+    let (year_first, year_after) = ArchivePage::Year(year).range().ok_or(CreateArchiveError)?;
+    let (first, after) = page.range().ok_or(CreateArchiveError)?;
+
+    // every page shows the calendar of the whole year
+    #[rustfmt::skip]
+    let dates: Vec<NaiveDate> = state.dba.published_created_between(utc(year_first)?, utc(year_after)?).await?
+        .into_iter()
+        .map(|created| time::to_prague_time(created).date_naive())
+        .collect();
+    if !dates.iter().any(|d| *d >= first && *d < after) {
+        return Ok(false);
+    }
+
+    let (current_month, current_day) = match page {
+        ArchivePage::Year(_) => (None, None),
+        ArchivePage::Month(_, month) => (Some(month), None),
+        ArchivePage::Day(date) => (Some(date.month()), Some(date)),
+    };
+
+    let months = (1..=12)
+        .map(|month| {
+            let count = dates.iter().filter(|d| d.month() == month).count();
+            ArchiveMonth {
+                name: library::get_czech_month_capital(month),
+                url: (count > 0).then(|| ArchivePage::Month(year, month).url()),
+                count,
+                current: current_month == Some(month),
+            }
+        })
+        .collect();
+
+    let weeks = current_month.map_or_else(Vec::new, |month| calendar(year, month, &dates, current_day));
+
+    let articles = match page {
+        ArchivePage::Day(_) => state.dba.articles_created_between(utc(first)?, utc(after)?).await?,
+        _ => Vec::new(),
+    };
+
+    let archive_template = ArchiveTemplate {
+        date: state.ds.date(),
+        weather: state.ds.weather(),
+        name_day: state.ds.name_day(),
+        navigation: state.dbc.navigation().await?,
+        title: page.title(),
+        year,
+        year_url: ArchivePage::Year(year).url(),
+        months,
+        weeks,
+        articles,
+    };
+    match archive_template.render() {
+        Ok(rendered_html) => {
+            let url = page.url();
+            let path = url.strip_prefix('/').unwrap_or(&url);
+            if let Some(dir) = Path::new(path).parent().and_then(|p| p.to_str()) {
+                processor::create_web_dir(dir)?;
+            }
+            processor::save_web_file(rendered_html, path)?;
+            Ok(true)
+        }
+        Err(_) => Err(CreateArchiveError),
+    }
+}
+
+/**
+ * an Article was published, changed or removed
+ */
+pub fn invalidate_archive(state: &TheState, created: DateTime<Utc>) {
+    state.dv.archive_year_invalidate(time::to_prague_time(created).year());
+}
+
+fn utc(date: NaiveDate) -> Result<DateTime<Utc>, ArchiveError> {
+    time::prague_midnight(date).ok_or(CreateArchiveError)
+}
+
+/*
+ * weeks of the month, starting on Monday
+ */
+fn calendar(year: i32, month: u32, dates: &[NaiveDate], current: Option<NaiveDate>) -> Vec<Vec<ArchiveDay>> {
+    let empty = || ArchiveDay {
+        day: 0,
+        url: None,
+        current: false,
+    };
+    let mut weeks = Vec::new();
+    let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+        return weeks;
+    };
+
+    let mut week: Vec<ArchiveDay> = (0..first.weekday().num_days_from_monday()).map(|_| empty()).collect();
+    for date in first.iter_days().take_while(|d| d.month() == month) {
+        week.push(ArchiveDay {
+            day: date.day(),
+            url: dates.contains(&date).then(|| ArchivePage::Day(date).url()),
+            current: current == Some(date),
+        });
+        if week.len() == 7 {
+            weeks.push(std::mem::take(&mut week));
+        }
+    }
+    if !week.is_empty() {
+        week.resize_with(7, empty);
+        weeks.push(week);
+    }
+    weeks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_page_from_path() {
+        let day = NaiveDate::from_ymd_opt(2025, 11, 17).unwrap();
+        assert_eq!(ArchivePage::from_path("archiv/2025.html"), Some(ArchivePage::Year(2025)));
+        assert_eq!(ArchivePage::from_path("archiv/2025/listopad.html"), Some(ArchivePage::Month(2025, 11)));
+        assert_eq!(ArchivePage::from_path("archiv/2025/11/17.html"), Some(ArchivePage::Day(day)));
+
+        assert_eq!(ArchivePage::from_path("archiv/2025/11.html"), None);
+        assert_eq!(ArchivePage::from_path("archiv/2025/1/7.html"), None);
+        assert_eq!(ArchivePage::from_path("archiv/2025/02/30.html"), None);
+        assert_eq!(ArchivePage::from_path("2025/11/17.html"), None);
+    }
+
+    #[test]
+    fn test_archive_page_url() {
+        let day = NaiveDate::from_ymd_opt(2025, 1, 7).unwrap();
+        assert_eq!(ArchivePage::Month(2025, 1).url(), "/archiv/2025/leden.html");
+        assert_eq!(ArchivePage::Day(day).url(), "/archiv/2025/01/07.html");
+        assert_eq!(ArchivePage::Day(day).title(), "Archiv — 7. ledna 2025");
+    }
+
+    #[test]
+    fn test_calendar() {
+        let day = NaiveDate::from_ymd_opt(2025, 11, 17).unwrap();
+        let weeks = calendar(2025, 11, &[day], Some(day));
+
+        // 1. 11. 2025 is Saturday
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][4].day, 0);
+        assert_eq!(weeks[0][5].day, 1);
+        assert_eq!(weeks[3][0].day, 17);
+        assert_eq!(weeks[3][0].url.as_deref(), Some("/archiv/2025/11/17.html"));
+        assert!(weeks[3][0].current);
+        assert!(weeks[3][1].url.is_none());
+        assert_eq!(weeks[4][6].day, 30);
+    }
+}
@@ -1,13 +1,18 @@
 use crate::application::form_create_article::create_article_parser::ArticleCreateError;
+use crate::application::page_author::author;
 use crate::application::page_article::article::ArticleError::RenderArticleError;
+use crate::application::page_article::live_entries;
 use crate::data::audio_processor::AudioProcessorError;
 use crate::data::image_processor::ImageProcessorError;
-use crate::data::processor;
+use crate::data::{embed_processor, library, processor, text_processor};
 use crate::data::video_processor::VideoProcessorError;
 use crate::db::database::SurrealError;
 use crate::db::database_article::SurrealArticleError;
-use crate::db::database_article_data::{MiniArticleData, ShortArticleData};
-use crate::db::database_system::SurrealSystemError;
+use crate::db::database_article_data::{
+    Article, ArticleSource, Correction, LiveBlogEntry, MiniArticleData, ShortArticleData,
+};
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::db::database_system::{BreakingNews, SurrealSystemError};
 use crate::system::server::TheState;
 use askama::Template;
 use thiserror::Error;
@@ -17,6 +22,9 @@ pub enum ArticleError {
     #[error("category unknown {0}")]
     CategoryFailed(String),
 
+    #[error("user {0} may only submit articles for review")]
+    PublishForbidden(String),
+
     #[error("article creation failed: {0}")]
     ArticleCreate(#[from] ArticleCreateError),
 
@@ -49,6 +57,9 @@ pub enum ArticleError {
 
     #[error("surreal system error {0}")]
     SurrealSystem(#[from] SurrealSystemError),
+
+    #[error("surreal category error {0}")]
+    SurrealCategory(#[from] SurrealCategoryError),
 }
 
 #[derive(Template)]
@@ -57,14 +68,24 @@ pub struct ArticleTemplate {
     pub date: String,
     pub weather: String,
     pub name_day: String,
+    pub breaking_news: Option<BreakingNews>,
+    pub navigation: Vec<Category>,
 
     pub author: String,
+    pub author_url: String,
+    pub published: String,
+    // only if the Article changed on a later day
+    pub updated: Option<String>,
+    pub reading_time: String,
 
     pub title: String,
     pub text: String,
 
     pub image_820_path: String,
     pub image_desc: String,
+    pub image_alt: String,
+    // empty for legacy images without a credit
+    pub image_credit: String,
     pub video_path: Option<String>,
     pub audio_path: Option<String>,
 
@@ -73,6 +94,37 @@ pub struct ArticleTemplate {
 
     pub related_articles: Vec<ShortArticleData>,
     pub articles_most_read: Vec<MiniArticleData>,
+
+    // shown in a box at the end of the Article
+    pub corrections: Vec<Correction>,
+
+    // the Zdroje section, after the text
+    pub sources: Vec<ArticleSource>,
+    // numbers of the sources with a footnote in the text
+    pub cited_sources: Vec<u32>,
+}
+
+impl ArticleTemplate {
+    /**
+     * only a cited source links back to its footnote
+     */
+    pub fn is_cited(&self, number: &u32) -> bool {
+        self.cited_sources.contains(number)
+    }
+}
+
+/**
+ * variant of the Article page for a live blog
+ * - the text is an introduction, entries follow newest first
+ */
+#[derive(Template)]
+#[template(path = "application/page_article/live_blog_template.html")]
+pub struct LiveBlogTemplate {
+    pub page: ArticleTemplate,
+    pub article_file_name: String,
+    pub entries: Vec<LiveBlogEntry>,
+    // the page asks only for entries newer than this, RFC 3339
+    pub since: String,
 }
 
 /**
@@ -81,32 +133,92 @@ pub struct ArticleTemplate {
  */
 pub async fn render_article(article_file_name: &str, state: &TheState) -> Result<(), ArticleError> {
     let article = state.dba.article_by_file_name(article_file_name).await?;
+    let (uuid, created, is_live_blog) = (article.uuid, article.created, article.is_live_blog);
+
+    let article_template = article_template(article, state).await?;
+    let rendered = if is_live_blog {
+        let entries = state.dba.live_blog_entries(uuid).await?;
+        let newest = entries.first().map_or(created, |entry| entry.created);
+        LiveBlogTemplate {
+            page: article_template,
+            article_file_name: article_file_name.to_string(),
+            entries,
+            since: live_entries::to_since(newest),
+        }
+        .render()
+    } else {
+        article_template.render()
+    };
+    match rendered {
+        Ok(rendered_html) => {
+            processor::save_web_file(rendered_html, article_file_name)?;
+            Ok(())
+        }
+        Err(_) => Err(RenderArticleError),
+    }
+}
 
+/**
+ * the Article page with everything around it, not rendered yet
+ * - the Article doesn't have to be stored, the preview uses it too
+ */
+pub async fn article_template(article: Article, state: &TheState) -> Result<ArticleTemplate, ArticleError> {
     let related_articles = state.dba.related_articles(article.related_articles).await?;
 
     let category = article.category.clone();
     let articles_most_read = state.dba.most_read_in_category_by_views(&category).await?;
-
-    let article_template = ArticleTemplate {
+    let category_display = state.dbc.category_name(&category).await?;
+    let navigation = state.dbc.navigation().await?;
+    let corrections = state.dba.corrections_by_article(article.uuid).await?;
+
+    // images uploaded before alt text was required
+    let image_alt = if article.image_alt.is_empty() { article.title.clone() } else { article.image_alt.clone() };
+    let published = library::display_date(article.created);
+    let updated = library::display_date(article.updated);
+    Ok(ArticleTemplate {
         date: state.ds.date(),
         weather: state.ds.weather(),
         name_day: state.ds.name_day(),
+        breaking_news: state.ds.breaking_news(),
+        navigation,
 
         author: article.author,
+        author_url: author::author_page_url(&article.username, 1),
+        updated: (updated != published).then_some(updated),
+        published,
+        reading_time: library::display_reading_time(article.reading_minutes),
         title: article.title,
 
-        text: article.text,
+        text: embed_processor::render_embeds(&article.text),
 
         image_820_path: article.image_820_path,
+        image_credit: library::display_image_credit(&article.image_credit, &article.image_source),
+        image_alt,
         image_desc: article.image_desc,
         video_path: if article.has_video { Some(article.video_path) } else { None },
         audio_path: if article.has_audio { Some(article.audio_path) } else { None },
         category: article.category.clone(),
-        category_display: processor::process_category(article.category.as_str()),
+        category_display,
         related_articles,
         articles_most_read,
-    };
-    match article_template.render() {
+        corrections,
+        cited_sources: text_processor::footnote_references(&article.text_raw),
+        sources: article.sources,
+    })
+}
+
+/*
+ * the same page for every expired Article, nothing of the content is left
+ */
+#[derive(Template)]
+#[template(path = "application/page_article/article_gone_template.html")]
+pub struct ArticleGoneTemplate {}
+
+/**
+ * replace the rendered Article by a page saying it is no longer available
+ */
+pub fn render_article_gone(article_file_name: &str) -> Result<(), ArticleError> {
+    match (ArticleGoneTemplate {}).render() {
         Ok(rendered_html) => {
             processor::save_web_file(rendered_html, article_file_name)?;
             Ok(())
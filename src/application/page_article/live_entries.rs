@@ -0,0 +1,86 @@
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::LiveBlogEntry;
+use crate::system::server::TheState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+use LiveEntriesError::{InvalidSince, LiveBlogNotFound};
+
+#[derive(Debug, Error)]
+pub enum LiveEntriesError {
+    #[error("live blog {0} not found")]
+    LiveBlogNotFound(String),
+
+    #[error("invalid since {0}, use RFC 3339")]
+    InvalidSince(String),
+
+    #[error("surreal article error {0}")]
+    LiveEntriesSurrealArticle(#[from] SurrealArticleError),
+}
+
+#[derive(Deserialize)]
+pub struct LiveEntriesQuery {
+    // RFC 3339, all entries if missing
+    pub since: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LiveEntryJson {
+    pub created: String,
+    pub created_display: String,
+    pub author: String,
+    pub text: String,
+    pub image_path: String,
+    pub image_desc: String,
+}
+
+impl From<LiveBlogEntry> for LiveEntryJson {
+    fn from(entry: LiveBlogEntry) -> Self {
+        Self {
+            created: to_since(entry.created),
+            created_display: entry.created_display(),
+            author: entry.author,
+            text: entry.text,
+            image_path: entry.image_path,
+            image_desc: entry.image_desc,
+        }
+    }
+}
+
+/*
+ * the page asks for entries newer than the newest one it shows
+ */
+pub fn to_since(created: DateTime<Utc>) -> String {
+    created.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/**
+ * entries of a published live blog, newest first
+ */
+pub async fn handle_live_entries(
+    State(state): State<TheState>,
+    Path(article_file_name): Path<String>,
+    Query(query): Query<LiveEntriesQuery>,
+) -> Result<Json<Vec<LiveEntryJson>>, LiveEntriesError> {
+    debug!("handle_live_entries: {} since={:?}", article_file_name, query.since);
+    let article = state
+        .dba
+        .article_by_file_name_optional(&article_file_name)
+        .await?
+        .filter(|article| article.is_published() && article.is_live_blog)
+        .ok_or_else(|| LiveBlogNotFound(article_file_name.clone()))?;
+
+    let entries = match query.since.filter(|since| !since.trim().is_empty()) {
+        Some(since) => {
+            let since_utc = DateTime::parse_from_rfc3339(since.trim())
+                .map_err(|_| InvalidSince(since.clone()))?
+                .with_timezone(&Utc);
+            state.dba.live_blog_entries_since(article.uuid, since_utc).await?
+        }
+        None => state.dba.live_blog_entries(article.uuid).await?,
+    };
+    Ok(Json(entries.into_iter().map(LiveEntryJson::from).collect()))
+}
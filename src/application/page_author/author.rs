@@ -0,0 +1,113 @@
+use crate::application::page_category::category;
+use crate::data::processor;
+use crate::data::processor::ProcessorError;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::ShortArticleData;
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::db::database_user::User;
+use crate::system::server::TheState;
+use askama::Template;
+use thiserror::Error;
+use AuthorError::CreateAuthorError;
+
+// articles on one author page
+pub const AUTHOR_PAGE_SIZE: u32 = 20;
+
+#[derive(Debug, Error)]
+pub enum AuthorError {
+    #[error("create author error")]
+    CreateAuthorError,
+
+    #[error("create author processor error {0}")]
+    ProcessorError(#[from] ProcessorError),
+
+    #[error("create author database error {0}")]
+    SurrealArticle(#[from] SurrealArticleError),
+
+    #[error("create author database category error {0}")]
+    SurrealCategory(#[from] SurrealCategoryError),
+}
+
+#[derive(Template)]
+#[template(path = "application/page_author/author_template.html")]
+pub struct AuthorTemplate<'a> {
+    pub date: String,
+    pub weather: String,
+    pub name_day: String,
+    pub navigation: Vec<Category>,
+    pub author: &'a User,
+    pub articles: Vec<ShortArticleData>,
+    pub page: u32,
+    pub previous_page: Option<String>,
+    pub next_page: Option<String>,
+}
+
+/**
+ * first page of an author is autor/{username}.html
+ * older articles are on autor/{username}/2.html, ...
+ */
+pub fn author_page_url(username: &str, page: u32) -> String {
+    format!("/autor{}", category::category_page_url(username, page))
+}
+
+/**
+ * reverse of author_page_url, path without the leading slash
+ */
+pub fn author_page_from_path(path: &str) -> Option<(&str, u32)> {
+    // paginated the same way as a category
+    let (username, page) = category::category_page_from_path(path.strip_prefix("autor/")?)?;
+    // the username becomes a file name
+    let safe = username.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    (safe && !username.is_empty()).then_some((username, page))
+}
+
+/**
+ * number of pages, an author without articles still has the first page
+ */
+pub async fn author_page_count(state: &TheState, username: &str) -> Result<u32, AuthorError> {
+    let count = state.dba.count_articles_by_username(username).await?;
+    Ok(count.div_ceil(AUTHOR_PAGE_SIZE).max(1))
+}
+
+pub async fn render_author(state: &TheState, author: &User, page: u32) -> Result<(), AuthorError> {
+    let page_count = author_page_count(state, &author.username).await?;
+    let start = (page - 1) * AUTHOR_PAGE_SIZE;
+    #[rustfmt::skip]
+    let articles = state.dba.articles_by_username_from(&author.username, start, AUTHOR_PAGE_SIZE).await?;
+
+    let author_template = AuthorTemplate {
+        date: state.ds.date(),
+        weather: state.ds.weather(),
+        name_day: state.ds.name_day(),
+        navigation: state.dbc.navigation().await?,
+        author,
+        articles,
+        page,
+        previous_page: (page > 1).then(|| author_page_url(&author.username, page - 1)),
+        next_page: (page < page_count).then(|| author_page_url(&author.username, page + 1)),
+    };
+    match author_template.render() {
+        Ok(rendered_html) => {
+            processor::create_web_dir(&format!("autor/{}", author.username))?;
+            let url = author_page_url(&author.username, page);
+            processor::save_web_file(rendered_html, url.strip_prefix('/').unwrap_or(&url))?;
+            Ok(())
+        }
+        Err(_) => Err(CreateAuthorError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_author_page_url() {
+        assert_eq!(author_page_url("editor", 1), "/autor/editor.html");
+        assert_eq!(author_page_url("editor", 2), "/autor/editor/2.html");
+        assert_eq!(author_page_from_path("autor/editor.html"), Some(("editor", 1)));
+        assert_eq!(author_page_from_path("autor/editor/2.html"), Some(("editor", 2)));
+        assert_eq!(author_page_from_path("editor.html"), None);
+        assert_eq!(author_page_from_path("autor/..html"), None);
+    }
+}
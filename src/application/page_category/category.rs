@@ -0,0 +1,167 @@
+use crate::data::processor;
+use crate::data::processor::ProcessorError;
+use crate::db::database::SurrealError;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::{MiniArticleData, ShortArticleData};
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::db::database_system::{BreakingNews, SurrealSystemError};
+use crate::system::server::TheState;
+use askama::Template;
+use thiserror::Error;
+use CategoryError::CreateCategoryError;
+
+// articles on one category page
+pub const CATEGORY_PAGE_SIZE: u32 = 20;
+
+#[derive(Debug, Error)]
+pub enum CategoryError {
+    #[error("create category error")]
+    CreateCategoryError,
+
+    #[error("create category processor error {0}")]
+    ProcessorError(#[from] ProcessorError),
+
+    #[error("create category database error {0}")]
+    DatabaseError(#[from] SurrealError),
+
+    #[error("create category database error {0}")]
+    SurrealArticle(#[from] SurrealArticleError),
+
+    #[error("create category database system error {0}")]
+    SurrealSystem(#[from] SurrealSystemError),
+
+    #[error("create category database category error {0}")]
+    SurrealCategory(#[from] SurrealCategoryError),
+}
+
+#[derive(Template)]
+#[template(path = "application/page_category/category_template.html")]
+pub struct CategoryTemplate<'a> {
+    pub date: String,
+    pub weather: String,
+    pub name_day: String,
+    pub breaking_news: Option<BreakingNews>,
+    pub navigation: Vec<Category>,
+    pub category: &'a Category,
+    pub articles_most_read: Vec<MiniArticleData>,
+    pub articles_left: &'a [ShortArticleData],
+    pub articles_right: &'a [ShortArticleData],
+    pub page: u32,
+    pub previous_page: Option<String>,
+    pub next_page: Option<String>,
+}
+
+/**
+ * first page of a category is {slug}.html
+ * older articles are on {slug}/2.html, {slug}/3.html, ...
+ */
+pub fn category_page_url(slug: &str, page: u32) -> String {
+    if page <= 1 {
+        format!("/{}.html", slug)
+    } else {
+        format!("/{}/{}.html", slug, page)
+    }
+}
+
+/**
+ * reverse of category_page_url, path without the leading slash
+ * - {slug}.html is the first page, {slug}/1.html doesn't exist
+ */
+pub fn category_page_from_path(path: &str) -> Option<(&str, u32)> {
+    let name = path.strip_suffix(".html")?;
+    match name.split_once('/') {
+        None => Some((name, 1)),
+        Some((slug, page)) => match page.parse::<u32>() {
+            Ok(page) if page > 1 => Some((slug, page)),
+            _ => None,
+        },
+    }
+}
+
+/**
+ * page of the category which lists the article at this position
+ */
+pub const fn category_page_of(position: u32) -> u32 {
+    position / CATEGORY_PAGE_SIZE + 1
+}
+
+/**
+ * number of pages, an empty category still has the first page
+ */
+pub async fn category_page_count(state: &TheState, slug: &str) -> Result<u32, CategoryError> {
+    let count = state.dba.count_articles_in_category(slug).await?;
+    Ok(count.div_ceil(CATEGORY_PAGE_SIZE).max(1))
+}
+
+pub async fn render_category(
+    state: &TheState,
+    category: &Category,
+    page: u32,
+) -> Result<(), CategoryError> {
+    let page_count = category_page_count(state, &category.slug).await?;
+    let start = (page - 1) * CATEGORY_PAGE_SIZE;
+    #[rustfmt::skip]
+    let articles = state.dba.articles_by_category_from(&category.slug, start, CATEGORY_PAGE_SIZE).await?;
+    let articles_most_read: Vec<MiniArticleData> =
+        state.dba.most_read_in_category_by_views(&category.slug).await?;
+    let navigation = state.dbc.navigation().await?;
+
+    let split = articles.len().div_ceil(2);
+    let (articles_left, articles_right) = articles.split_at(split);
+    let category_template = CategoryTemplate {
+        date: state.ds.date(),
+        weather: state.ds.weather(),
+        name_day: state.ds.name_day(),
+        breaking_news: state.ds.breaking_news(),
+        navigation,
+        category,
+        articles_most_read,
+        articles_left,
+        articles_right,
+        page,
+        previous_page: (page > 1).then(|| category_page_url(&category.slug, page - 1)),
+        next_page: (page < page_count).then(|| category_page_url(&category.slug, page + 1)),
+    };
+    match category_template.render() {
+        Ok(rendered_html) => {
+            let path = category_page_url(&category.slug, page);
+            if page > 1 {
+                processor::create_web_dir(&category.slug)?;
+            }
+            processor::save_web_file(rendered_html, real_path(&path))?;
+            Ok(())
+        }
+        Err(_) => Err(CreateCategoryError),
+    }
+}
+
+fn real_path(url: &str) -> &str {
+    url.strip_prefix('/').unwrap_or(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_page_url() {
+        assert_eq!(category_page_url("veda", 1), "/veda.html");
+        assert_eq!(category_page_url("veda", 3), "/veda/3.html");
+    }
+
+    #[test]
+    fn test_category_page_from_path() {
+        assert_eq!(category_page_from_path("veda.html"), Some(("veda", 1)));
+        assert_eq!(category_page_from_path("veda/3.html"), Some(("veda", 3)));
+        assert_eq!(category_page_from_path("veda/1.html"), None);
+        assert_eq!(category_page_from_path("veda/x.html"), None);
+        assert_eq!(category_page_from_path("veda.png"), None);
+    }
+
+    #[test]
+    fn test_category_page_of() {
+        assert_eq!(category_page_of(0), 1);
+        assert_eq!(category_page_of(CATEGORY_PAGE_SIZE - 1), 1);
+        assert_eq!(category_page_of(CATEGORY_PAGE_SIZE), 2);
+    }
+}
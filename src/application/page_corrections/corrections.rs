@@ -0,0 +1,85 @@
+use crate::data::processor;
+use crate::data::processor::ProcessorError;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::MiniArticleData;
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::db::database_system::{BreakingNews, SurrealSystemError};
+use crate::system::server::TheState;
+use askama::Template;
+use thiserror::Error;
+
+pub const CORRECTIONS_FILE_NAME: &str = "opravy.html";
+
+#[derive(Debug, Error)]
+pub enum CorrectionsError {
+    #[error("corrections processor error {0}")]
+    CorrectionsProcessor(#[from] ProcessorError),
+
+    #[error("corrections database article error {0}")]
+    CorrectionsSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("corrections database system error {0}")]
+    CorrectionsSurrealSystem(#[from] SurrealSystemError),
+
+    #[error("corrections database category error {0}")]
+    CorrectionsSurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("corrections render error")]
+    CorrectionsRender(#[from] askama::Error),
+}
+
+/*
+ * one correction with the Article it fixes
+ */
+pub struct CorrectionRow {
+    pub date: String,
+    pub article_file_name: String,
+    pub title: String,
+    pub text: String,
+}
+
+#[derive(Template)]
+#[template(path = "application/page_corrections/corrections_template.html")]
+pub struct CorrectionsTemplate {
+    pub date: String,
+    pub weather: String,
+    pub name_day: String,
+    pub breaking_news: Option<BreakingNews>,
+    pub navigation: Vec<Category>,
+    pub articles_most_read: Vec<MiniArticleData>,
+    pub rows: Vec<CorrectionRow>,
+}
+
+/**
+ * all corrections across the site, newest first
+ * - corrections of Articles which are not published anymore are left out
+ */
+pub async fn render_corrections(state: &TheState) -> Result<(), CorrectionsError> {
+    let articles_most_read = state.dba.most_read_all_by_views().await?;
+    let navigation = state.dbc.navigation().await?;
+
+    let mut rows = Vec::new();
+    for correction in state.dba.all_corrections().await? {
+        if let Some(article) = state.dba.short_article_by_id(correction.article_uuid).await? {
+            rows.push(CorrectionRow {
+                date: correction.created_display(),
+                article_file_name: article.article_file_name,
+                title: article.title,
+                text: correction.text,
+            });
+        }
+    }
+
+    let rendered_html = CorrectionsTemplate {
+        date: state.ds.date(),
+        weather: state.ds.weather(),
+        name_day: state.ds.name_day(),
+        breaking_news: state.ds.breaking_news(),
+        navigation,
+        articles_most_read,
+        rows,
+    }
+    .render()?;
+    processor::save_web_file(rendered_html, CORRECTIONS_FILE_NAME)?;
+    Ok(())
+}
@@ -3,12 +3,21 @@ use crate::db::database_article::SurrealArticleError;
 use crate::db::database_article_data::{
     MainArticleData, MiniArticleData, ShortArticleData, TopArticleData,
 };
-use crate::db::database_system::SurrealSystemError;
+use crate::db::database_category::{Category, SurrealCategoryError};
+use crate::db::database_homepage::SurrealHomepageError;
+use crate::db::database_system::{BreakingNews, SurrealSystemError};
 use crate::system::server::TheState;
 use askama::Template;
+use chrono::Utc;
 use thiserror::Error;
 use tracing::debug;
 
+// Articles listed in a section
+const SECTION_SIZE: usize = 10;
+
+// main, second and third
+pub const TOP_SLOTS: usize = 3;
+
 #[derive(Debug, Error)]
 pub enum IndexError {
     #[error("create article error")]
@@ -22,6 +31,18 @@ pub enum IndexError {
 
     #[error("surreal system error {0}")]
     SurrealSystem(#[from] SurrealSystemError),
+
+    #[error("surreal category error {0}")]
+    SurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("surreal homepage error {0}")]
+    SurrealHomepage(#[from] SurrealHomepageError),
+}
+
+pub struct IndexSection {
+    // its homepage_label links to the category page
+    pub category: Category,
+    pub articles: Vec<ShortArticleData>,
 }
 
 /*
@@ -33,6 +54,8 @@ pub struct IndexTemplate {
     pub date: String,
     pub weather: String,
     pub name_day: String,
+    pub breaking_news: Option<BreakingNews>,
+    pub navigation: Vec<Category>,
 
     pub articles_most_read: Vec<MiniArticleData>,
 
@@ -41,35 +64,40 @@ pub struct IndexTemplate {
     pub second_article: TopArticleData,
     pub third_article: TopArticleData,
 
-    pub z_republiky_articles: Vec<ShortArticleData>,
-    pub ze_zahranici_articles: Vec<ShortArticleData>,
+    pub sections: Vec<IndexSection>,
 }
 
 pub async fn render_index(state: &TheState) -> Result<(), IndexError> {
     let articles_most_read = state.dba.most_read_all_by_views().await?;
-    let z_republiky_articles = state.dba.articles_by_category("republika", 10).await?;
-    let ze_zahranici_articles = state.dba.articles_by_category("zahranici", 10).await?;
-
     debug!("articles_most_read: {}", articles_most_read.len());
-    debug!("z_republiky_articles: {}", z_republiky_articles.len());
-    debug!("ze_zahranici_articles: {}", ze_zahranici_articles.len());
 
-    let (main_article, second_article, third_article) = state.dba.article_top_three().await?;
+    let mut top = top_articles(state).await?.into_iter().map(|a| a.unwrap_or_else(MainArticleData::empty));
+    let main_article = top.next().unwrap_or_else(MainArticleData::empty);
+    let second_article = TopArticleData::from(top.next().unwrap_or_else(MainArticleData::empty));
+    let third_article = TopArticleData::from(top.next().unwrap_or_else(MainArticleData::empty));
 
-    let main_article_category_display =
-        processor::process_category(&main_article.category);
+    let main_article_category_display = state.dbc.category_name(&main_article.category).await?;
+    let navigation = state.dbc.navigation().await?;
+
+    let mut sections = Vec::new();
+    for category in state.dbc.homepage_sections().await? {
+        let articles = section_articles(state, &category.slug).await?;
+        debug!("section {}: {}", category.slug, articles.len());
+        sections.push(IndexSection { category, articles });
+    }
 
     let template = IndexTemplate {
         date: state.ds.date(),
         weather: state.ds.weather(),
         name_day: state.ds.name_day(),
+        breaking_news: state.ds.breaking_news(),
+        navigation,
         articles_most_read,
         main_article,
         main_article_category_display,
         second_article,
         third_article,
-        z_republiky_articles,
-        ze_zahranici_articles,
+        sections,
     };
 
     match template.render() {
@@ -81,3 +109,47 @@ pub async fn render_index(state: &TheState) -> Result<(), IndexError> {
         Err(_) => Err(IndexError::RenderError),
     }
 }
+
+/*
+ * pinned Articles keep their slot, the free slots are filled by the newest main Articles
+ */
+async fn top_articles(state: &TheState) -> Result<Vec<Option<MainArticleData>>, IndexError> {
+    let mut top: Vec<Option<MainArticleData>> = vec![None; TOP_SLOTS];
+    for slot in state.dbh.active_slots("", Utc::now()).await? {
+        let position = usize::try_from(slot.position).unwrap_or(TOP_SLOTS);
+        if let Some(free) = top.get_mut(position) {
+            *free = state.dba.main_article_by_id(slot.article_uuid).await?;
+        }
+    }
+
+    let pinned: Vec<String> = top.iter().flatten().map(|a| a.article_file_name.clone()).collect();
+    let mut latest = state
+        .dba
+        .main_articles(TOP_SLOTS as u32 * 2)
+        .await?
+        .into_iter()
+        .filter(|article| !pinned.contains(&article.article_file_name));
+    for free in top.iter_mut().filter(|a| a.is_none()) {
+        *free = latest.next();
+    }
+    Ok(top)
+}
+
+/*
+ * highlights first, then the latest Articles of the section
+ */
+async fn section_articles(state: &TheState, slug: &str) -> Result<Vec<ShortArticleData>, IndexError> {
+    let mut articles: Vec<ShortArticleData> = Vec::new();
+    for slot in state.dbh.active_slots(slug, Utc::now()).await? {
+        if let Some(article) = state.dba.short_article_by_id(slot.article_uuid).await? {
+            articles.push(article);
+        }
+    }
+    for article in state.dba.articles_by_category(slug, SECTION_SIZE as u32).await? {
+        if !articles.iter().any(|a| a.article_file_name == article.article_file_name) {
+            articles.push(article);
+        }
+    }
+    articles.truncate(SECTION_SIZE);
+    Ok(articles)
+}
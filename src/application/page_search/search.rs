@@ -1,5 +1,7 @@
 use crate::data::text_validator::validate_search_query;
 use crate::db::database_article_data::{MiniArticleData, ShortArticleData};
+use crate::db::database_category::Category;
+use crate::db::database_system::BreakingNews;
 use crate::system::server::TheState;
 use askama::Template;
 use axum::extract::State;
@@ -21,6 +23,8 @@ pub struct SearchTemplate {
     pub date: String,
     pub weather: String,
     pub name_day: String,
+    pub breaking_news: Option<BreakingNews>,
+    pub navigation: Vec<Category>,
     pub articles_most_read: Vec<MiniArticleData>,
     pub articles: Vec<ShortArticleData>,
 }
@@ -54,6 +58,10 @@ pub async fn handle_search(
         error!("error while getting most read articles: {}", e);
         Vec::new()
     });
+    let navigation = state.dbc.navigation().await.unwrap_or_else(|e| {
+        error!("error while getting navigation: {}", e);
+        Vec::new()
+    });
 
     if let Ok(articles) = articles_r {
         let template = SearchTemplate {
@@ -61,6 +69,8 @@ pub async fn handle_search(
             date: state.ds.date(),
             weather: state.ds.weather(),
             name_day: state.ds.name_day(),
+            breaking_news: state.ds.breaking_news(),
+            navigation,
             articles,
             articles_most_read: articles_most_read_use,
         };
@@ -77,6 +87,8 @@ pub async fn handle_search(
             date: state.ds.date(),
             weather: state.ds.weather(),
             name_day: state.ds.name_day(),
+            breaking_news: state.ds.breaking_news(),
+            navigation,
             articles: Vec::new(),
             articles_most_read: articles_most_read_use,
         };
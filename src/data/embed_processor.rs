@@ -0,0 +1,129 @@
+use crate::data::text_validator;
+use crate::data::text_validator::EmbedProvider;
+
+const EMBED_START: &str = "<!--embed:";
+const EMBED_END: &str = "-->";
+
+/**
+ * expand the embeds stored in the processed Article text into click-to-load placeholders
+ * - nothing of the provider is loaded until the reader asks for it
+ * - the id is validated again, a stored embed which isn't valid anymore is left out
+ */
+pub fn render_embeds(text: &str) -> String {
+    let mut html = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(EMBED_START) {
+        html.push_str(&rest[..start]);
+        let after = &rest[start + EMBED_START.len()..];
+        let Some(end) = after.find(EMBED_END) else {
+            rest = &rest[start..];
+            break;
+        };
+        if let Some((provider, id)) = after[..end].split_once(':')
+            && let Ok(provider) = text_validator::validate_embed(provider, id)
+        {
+            html.push_str(&placeholder(provider, id));
+        }
+        rest = &after[end + EMBED_END.len()..];
+    }
+    html.push_str(rest);
+    html
+}
+
+/*
+ * the iframe is created by js/embed.js from data-embed-src
+ */
+fn placeholder(provider: EmbedProvider, id: &str) -> String {
+    let (title, open) = match provider {
+        EmbedProvider::YouTube => ("YouTube", "Otevřít na YouTube"),
+        EmbedProvider::X => ("X", "Otevřít na X"),
+        EmbedProvider::Instagram => ("Instagram", "Otevřít na Instagramu"),
+        EmbedProvider::Map => ("OpenStreetMap", "Otevřít mapu"),
+    };
+    let (src, link) = urls(provider, id);
+    format!(
+        "<div class=\"embed embed-{}\" data-embed-src=\"{}\" data-embed-title=\"{}\">\
+            <p class=\"embed-notice\">Obsah z {} se načte až po kliknutí, poskytovatel může ukládat cookies.</p>\
+            <button type=\"button\" class=\"embed-load\">Zobrazit obsah</button> \
+            <a class=\"embed-link\" href=\"{}\" target=\"_blank\" rel=\"noopener noreferrer\">{}</a>\
+        </div>",
+        provider.name(),
+        src,
+        title,
+        title,
+        link,
+        open
+    )
+}
+
+/*
+ * iframe source and link to the original, ids are validated, no escaping needed
+ * - YouTube without cookies until the video is played
+ */
+fn urls(provider: EmbedProvider, id: &str) -> (String, String) {
+    match provider {
+        EmbedProvider::YouTube => (
+            format!("https://www.youtube-nocookie.com/embed/{}", id),
+            format!("https://www.youtube.com/watch?v={}", id),
+        ),
+        EmbedProvider::X => (
+            format!("https://platform.twitter.com/embed/Tweet.html?id={}", id),
+            format!("https://x.com/i/status/{}", id),
+        ),
+        EmbedProvider::Instagram => (
+            format!("https://www.instagram.com/p/{}/embed", id),
+            format!("https://www.instagram.com/p/{}/", id),
+        ),
+        EmbedProvider::Map => {
+            let (latitude, longitude, zoom) =
+                text_validator::map_position(id).unwrap_or((0.0, 0.0, text_validator::MAP_DEFAULT_ZOOM));
+            // the visible area halves with every zoom level
+            let delta = 360.0 / f64::from(1u32 << zoom);
+            (
+                format!(
+                    "https://www.openstreetmap.org/export/embed.html?bbox={:.5},{:.5},{:.5},{:.5}\
+                        &amp;layer=mapnik&amp;marker={:.5},{:.5}",
+                    longitude - delta,
+                    latitude - delta / 2.0,
+                    longitude + delta,
+                    latitude + delta / 2.0,
+                    latitude,
+                    longitude
+                ),
+                format!(
+                    "https://www.openstreetmap.org/?mlat={:.5}&amp;mlon={:.5}#map={}/{:.5}/{:.5}",
+                    latitude, longitude, zoom, latitude, longitude
+                ),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_embeds() {
+        let html = render_embeds("<p>Before</p><!--embed:youtube:dQw4w9WgXcQ--><p>After</p>");
+        assert!(html.starts_with("<p>Before</p><div class=\"embed embed-youtube\""));
+        assert!(html.contains("data-embed-src=\"https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ\""));
+        assert!(html.contains("href=\"https://www.youtube.com/watch?v=dQw4w9WgXcQ\""));
+        assert!(!html.contains("<iframe"));
+        assert!(html.ends_with("</div><p>After</p>"));
+    }
+
+    #[test]
+    fn test_render_embeds_map() {
+        let html = render_embeds("<!--embed:map:50.0875,14.4213,12-->");
+        assert!(html.contains("marker=50.08750,14.42130"));
+        assert!(html.contains("#map=12/50.08750/14.42130"));
+    }
+
+    #[test]
+    fn test_render_embeds_invalid() {
+        assert_eq!(render_embeds("<p>a</p><!--embed:vimeo:123--><p>b</p>"), "<p>a</p><p>b</p>");
+        assert_eq!(render_embeds("<p>a</p><!--embed:x:1\"onload=\"x--><p>b</p>"), "<p>a</p><p>b</p>");
+        assert_eq!(render_embeds("<p>a</p><!--embed:x:1"), "<p>a</p><!--embed:x:1");
+    }
+}
@@ -1,8 +1,12 @@
 use crate::data::image_validator::{validate_image_width, ImageValidationError};
 use image::imageops::Lanczos3;
 use image::{DynamicImage, GenericImageView, ImageError};
+use std::path::Path;
 use thiserror::Error;
 
+// uploaded media, served by the web router
+const UPLOADS_DIR: &str = "web/u";
+
 #[derive(Debug, Error)]
 pub enum ImageProcessorError {
     #[error("failed to save image because {0}")]
@@ -16,6 +20,18 @@ pub fn process_images(
     img_data: &[u8],
     file_base: &str,
     ext: &str,
+) -> Result<(), ImageProcessorError> {
+    process_images_into(img_data, Path::new(UPLOADS_DIR), file_base, ext)
+}
+
+/**
+ * the same size variants into another directory, the preview doesn't publish anything
+ */
+pub fn process_images_into(
+    img_data: &[u8],
+    dir: &Path,
+    file_base: &str,
+    ext: &str,
 ) -> Result<(), ImageProcessorError> {
     let img = image::load_from_memory(img_data)?;
     let (width, height) = img.dimensions();
@@ -27,20 +43,55 @@ pub fn process_images(
     let name_820 = format!("{}_image_820.{}", file_base, ext);
 
     // Save 820xany
-    save_image(&img_820, name_820.as_str())?;
+    save_image(&img_820, dir, name_820.as_str())?;
 
     // Save 50x50
-    resized_and_save_image(&img, 50, 50, file_base, "image_50", ext)?;
+    resized_and_save_image(&img, dir, 50, 50, file_base, "image_50", ext)?;
     // Save 288x211
-    resized_and_save_image(&img, 288, 211, file_base, "image_288", ext)?;
+    resized_and_save_image(&img, dir, 288, 211, file_base, "image_288", ext)?;
     // Save 440x300
-    resized_and_save_image(&img, 440, 300, file_base, "image_440", ext)?;
+    resized_and_save_image(&img, dir, 440, 300, file_base, "image_440", ext)?;
 
     Ok(())
 }
 
+/**
+ * square photo of an author
+ * - returns the path relative to web
+ */
+pub fn process_author_photo(
+    img_data: &[u8],
+    file_base: &str,
+    ext: &str,
+) -> Result<String, ImageProcessorError> {
+    let img = image::load_from_memory(img_data)?;
+    let photo = img.resize_to_fill(240, 240, Lanczos3);
+    let name = format!("{}_photo.{}", file_base, ext);
+    save_image(&photo, Path::new(UPLOADS_DIR), name.as_str())?;
+    Ok(format!("u/{}", name))
+}
+
+/**
+ * image of a live blog entry, shown in the width of the article
+ * - returns the path relative to web
+ */
+pub fn process_live_blog_image(
+    img_data: &[u8],
+    file_base: &str,
+    ext: &str,
+) -> Result<String, ImageProcessorError> {
+    let img = image::load_from_memory(img_data)?;
+    let (width, height) = img.dimensions();
+    validate_image_width(width)?;
+    let img_820 = img.resize(820, height, Lanczos3);
+    let name = format!("{}_image_820.{}", file_base, ext);
+    save_image(&img_820, Path::new(UPLOADS_DIR), name.as_str())?;
+    Ok(format!("u/{}", name))
+}
+
 fn resized_and_save_image(
     img: &DynamicImage,
+    dir: &Path,
     w: u32,
     h: u32,
     file_base: &str,
@@ -56,12 +107,12 @@ fn resized_and_save_image(
     let resized = img.resize_to_fill(w, new_height, Lanczos3);
 
     let name = format!("{}_{}.{}", file_base, resolution_image_suffix, ext);
-    save_image(&resized, name.as_str())?;
+    save_image(&resized, dir, name.as_str())?;
     Ok(())
 }
 
-fn save_image(image: &DynamicImage, file_name: &str) -> Result<(), ImageProcessorError> {
-    image.save(format!("web/u/{}", file_name))?;
+fn save_image(image: &DynamicImage, dir: &Path, file_name: &str) -> Result<(), ImageProcessorError> {
+    image.save(dir.join(file_name))?;
     Ok(())
 }
 
@@ -96,6 +147,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_process_author_photo() -> Result<(), TrustError> {
+        let mut img_data = Vec::new();
+        ImageRgb8(RgbImage::new(300, 200))
+            .write_to(&mut std::io::Cursor::new(&mut img_data), image::ImageFormat::Png)?;
+
+        let path = process_author_photo(&img_data, "test_author", "png")?;
+        assert_eq!(path, "u/test_author_photo.png");
+
+        let photo = image::open("web/u/test_author_photo.png")?;
+        assert_eq!(photo.dimensions(), (240, 240));
+        trust::me::remove_file("web/u/test_author_photo.png")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_live_blog_image() -> Result<(), TrustError> {
+        let mut img_data = Vec::new();
+        ImageRgb8(RgbImage::new(1000, 500))
+            .write_to(&mut std::io::Cursor::new(&mut img_data), image::ImageFormat::Png)?;
+
+        let path = process_live_blog_image(&img_data, "test_live_entry", "png")?;
+        assert_eq!(path, "u/test_live_entry_image_820.png");
+
+        let image = image::open("web/u/test_live_entry_image_820.png")?;
+        assert_eq!(image.dimensions(), (820, 410));
+        trust::me::remove_file("web/u/test_live_entry_image_820.png")?;
+        Ok(())
+    }
+
     #[test]
     fn test_process_images_too_small() {
         let mut img_data = Vec::new();
@@ -110,7 +191,7 @@ mod tests {
     #[test]
     fn test_resized_and_save_image() -> Result<(), TrustError> {
         let img = ImageRgb8(RgbImage::new(100, 100));
-        let res = resized_and_save_image(&img, 50, 50, "resize_me", "image_50", "png");
+        let res = resized_and_save_image(&img, Path::new(UPLOADS_DIR), 50, 50, "resize_me", "image_50", "png");
 
         assert!(res.is_ok());
         assert!(Path::new("web/u/resize_me_image_50.png").exists());
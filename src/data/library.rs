@@ -1,5 +1,5 @@
 use crate::data::time::to_prague_time;
-use chrono::{DateTime, Datelike, Utc, Weekday};
+use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday};
 // TODO nejsou vyřešeny státní svátky
 
 pub const CZECH_MONTHS_CAPITAL: [&str; 12] = [
@@ -42,16 +42,23 @@ pub const fn get_czech_month(month: u32) -> &'static str {
     CZECH_MONTHS[idx]
 }
 
-#[allow(unused)]
-const fn get_czech_month_capital(month: u32) -> &'static str {
+pub const fn get_czech_month_capital(month: u32) -> &'static str {
     let idx = (month - 1) as usize;
     CZECH_MONTHS_CAPITAL[idx]
 }
 
-const fn get_czech_month_genitive(month: u32) -> &'static str {
+pub const fn get_czech_month_genitive(month: u32) -> &'static str {
     CZECH_MONTHS_GENITIVE[(month - 1) as usize]
 }
 
+/**
+ * month number from the name used in URLs, e.g. listopad -> 11
+ */
+pub fn czech_month_from_name(name: &str) -> Option<u32> {
+    let idx = CZECH_MONTHS.iter().position(|m| *m == name)?;
+    u32::try_from(idx + 1).ok()
+}
+
 pub fn day_of_week(utc: DateTime<Utc>) -> &'static str {
     let now = to_prague_time(utc);
     match now.weekday() {
@@ -65,37 +72,6 @@ pub fn day_of_week(utc: DateTime<Utc>) -> &'static str {
     }
 }
 
-pub fn safe_article_file_name(title: &str) -> String {
-    title
-        .trim()
-        .to_lowercase()
-        .chars()
-        .map(|c| match c {
-            'a'..='z' | '0'..='9' => c,
-
-            // samohlásky
-            'á' => 'a',
-            'é' => 'e',
-            'ě' => 'e',
-            'í' => 'i',
-            'ó' => 'o',
-            'ú' => 'u',
-            'ů' => 'u',
-
-            // souhlásky
-            'č' => 'c',
-            'ď' => 'd',
-            'ň' => 'n',
-            'ř' => 'r',
-            'š' => 's',
-            'ť' => 't',
-            'ý' => 'y',
-            'ž' => 'z',
-            _ => '-',
-        })
-        .collect::<String>()
-}
-
 /**
  * Prague display date from UTC
  */
@@ -108,17 +84,94 @@ pub fn display_date(utc: DateTime<Utc>) -> String {
     format!("{} {}. {} {}", day_name, now.day(), month_name_genitive, now.year())
 }
 
+/**
+ * Prague display date and time from UTC
+ */
+pub fn display_date_time(utc: DateTime<Utc>) -> String {
+    let now = to_prague_time(utc);
+    format!("{} {}", display_date(utc), now.format("%H:%M"))
+}
+
+// average silent reading speed of a news text
+pub const READING_WORDS_PER_MINUTE: u32 = 200;
+
+/**
+ * estimated minutes to read, at least one
+ */
+pub const fn reading_minutes(word_count: u32) -> u32 {
+    let minutes = word_count.div_ceil(READING_WORDS_PER_MINUTE);
+    if minutes == 0 { 1 } else { minutes }
+}
+
+pub fn display_reading_time(minutes: u32) -> String {
+    format!("{} min čtení", minutes)
+}
+
+/**
+ * "2024-07-01" as 1. 7. 2024, anything else is shown as it is
+ */
+pub fn display_iso_date(date: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_or_else(|_| date.to_string(), |date| date.format("%-d. %-m. %Y").to_string())
+}
+
+/**
+ * credit line of an image, the source is left out if it is the same as the credit
+ * - empty if neither is known, legacy images have none
+ */
+pub fn display_image_credit(credit: &str, source: &str) -> String {
+    let credit = credit.trim();
+    let source = source.trim();
+    match (credit.is_empty(), source.is_empty()) {
+        (true, true) => "".into(),
+        (false, true) => format!("Foto: {}", credit),
+        (true, false) => format!("Foto: {}", source),
+        _ if credit == source => format!("Foto: {}", credit),
+        _ => format!("Foto: {}, {}", credit, source),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::TimeZone;
 
+    #[test]
+    fn test_display_image_credit() {
+        assert_eq!(display_image_credit("Jan Novák", "ČTK"), "Foto: Jan Novák, ČTK");
+        assert_eq!(display_image_credit("Reuters", "Reuters"), "Foto: Reuters");
+        assert_eq!(display_image_credit("", "archiv"), "Foto: archiv");
+        assert_eq!(display_image_credit(" ", ""), "");
+    }
+
+    #[test]
+    fn test_reading_minutes() {
+        assert_eq!(reading_minutes(0), 1);
+        assert_eq!(reading_minutes(200), 1);
+        assert_eq!(reading_minutes(201), 2);
+        assert_eq!(reading_minutes(1000), 5);
+        assert_eq!(display_reading_time(3), "3 min čtení");
+    }
+
+    #[test]
+    fn test_display_iso_date() {
+        assert_eq!(display_iso_date("2024-07-01"), "1. 7. 2024");
+        assert_eq!(display_iso_date("včera"), "včera");
+    }
+
     #[test]
     fn test_get_czech_month() {
         assert_eq!(get_czech_month(1), "leden");
         assert_eq!(get_czech_month(12), "prosinec");
     }
 
+    #[test]
+    fn test_czech_month_from_name() {
+        assert_eq!(czech_month_from_name("leden"), Some(1));
+        assert_eq!(czech_month_from_name("listopad"), Some(11));
+        assert_eq!(czech_month_from_name("Listopad"), None);
+    }
+
     #[test]
     fn test_get_czech_month_capital() {
         assert_eq!(get_czech_month_capital(1), "Leden");
@@ -139,18 +192,15 @@ mod tests {
         assert_eq!(day_of_week(dt), "Neděle");
     }
 
-    #[test]
-    fn test_save_article_file_name() {
-        assert_eq!(
-            safe_article_file_name(&"Příliš žluťoučký kůň".to_string()),
-            "prilis-zlutoucky-kun"
-        );
-        assert_eq!(safe_article_file_name(&"Hello World!".to_string()), "hello-world-");
-    }
-
     #[test]
     fn test_formatted_article_date() {
         let dt = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
         assert_eq!(display_date(dt), "Pondělí 1. ledna 2024");
     }
+
+    #[test]
+    fn test_formatted_article_date_time() {
+        let dt = Utc.with_ymd_and_hms(2024, 7, 1, 6, 30, 0).unwrap();
+        assert_eq!(display_date_time(dt), "Pondělí 1. července 2024 08:30");
+    }
 }
@@ -2,7 +2,7 @@ use std::fs;
 use std::io::Error;
 use std::path::Path;
 use thiserror::Error;
-use tracing::{debug, error};
+use tracing::debug;
 
 #[derive(Debug, Error)]
 pub enum ProcessorError {
@@ -22,34 +22,16 @@ pub fn save_web_file(rendered_html: String, path: &str) -> Result<(), ProcessorE
     Ok(())
 }
 
-pub fn process_category(raw_category: &str) -> String {
-    match raw_category {
-        "zahranici" => "zahraničí".into(),
-        "republika" => "republika".into(),
-        "finance" => "finance".into(),
-        "technologie" => "technologie".into(),
-        "veda" => "věda".into(),
-        cat => {
-            error!("Unknown category: {}", cat);
-            "".into()
-        }
-    }
+/*
+ * directory inside web for nested pages, e.g. web/veda/2.html
+ */
+pub fn create_web_dir(dir: &str) -> Result<(), ProcessorError> {
+    fs::create_dir_all(Path::new("web").join(dir))?;
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data::processor::process_category;
-
     #[test]
     fn test_process_articles_create() {}
-
-    #[test]
-    fn test_process_category() {
-        assert_eq!(process_category("zahranici"), "zahraničí");
-        assert_eq!(process_category("republika"), "republika");
-        assert_eq!(process_category("finance"), "finance");
-        assert_eq!(process_category("technologie"), "technologie");
-        assert_eq!(process_category("veda"), "věda");
-        assert_eq!(process_category("invalid"), "");
-    }
 }
@@ -0,0 +1,151 @@
+/*
+ * term similarity of texts
+ * - used to suggest related Articles for a draft
+ * - tf-idf weighted terms, compared by cosine similarity
+ */
+
+use crate::data::slug;
+use std::collections::HashMap;
+
+// shorter words carry almost no meaning
+const TERM_MIN_LENGTH: usize = 3;
+
+// already folded, the terms are compared without diacritics
+const STOP_WORDS: &[&str] = &[
+    "aby", "ale", "ani", "ano", "asi", "bez", "bude", "budou", "byl", "byla", "byli", "bylo",
+    "byly", "coz", "dalsi", "den", "dnes", "jak", "jako", "jake", "jaky", "jeho", "jej", "jeji",
+    "jejich", "jen", "jeste", "jiz", "jsem", "jsme", "jsou", "jste", "kde", "kdo", "kdy", "kdyz",
+    "ktera", "ktere", "kteri", "kterou", "ktery", "mezi", "mit", "muze", "nad", "nam", "nas",
+    "neni", "nebo", "nez", "nich", "nim", "pak", "pod", "podle", "pokud", "pouze", "pro", "proc",
+    "proto", "protoze", "pred", "pres", "pri", "roce", "rok", "roku", "sve", "svou", "svuj", "tak",
+    "take", "tam", "ten", "tento", "tedy", "tim", "tohle", "toho", "tom", "tomu", "tuto", "tyto",
+    "vam", "vas", "vice", "vsak", "vse", "vsech", "zde",
+];
+
+/**
+ * lowercase text without diacritics
+ */
+pub fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.to_lowercase().chars() {
+        match slug::transliterate(c) {
+            Some(ascii) => folded.push_str(ascii),
+            None => folded.push(c),
+        }
+    }
+    folded
+}
+
+/**
+ * meaningful words of a text, folded, in the order of appearance
+ */
+pub fn terms(text: &str) -> Vec<String> {
+    fold(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| term.chars().count() >= TERM_MIN_LENGTH && !STOP_WORDS.contains(term))
+        .map(String::from)
+        .collect()
+}
+
+/**
+ * indexes of the documents most similar to the draft with their score, best first
+ * - documents without any common term are left out
+ */
+pub fn rank(draft: &str, documents: &[String], limit: usize) -> Vec<(usize, f64)> {
+    let draft_counts = term_counts(draft);
+    if draft_counts.is_empty() {
+        return Vec::new();
+    }
+    let document_counts: Vec<HashMap<String, f64>> =
+        documents.iter().map(|d| term_counts(d)).collect();
+
+    // terms frequent across all documents say little about any of them
+    let mut document_frequency: HashMap<&str, f64> = HashMap::new();
+    for counts in &document_counts {
+        for term in counts.keys() {
+            *document_frequency.entry(term.as_str()).or_default() += 1.0;
+        }
+    }
+    let total = documents.len() as f64;
+    let idf = |term: &str| {
+        let df = document_frequency.get(term).copied().unwrap_or_default();
+        ((total + 1.0) / (df + 1.0)).ln() + 1.0
+    };
+
+    let draft_vector = weighted(&draft_counts, &idf);
+    let mut ranked: Vec<(usize, f64)> = document_counts
+        .iter()
+        .enumerate()
+        .map(|(i, counts)| (i, cosine(&draft_vector, &weighted(counts, &idf))))
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.truncate(limit);
+    ranked
+}
+
+fn term_counts(text: &str) -> HashMap<String, f64> {
+    let mut counts = HashMap::new();
+    for term in terms(text) {
+        *counts.entry(term).or_default() += 1.0;
+    }
+    counts
+}
+
+fn weighted<'a>(
+    counts: &'a HashMap<String, f64>,
+    idf: &impl Fn(&str) -> f64,
+) -> HashMap<&'a str, f64> {
+    counts.iter().map(|(term, count)| (term.as_str(), count * idf(term))).collect()
+}
+
+fn cosine(a: &HashMap<&str, f64>, b: &HashMap<&str, f64>) -> f64 {
+    let dot: f64 =
+        a.iter().filter_map(|(term, weight)| b.get(term).map(|other| weight * other)).sum();
+    if dot == 0.0 {
+        return 0.0;
+    }
+    let norm_a = a.values().map(|w| w * w).sum::<f64>().sqrt();
+    let norm_b = b.values().map(|w| w * w).sum::<f64>().sqrt();
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(fold("Příliš Žluťoučký kůň"), "prilis zlutoucky kun");
+    }
+
+    #[test]
+    fn test_terms() {
+        assert_eq!(
+            terms("Vláda je pro nové zákony, které přijala."),
+            vec!["vlada", "nove", "zakony", "prijala"]
+        );
+        assert!(terms("a i v ve na se").is_empty());
+    }
+
+    #[test]
+    fn test_rank() {
+        let documents = vec![
+            "Počasí o víkendu bude slunečné".to_string(),
+            "Vláda schválila rozpočet na příští rok".to_string(),
+            "Sněmovna projedná vládní rozpočet".to_string(),
+        ];
+        let ranked = rank("Rozpočet vlády: vláda schválila škrty", &documents, 5);
+        let indexes: Vec<usize> = ranked.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indexes, vec![1, 2]);
+        assert!(ranked[0].1 > ranked[1].1);
+    }
+
+    #[test]
+    fn test_rank_limit_and_empty() {
+        let documents = vec!["rozpočet".to_string(), "rozpočet státu".to_string()];
+        assert_eq!(rank("rozpočet", &documents, 1).len(), 1);
+        assert!(rank("a v na", &documents, 5).is_empty());
+        assert!(rank("rozpočet", &[], 5).is_empty());
+    }
+}
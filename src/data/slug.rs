@@ -0,0 +1,122 @@
+/*
+ * article file names from titles
+ * - only a-z, 0-9 and single dashes between words
+ * - uniqueness is checked in the database, see DatabaseArticle::unique_base_file_name
+ */
+
+// long titles would make unreadable urls
+pub const SLUG_MAX_LENGTH: usize = 80;
+
+// title without any letter or digit
+const SLUG_EMPTY: &str = "clanek";
+
+// pages, static directories and path prefixes of the web router
+// neither an Article nor a category may take their name
+pub const RESERVED_SLUGS: [&str; 11] =
+    ["index", "news", "search", "opravy", "archiv", "autor", "live", "u", "css", "js", "image"];
+
+/**
+ * file name without the .html suffix
+ */
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+    for c in title.trim().to_lowercase().chars() {
+        match c {
+            'a'..='z' | '0'..='9' => slug.push(c),
+            _ => match transliterate(c) {
+                Some(ascii) => slug.push_str(ascii),
+                // any other character separates words, never two dashes in a row
+                None if !slug.is_empty() && !slug.ends_with('-') => slug.push('-'),
+                None => {}
+            },
+        }
+    }
+    let slug = truncate(&slug, SLUG_MAX_LENGTH);
+    if slug.is_empty() { SLUG_EMPTY.into() } else { slug }
+}
+
+pub fn is_reserved(slug: &str) -> bool {
+    RESERVED_SLUGS.contains(&slug)
+}
+
+/**
+ * the n-th article of the same title, "title-2", "title-3"
+ * - the suffix always fits into the maximum length
+ */
+pub fn with_suffix(slug: &str, n: u32) -> String {
+    let suffix = format!("-{}", n);
+    format!("{}{}", truncate(slug, SLUG_MAX_LENGTH - suffix.len()), suffix)
+}
+
+fn truncate(slug: &str, max_length: usize) -> String {
+    // slug is ascii only, any index is a char boundary
+    let end = slug.len().min(max_length);
+    slug[..end].trim_end_matches('-').to_string()
+}
+
+pub(crate) const fn transliterate(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        // samohlásky, čeština a slovenština
+        'á' => "a",
+        'é' => "e",
+        'ě' => "e",
+        'í' => "i",
+        'ó' => "o",
+        'ô' => "o",
+        'ú' => "u",
+        'ů' => "u",
+        'ý' => "y",
+
+        // souhlásky, čeština a slovenština
+        'č' => "c",
+        'ď' => "d",
+        'ĺ' => "l",
+        'ľ' => "l",
+        'ň' => "n",
+        'ŕ' => "r",
+        'ř' => "r",
+        'š' => "s",
+        'ť' => "t",
+        'ž' => "z",
+
+        // němčina
+        'ä' => "ae",
+        'ö' => "oe",
+        'ü' => "ue",
+        'ß' => "ss",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Příliš žluťoučký kůň"), "prilis-zlutoucky-kun");
+        assert_eq!(slugify("Hello World!"), "hello-world");
+        assert_eq!(slugify("  -- Hello,   World --  "), "hello-world");
+        assert_eq!(slugify("Ľúbostná pôvabná ťava"), "lubostna-povabna-tava");
+        assert_eq!(slugify("Größe der Bücher"), "groesse-der-buecher");
+        assert_eq!(slugify("!!!"), "clanek");
+    }
+
+    #[test]
+    fn test_slugify_max_length() {
+        let slug = slugify(&"slovo ".repeat(30));
+        assert!(slug.len() <= SLUG_MAX_LENGTH);
+        assert!(!slug.ends_with('-'));
+    }
+
+    #[test]
+    fn test_with_suffix() {
+        assert_eq!(with_suffix("hello-world", 2), "hello-world-2");
+
+        let long = "a".repeat(SLUG_MAX_LENGTH);
+        let suffixed = with_suffix(&long, 12);
+        assert_eq!(suffixed.len(), SLUG_MAX_LENGTH);
+        assert!(suffixed.ends_with("a-12"));
+    }
+}
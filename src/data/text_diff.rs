@@ -0,0 +1,154 @@
+/*
+ * word level diff of two texts
+ * - used to compare Article revisions
+ */
+use crate::data::text_processor;
+use similar::{Algorithm, ChangeTag};
+use std::time::{Duration, Instant};
+
+// the diff view must not hang on two unrelated long texts
+const DIFF_DEADLINE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Same,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffPart {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+impl DiffPart {
+    pub fn is_added(&self) -> bool {
+        self.kind == DiffKind::Added
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.kind == DiffKind::Removed
+    }
+}
+
+/**
+ * processed text without the HTML tags, as the reader sees it
+ * - tags are replaced by a space, so that paragraphs don't glue together
+ * - entities are decoded, the template escapes the text again
+ */
+pub fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                text.push(' ');
+            }
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text_processor::unescape(&text)
+}
+
+/**
+ * Myers diff of words, linear in memory
+ * - consecutive words of the same kind are merged into one part
+ * - very different long texts give up on the minimal diff after DIFF_DEADLINE
+ */
+pub fn diff_words(old: &str, new: &str) -> Vec<DiffPart> {
+    let old_words: Vec<&str> = old.split_whitespace().collect();
+    let new_words: Vec<&str> = new.split_whitespace().collect();
+
+    let deadline = Instant::now() + DIFF_DEADLINE;
+    let mut parts = Vec::new();
+    for op in similar::capture_diff_slices_deadline(Algorithm::Myers, &old_words, &new_words, Some(deadline)) {
+        for change in op.iter_changes(&old_words, &new_words) {
+            let kind = match change.tag() {
+                ChangeTag::Equal => DiffKind::Same,
+                ChangeTag::Insert => DiffKind::Added,
+                ChangeTag::Delete => DiffKind::Removed,
+            };
+            push_word(&mut parts, kind, change.value());
+        }
+    }
+    parts
+}
+
+fn push_word(parts: &mut Vec<DiffPart>, kind: DiffKind, word: &str) {
+    match parts.last_mut() {
+        Some(last) if last.kind == kind => {
+            last.text.push(' ');
+            last.text.push_str(word);
+        }
+        _ => parts.push(DiffPart {
+            kind,
+            text: word.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(kind: DiffKind, text: &str) -> DiffPart {
+        DiffPart {
+            kind,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_strip_html() {
+        let text = strip_html("<div class=\"container\"><p>Para 1</p><p>Para 2</p></div>");
+        assert_eq!(text.split_whitespace().collect::<Vec<_>>(), vec!["Para", "1", "Para", "2"]);
+    }
+
+    #[test]
+    fn test_strip_html_entities() {
+        let text = strip_html("<p>Tom &amp; Jerry &lt;3 &#39;x&#39; &amp;lt;</p>");
+        assert_eq!(text.trim(), "Tom & Jerry <3 'x' &lt;");
+    }
+
+    #[test]
+    fn test_diff_words_same() {
+        let diff = diff_words("one two three", "one two three");
+        assert_eq!(diff, vec![part(DiffKind::Same, "one two three")]);
+    }
+
+    #[test]
+    fn test_diff_words_changed() {
+        let diff = diff_words("the quick brown fox jumps", "the slow brown fox runs far");
+        assert_eq!(
+            diff,
+            vec![
+                part(DiffKind::Same, "the"),
+                part(DiffKind::Removed, "quick"),
+                part(DiffKind::Added, "slow"),
+                part(DiffKind::Same, "brown fox"),
+                part(DiffKind::Removed, "jumps"),
+                part(DiffKind::Added, "runs far"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_words_long() {
+        let old = vec!["stará"; 20_000].join(" ");
+        let new = vec!["nová"; 20_000].join(" ");
+        let diff = diff_words(&old, &new);
+        assert!(diff.iter().any(DiffPart::is_removed));
+        assert!(diff.iter().any(DiffPart::is_added));
+    }
+
+    #[test]
+    fn test_diff_words_empty() {
+        assert_eq!(diff_words("", "new text"), vec![part(DiffKind::Added, "new text")]);
+        assert_eq!(diff_words("old text", ""), vec![part(DiffKind::Removed, "old text")]);
+        assert!(diff_words("", "").is_empty());
+    }
+}
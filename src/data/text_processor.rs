@@ -1,3 +1,6 @@
+use crate::data::text_validator;
+use crate::db::database_article_data::ArticleImage;
+use std::collections::HashMap;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -16,29 +19,496 @@ pub fn process_short_text(raw_text: &str) -> String {
         .join("</p><p>")
 }
 
-// TODO do this better
-pub fn process_text(raw_text: &str) -> String {
-    raw_text
+/**
+ * Article text markup, a small Markdown subset, everything else is escaped
+ * - blank line - new paragraph, two blank lines - new section
+ * - ## heading, ### subheading
+ * - lines starting with "- " or "* " - list, "1. ", "2. " ... - numbered list
+ * - lines starting with "> " or a paragraph starting with three spaces - quote
+ * - **bold**, *italic*
+ * - [label](https://...) - link, only http(s), mailto and site relative links
+ * - [[slug]] or [[slug|label]] - link to another Article, titles resolved by the caller
+ * - [[image:N]] - image N of the Article, [[gallery:N,M,...]] - swipeable gallery, alone in a paragraph
+ * - [^N] - footnote, source N of the Article
+ * - {{provider:id}} - embedded YouTube, X, Instagram or map, alone in a paragraph, expanded on render
+ * - backslash before a markup character keeps it as it is
+ */
+pub fn process_text(raw_text: &str, internal_links: &HashMap<String, String>, images: &[ArticleImage]) -> String {
+    let html = raw_text
         .replace("\r\n", "\n")
         .split("\n\n\n")
-        .filter(|block| !block.trim().is_empty())
-        .map(|block| {
-            let inner_html = block
+        .filter(|section| !section.trim().is_empty())
+        .map(|section| {
+            let inner_html = section
                 .split("\n\n")
                 .filter(|s| !s.trim().is_empty())
-                .map(|s| {
-                    if s.starts_with("   ") {
-                        format!("<blockquote>{}</blockquote>", s.trim())
-                    } else {
-                        format!("<p>{}</p>", s.trim().replace("\n", " "))
-                    }
-                })
+                .map(|s| process_block(s, internal_links, images))
                 .collect::<Vec<String>>()
                 .join("");
             format!("<div class=\"container\">{}</div>", inner_html)
         })
         .collect::<Vec<String>>()
-        .join("")
+        .join("");
+    unique_footnote_ids(&html)
+}
+
+/**
+ * file names of all [[slug]] links in the raw text
+ */
+pub fn internal_links(raw_text: &str) -> Vec<String> {
+    let mut links = Vec::new();
+    let mut rest = raw_text;
+    while let Some(start) = rest.find("[[") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("]]") else {
+            break;
+        };
+        let name = internal_link_file_name(&after[..end]);
+        if !name.is_empty() && !is_image_placeholder(&after[..end]) && !links.contains(&name) {
+            links.push(name);
+        }
+        rest = &after[end + 2..];
+    }
+    links
+}
+
+/**
+ * numbers of all images placed in the raw text
+ */
+pub fn image_references(raw_text: &str) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    for block in raw_text.replace("\r\n", "\n").split("\n\n") {
+        for number in image_placeholder(block.trim()).map(|(_, numbers)| numbers).unwrap_or_default() {
+            if !numbers.contains(&number) {
+                numbers.push(number);
+            }
+        }
+    }
+    numbers
+}
+
+/**
+ * words of the raw text as the reader sees them
+ * - images, galleries and embeds aren't words, markup characters are ignored
+ */
+pub fn word_count(raw_text: &str) -> u32 {
+    let words = raw_text
+        .replace("\r\n", "\n")
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|block| image_placeholder(block).is_none() && embed_shortcode(block).is_none())
+        .flat_map(str::split_whitespace)
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count();
+    u32::try_from(words).unwrap_or(u32::MAX)
+}
+
+/**
+ * numbers of all footnotes [^N] in the raw text
+ */
+pub fn footnote_references(raw_text: &str) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    let mut rest = raw_text;
+    while let Some(start) = rest.find("[^") {
+        let escaped = rest[..start].ends_with('\\');
+        if !escaped
+            && let Some((number, _)) = footnote(&rest[start..])
+            && !numbers.contains(&number)
+        {
+            numbers.push(number);
+        }
+        rest = &rest[start + 2..];
+    }
+    numbers
+}
+
+/**
+ * provider and id of all {{provider:id}} blocks in the raw text, not validated
+ */
+pub fn embeds(raw_text: &str) -> Vec<(String, String)> {
+    raw_text
+        .replace("\r\n", "\n")
+        .split("\n\n")
+        .filter_map(|block| embed_shortcode(block.trim()))
+        .map(|(provider, id)| (provider.to_string(), id.to_string()))
+        .collect()
+}
+
+/**
+ * raw text of an Article stored before the markup, rebuilt from its HTML
+ * - the old form wrapped sections, paragraphs and quotes, anything else was typed by the writer
+ * - links, bold and italic become markup, other tags are dropped, markup characters are escaped
+ */
+pub fn legacy_raw_text(html: &str) -> String {
+    html.split("<div class=\"container\">")
+        .map(|section| {
+            let section = section.trim();
+            legacy_blocks(section.strip_suffix("</div>").unwrap_or(section)).join("\n\n")
+        })
+        .filter(|section| !section.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n\n\n")
+}
+
+/**
+ * raw short or mini text of an Article stored before the edit form, process_short_text reversed
+ */
+pub fn legacy_raw_short_text(html: &str) -> String {
+    html.split("</p><p>").map(|paragraph| paragraph.replace("<br>\n", "\n")).collect::<Vec<String>>().join("\n\n")
+}
+
+fn legacy_blocks(section: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut rest = section.trim();
+    while !rest.is_empty() {
+        let (inner, is_quote, remaining) = if let Some((inner, remaining)) =
+            delimited(rest, "<blockquote>", "</blockquote>")
+        {
+            (inner, true, remaining)
+        } else if let Some((inner, remaining)) = delimited(rest, "<p>", "</p>") {
+            (inner, false, remaining)
+        } else {
+            (rest, false, "")
+        };
+        let text = legacy_inline(inner);
+        if is_quote && !text.is_empty() {
+            blocks.push(format!("   {}", text));
+        } else if text.starts_with(['#', '>', '-', '{']) {
+            // would be a heading, a quote, a list or an embed
+            blocks.push(format!("\\{}", text));
+        } else if !text.is_empty() {
+            blocks.push(text);
+        }
+        rest = remaining.trim();
+    }
+    blocks
+}
+
+fn legacy_inline(html: &str) -> String {
+    let mut raw = String::new();
+    // closing markup of each open link, empty for a link the markup doesn't allow
+    let mut open_links = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        raw.push_str(&escape_markup(&unescape(&rest[..start])));
+        rest = &rest[start..];
+        let is_tag = rest[1..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '/');
+        let Some(end) = rest.find('>').filter(|_| is_tag) else {
+            raw.push('<');
+            rest = &rest[1..];
+            continue;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+        match tag.split_whitespace().next().unwrap_or_default().to_lowercase().as_str() {
+            "strong" | "/strong" | "b" | "/b" => raw.push_str("**"),
+            "em" | "/em" | "i" | "/i" => raw.push('*'),
+            "br" | "br/" => raw.push(' '),
+            "a" => match legacy_href(tag).filter(|url| is_allowed_url(url)) {
+                Some(url) => {
+                    raw.push('[');
+                    open_links.push(format!("]({})", url));
+                }
+                None => open_links.push(String::new()),
+            },
+            "/a" => raw.push_str(&open_links.pop().unwrap_or_default()),
+            _ => {}
+        }
+    }
+    raw.push_str(&escape_markup(&unescape(rest)));
+    raw.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn legacy_href(tag: &str) -> Option<String> {
+    let after = &tag[tag.find("href=")? + "href=".len()..];
+    let quote = after.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let (url, _) = after[1..].split_once(quote)?;
+    Some(unescape(url.trim()))
+}
+
+fn escape_markup(text: &str) -> String {
+    text.chars().fold(String::with_capacity(text.len()), |mut escaped, c| {
+        if matches!(c, '\\' | '*' | '[') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        escaped
+    })
+}
+
+fn process_block(block: &str, links: &HashMap<String, String>, images: &[ArticleImage]) -> String {
+    if let Some((is_gallery, numbers)) = image_placeholder(block.trim()) {
+        return image_block(is_gallery, &numbers, images);
+    }
+    if let Some((provider, id)) = embed_shortcode(block.trim())
+        && text_validator::validate_embed(provider, id).is_ok()
+    {
+        // the placeholder is rendered with the page, it may change without editing the Article
+        return format!("<!--embed:{}:{}-->", provider, id);
+    }
+    if block.starts_with("   ") {
+        return format!("<blockquote>{}</blockquote>", process_inline(&join_lines(block.lines()), links));
+    }
+    let block = block.trim();
+    if let Some(heading) = block.strip_prefix("### ") {
+        return format!("<h3>{}</h3>", process_inline(&join_lines(heading.lines()), links));
+    }
+    if let Some(heading) = block.strip_prefix("## ") {
+        return format!("<h2>{}</h2>", process_inline(&join_lines(heading.lines()), links));
+    }
+    let lines: Vec<&str> = block.lines().map(str::trim).collect();
+    if let Some(quote) = strip_all(&lines, |line| line.strip_prefix('>')) {
+        return format!("<blockquote>{}</blockquote>", process_inline(&join_lines(quote.into_iter()), links));
+    }
+    if let Some(items) = strip_all(&lines, |line| line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))) {
+        return format!("<ul>{}</ul>", list_items(&items, links));
+    }
+    if let Some(items) = ordered_items(&lines) {
+        return format!("<ol>{}</ol>", list_items(&items, links));
+    }
+    format!("<p>{}</p>", process_inline(&join_lines(lines.into_iter()), links))
+}
+
+/*
+ * [[image:N]] or [[gallery:N,M,...]], true for a gallery
+ */
+fn image_placeholder(block: &str) -> Option<(bool, Vec<u32>)> {
+    let inner = block.strip_prefix("[[")?.strip_suffix("]]")?;
+    let (kind, numbers) = inner.split_once(':')?;
+    let is_gallery = match kind.trim() {
+        "image" => false,
+        "gallery" => true,
+        _ => return None,
+    };
+    let numbers: Vec<u32> = numbers.split(',').map(|n| n.trim().parse().ok()).collect::<Option<_>>()?;
+    (is_gallery || numbers.len() == 1).then_some((is_gallery, numbers))
+}
+
+fn embed_shortcode(block: &str) -> Option<(&str, &str)> {
+    let (provider, id) = block.strip_prefix("{{")?.strip_suffix("}}")?.split_once(':')?;
+    Some((provider.trim(), id.trim()))
+}
+
+fn is_image_placeholder(link: &str) -> bool {
+    image_placeholder(&format!("[[{}]]", link)).is_some()
+}
+
+/*
+ * unknown images are left out, the form doesn't save such text
+ */
+fn image_block(is_gallery: bool, numbers: &[u32], images: &[ArticleImage]) -> String {
+    let placed: Vec<&ArticleImage> =
+        numbers.iter().filter_map(|number| images.iter().find(|image| image.number == *number)).collect();
+    if !is_gallery {
+        return placed.first().map(|image| figure(image, None)).unwrap_or_default();
+    }
+    if placed.is_empty() {
+        return String::new();
+    }
+    let figures: String =
+        placed.iter().enumerate().map(|(i, image)| figure(image, Some((i + 1, placed.len())))).collect();
+    format!("<div class=\"gallery\">{}</div>", figures)
+}
+
+/*
+ * position and count in a gallery
+ */
+fn figure(image: &ArticleImage, position: Option<(usize, usize)>) -> String {
+    let count = position.map(|(i, n)| format!("<span class=\"gallery-count\">{} / {}</span> ", i, n)).unwrap_or_default();
+    let credit = image.credit_display();
+    let credit = if credit.is_empty() {
+        credit
+    } else {
+        format!(" <span class=\"image-credit\">{}</span>", escape(&credit))
+    };
+    // images uploaded before alt text was required have only the caption
+    let alt = if image.alt.is_empty() { &image.caption } else { &image.alt };
+    format!(
+        "<figure class=\"article-image\"><img src=\"{}\" alt=\"{}\" loading=\"lazy\"><figcaption>{}{}{}</figcaption></figure>",
+        escape(&image.image_820_path),
+        escape(alt),
+        count,
+        escape(&image.caption),
+        credit
+    )
+}
+
+/*
+ * Some only if every line has the marker
+ */
+fn strip_all<'a>(lines: &[&'a str], strip: impl Fn(&'a str) -> Option<&'a str>) -> Option<Vec<&'a str>> {
+    lines.iter().map(|line| strip(line)).collect()
+}
+
+/*
+ * "17. listopadu" starts many paragraphs, a numbered list needs 1. 2. ...
+ */
+fn ordered_items<'a>(lines: &[&'a str]) -> Option<Vec<&'a str>> {
+    if lines.len() < 2 {
+        return None;
+    }
+    lines.iter().enumerate().map(|(i, line)| line.strip_prefix(&format!("{}. ", i + 1))).collect()
+}
+
+fn list_items(items: &[&str], links: &HashMap<String, String>) -> String {
+    items.iter().map(|item| format!("<li>{}</li>", process_inline(item.trim(), links))).collect()
+}
+
+fn join_lines<'a>(lines: impl Iterator<Item = &'a str>) -> String {
+    lines.map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<&str>>().join(" ")
+}
+
+fn process_inline(text: &str, links: &HashMap<String, String>) -> String {
+    let mut html = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let after = &rest[c.len_utf8()..];
+        if c == '\\'
+            && let Some(next) = after.chars().next()
+            && is_markup(next)
+        {
+            html.push_str(&escape(&next.to_string()));
+            rest = &after[next.len_utf8()..];
+        } else if let Some((number, remaining)) = footnote(rest) {
+            html.push_str(&format!(
+                "<sup class=\"footnote-ref\"><a href=\"#fn-{0}\" id=\"fnref-{0}\">{0}</a></sup>",
+                number
+            ));
+            rest = remaining;
+        } else if let Some((link, remaining)) = delimited(rest, "[[", "]]") {
+            html.push_str(&internal_link(link, links));
+            rest = remaining;
+        } else if let Some((inner, remaining)) = emphasis(rest, "**") {
+            html.push_str(&format!("<strong>{}</strong>", process_inline(inner, links)));
+            rest = remaining;
+        } else if let Some((inner, remaining)) = emphasis(rest, "*") {
+            html.push_str(&format!("<em>{}</em>", process_inline(inner, links)));
+            rest = remaining;
+        } else if let Some((label, url, remaining)) = link(rest) {
+            html.push_str(&format!("<a href=\"{}\">{}</a>", escape(url), process_inline(label, links)));
+            rest = remaining;
+        } else {
+            html.push_str(&escape(&c.to_string()));
+            rest = after;
+        }
+    }
+    html
+}
+
+fn delimited<'a>(text: &'a str, open: &str, close: &str) -> Option<(&'a str, &'a str)> {
+    let after = text.strip_prefix(open)?;
+    let end = after.find(close)?;
+    Some((&after[..end], &after[end + close.len()..]))
+}
+
+fn footnote(text: &str) -> Option<(u32, &str)> {
+    let (number, rest) = delimited(text, "[^", "]")?;
+    Some((number.trim().parse().ok()?, rest))
+}
+
+/*
+ * a source cited more than once links back to its first footnote
+ */
+fn unique_footnote_ids(html: &str) -> String {
+    const ID: &str = " id=\"fnref-";
+    let mut unique = String::with_capacity(html.len());
+    let mut seen = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(ID) {
+        let after = &rest[start + ID.len()..];
+        let end = after.find('"').map_or(after.len(), |end| end + 1);
+        let number = &after[..end];
+        unique.push_str(&rest[..start]);
+        if !seen.contains(&number) {
+            seen.push(number);
+            unique.push_str(&rest[start..start + ID.len() + end]);
+        }
+        rest = &after[end..];
+    }
+    unique.push_str(rest);
+    unique
+}
+
+/*
+ * 2 * 3 * 4 isn't italic, the marker has to touch the text
+ */
+fn emphasis<'a>(text: &'a str, marker: &str) -> Option<(&'a str, &'a str)> {
+    let (inner, rest) = delimited(text, marker, marker)?;
+    let touches = !inner.is_empty() && !inner.starts_with(char::is_whitespace) && !inner.ends_with(char::is_whitespace);
+    touches.then_some((inner, rest))
+}
+
+fn link(text: &str) -> Option<(&str, &str, &str)> {
+    let (label, rest) = delimited(text, "[", "]")?;
+    let (url, rest) = parenthesized(rest)?;
+    let url = url.trim();
+    (is_allowed_url(url) && !label.is_empty()).then_some((label, url, rest))
+}
+
+/*
+ * the url ends at the matching ")", urls like wikipedia's keep their own parentheses
+ */
+fn parenthesized(text: &str) -> Option<(&str, &str)> {
+    let after = text.strip_prefix('(')?;
+    let mut depth = 0usize;
+    for (i, c) in after.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some((&after[..i], &after[i + 1..])),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_allowed_url(url: &str) -> bool {
+    ["http://", "https://", "mailto:"].iter().any(|scheme| url.starts_with(scheme))
+        || (url.starts_with('/') && !url.starts_with("//"))
+}
+
+/*
+ * unknown Articles stay plain text
+ */
+fn internal_link(link: &str, links: &HashMap<String, String>) -> String {
+    let (target, label) = link.split_once('|').map_or((link, None), |(target, label)| (target, Some(label.trim())));
+    let file_name = internal_link_file_name(target);
+    links.get(&file_name).map_or_else(
+        || escape(label.unwrap_or_else(|| target.trim())),
+        |title| format!("<a href=\"/{}\">{}</a>", escape(&file_name), escape(label.unwrap_or(title))),
+    )
+}
+
+fn internal_link_file_name(link: &str) -> String {
+    let target = link.split('|').next().unwrap_or_default().trim();
+    if target.is_empty() || target.ends_with(".html") {
+        target.to_string()
+    } else {
+        format!("{}.html", target)
+    }
+}
+
+const fn is_markup(c: char) -> bool {
+    matches!(c, '\\' | '*' | '[' | ']' | '(' | ')' | '>' | '-' | '#' | '{')
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/**
+ * the entities written by escape, &amp; last
+ */
+pub fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
 }
 
 #[cfg(test)]
@@ -52,14 +522,199 @@ mod tests {
         assert_eq!(output, "Para 1</p><p>Para 2<br>\nLine 2");
     }
 
+    fn links() -> HashMap<String, String> {
+        HashMap::from([("other-article.html".to_string(), "Other <Article>".to_string())])
+    }
+
+    fn image(number: u32, credit: &str, source: &str) -> ArticleImage {
+        ArticleImage {
+            number,
+            caption: format!("Caption <{}>", number),
+            alt: if number == 1 { "Alt \"1\"".into() } else { String::new() },
+            credit: credit.into(),
+            source: source.into(),
+            image_50_path: format!("u/a_gallery_{}_image_50.png", number),
+            image_288_path: format!("u/a_gallery_{}_image_288.png", number),
+            image_440_path: format!("u/a_gallery_{}_image_440.png", number),
+            image_820_path: format!("u/a_gallery_{}_image_820.png", number),
+        }
+    }
+
     #[test]
     fn test_process_text() {
         let input = "Block 1 Para 1\n\nBlock 1 Para 2\n\n\n   Block 2 Quote\n\nBlock 2 Para";
-        let output = process_text(input);
+        let output = process_text(input, &HashMap::new(), &[]);
         assert!(output.contains("<div class=\"container\">"));
         assert!(output.contains("<p>Block 1 Para 1</p>"));
         assert!(output.contains("<p>Block 1 Para 2</p>"));
         assert!(output.contains("<blockquote>Block 2 Quote</blockquote>"));
         assert!(output.contains("<p>Block 2 Para</p>"));
     }
+
+    #[test]
+    fn test_paragraph_lines() {
+        assert_eq!(process_text("line 1\r\nline 2", &links(), &[]), "<div class=\"container\"><p>line 1 line 2</p></div>");
+    }
+
+    #[test]
+    fn test_headings() {
+        assert_eq!(process_block("## Heading *one*", &links(), &[]), "<h2>Heading <em>one</em></h2>");
+        assert_eq!(process_block("### Subheading", &links(), &[]), "<h3>Subheading</h3>");
+        assert_eq!(process_block("##No space", &links(), &[]), "<p>##No space</p>");
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(process_block("- one\n* two", &links(), &[]), "<ul><li>one</li><li>two</li></ul>");
+        assert_eq!(process_block("1. one\n2. **two**", &links(), &[]), "<ol><li>one</li><li><strong>two</strong></li></ol>");
+        assert_eq!(process_block("- one\nnot an item", &links(), &[]), "<p>- one not an item</p>");
+        assert_eq!(process_block("17. listopadu", &links(), &[]), "<p>17. listopadu</p>");
+        assert_eq!(process_block("1. one\n3. three", &links(), &[]), "<p>1. one 3. three</p>");
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(process_block("> quoted\n> text", &links(), &[]), "<blockquote>quoted text</blockquote>");
+        assert_eq!(process_block("   legacy quote", &links(), &[]), "<blockquote>legacy quote</blockquote>");
+    }
+
+    #[test]
+    fn test_emphasis() {
+        assert_eq!(process_inline("**bold** and *italic*", &links()), "<strong>bold</strong> and <em>italic</em>");
+        assert_eq!(process_inline("**bold *italic* inside**", &links()), "<strong>bold <em>italic</em> inside</strong>");
+        assert_eq!(process_inline("2 * 3 * 4", &links()), "2 * 3 * 4");
+        assert_eq!(process_inline("*unclosed", &links()), "*unclosed");
+    }
+
+    #[test]
+    fn test_links() {
+        assert_eq!(process_inline("[web](https://a.cz/?a=1&b=2)", &links()), "<a href=\"https://a.cz/?a=1&amp;b=2\">web</a>");
+        assert_eq!(process_inline("[home](/index.html)", &links()), "<a href=\"/index.html\">home</a>");
+        assert_eq!(process_inline("[x](javascript:alert(1))", &links()), "[x](javascript:alert(1))");
+        assert_eq!(process_inline("[x](//evil.com)", &links()), "[x](//evil.com)");
+        assert_eq!(process_inline("[x](https://a.cz\" onclick=\"y)", &links()), "<a href=\"https://a.cz&quot; onclick=&quot;y\">x</a>");
+    }
+
+    #[test]
+    fn test_links_parentheses() {
+        assert_eq!(
+            process_inline("[Merkur](https://cs.wikipedia.org/wiki/Merkur_(rozcestník)) a dál", &links()),
+            "<a href=\"https://cs.wikipedia.org/wiki/Merkur_(rozcestník)\">Merkur</a> a dál"
+        );
+        assert_eq!(
+            process_inline("(viz [web](https://a.cz/x_(y)))", &links()),
+            "(viz <a href=\"https://a.cz/x_(y)\">web</a>)"
+        );
+        assert_eq!(process_inline("[x](https://a.cz/(open", &links()), "[x](https://a.cz/(open");
+    }
+
+    #[test]
+    fn test_internal_links() {
+        assert_eq!(process_inline("[[other-article]]", &links()), "<a href=\"/other-article.html\">Other &lt;Article&gt;</a>");
+        assert_eq!(process_inline("[[other-article.html|see]]", &links()), "<a href=\"/other-article.html\">see</a>");
+        assert_eq!(process_inline("[[missing|label]]", &links()), "label");
+        assert_eq!(internal_links("[[a]] text [[b.html|B]] [[a]] [[]] [[unclosed"), ["a.html", "b.html"]);
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(
+            process_text("<script>alert('x')</script>", &links(), &[]),
+            "<div class=\"container\"><p>&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;</p></div>"
+        );
+        assert_eq!(process_inline("a & b \"c\"", &links()), "a &amp; b &quot;c&quot;");
+        assert_eq!(process_inline("\\*not italic\\*", &links()), "*not italic*");
+        assert_eq!(process_inline("\\x", &links()), "\\x");
+    }
+
+    #[test]
+    fn test_image() {
+        let images = [image(1, "Jan Novák", "ČTK"), image(2, "", "")];
+        assert_eq!(
+            process_block("[[image:1]]", &links(), &images),
+            "<figure class=\"article-image\"><img src=\"u/a_gallery_1_image_820.png\" alt=\"Alt &quot;1&quot;\" loading=\"lazy\">\
+                <figcaption>Caption &lt;1&gt; <span class=\"image-credit\">Foto: Jan Novák, ČTK</span></figcaption></figure>"
+        );
+        let legacy = process_block("[[image: 2]]", &links(), &images);
+        assert!(legacy.contains("alt=\"Caption &lt;2&gt;\""));
+        assert!(legacy.ends_with("<figcaption>Caption &lt;2&gt;</figcaption></figure>"));
+        assert_eq!(process_block("[[image:3]]", &links(), &images), "");
+        assert_eq!(process_block("[[image:1,2]]", &links(), &images), "<p>image:1,2</p>");
+    }
+
+    #[test]
+    fn test_gallery() {
+        let images = [image(1, "", ""), image(2, "", "")];
+        let gallery = process_block("[[gallery:2, 1, 5]]", &links(), &images);
+        assert!(gallery.starts_with("<div class=\"gallery\"><figure"));
+        assert_eq!(gallery.matches("<figure").count(), 2);
+        assert!(gallery.find("gallery_2_image_820").unwrap() < gallery.find("gallery_1_image_820").unwrap());
+        assert!(gallery.contains("<span class=\"gallery-count\">2 / 2</span>"));
+        assert_eq!(process_block("[[gallery:7]]", &links(), &images), "");
+        assert_eq!(process_block("[[gallery:x]]", &links(), &images), "<p>gallery:x</p>");
+    }
+
+    #[test]
+    fn test_image_references() {
+        let text = "Text\n\n[[image:1]]\n\n[[gallery:2,3,1]]\n\nmore [[image:9]] inline\n\n[[other-article]]";
+        assert_eq!(image_references(text), [1, 2, 3]);
+        assert_eq!(internal_links(text), ["other-article.html"]);
+    }
+
+    #[test]
+    fn test_embed() {
+        assert_eq!(process_block("{{youtube:dQw4w9WgXcQ}}", &links(), &[]), "<!--embed:youtube:dQw4w9WgXcQ-->");
+        assert_eq!(process_block(" {{ map : 50.08,14.42 }} ", &links(), &[]), "<!--embed:map:50.08,14.42-->");
+        assert_eq!(process_block("{{youtube:<script>}}", &links(), &[]), "<p>{{youtube:&lt;script&gt;}}</p>");
+        assert_eq!(process_block("see {{x:123}}", &links(), &[]), "<p>see {{x:123}}</p>");
+        assert_eq!(process_block("\\{{x:123}}", &links(), &[]), "<p>{{x:123}}</p>");
+        let text = "Text\n\n{{x:123}}\n\ninline {{x:456}}\n\n{{vimeo:1}}";
+        assert_eq!(embeds(text), [("x".to_string(), "123".to_string()), ("vimeo".to_string(), "1".to_string())]);
+    }
+
+    #[test]
+    fn test_word_count() {
+        assert_eq!(word_count(""), 0);
+        assert_eq!(word_count("## Nadpis\r\n\r\nPříliš **žluťoučký** kůň - úpěl."), 5);
+        assert_eq!(word_count("One two.\n\n[[gallery:1,2]]\n\n{{youtube:dQw4w9WgXcQ}}\n\n- three\n- four"), 4);
+    }
+
+    #[test]
+    fn test_footnotes() {
+        assert_eq!(
+            process_block("Inflation rose[^1].", &links(), &[]),
+            "<p>Inflation rose<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\">1</a></sup>.</p>"
+        );
+        assert_eq!(process_block("\\[^1] and [^x]", &links(), &[]), "<p>[^1] and [^x]</p>");
+        let html = process_text("First[^2], second[^1].\n\nAgain[^2].", &links(), &[]);
+        assert_eq!(html.matches("id=\"fnref-2\"").count(), 1);
+        assert_eq!(html.matches("href=\"#fn-2\"").count(), 2);
+        assert_eq!(footnote_references("a[^2] b[^1] c[^2] \\[^3] [^x]"), [2, 1]);
+    }
+
+    #[test]
+    fn test_legacy_raw_text() {
+        let html = "<div class=\"container\"><p>See <a href=\"https://www.czso.cz\">the office</a> &amp; <b>more</b></p>\
+            <blockquote>A quote</blockquote></div><div class=\"container\"><p>- 2 * 3 [note]</p>\
+            <p>a < b <a href=\"javascript:alert(1)\">x</a><br>y</p></div>";
+        let raw = legacy_raw_text(html);
+        assert_eq!(
+            raw,
+            "See [the office](https://www.czso.cz) & **more**\n\n   A quote\n\n\n\\- 2 \\* 3 \\[note]\n\na < b x y"
+        );
+        // the first edit renders the text as it was
+        assert_eq!(
+            process_text(&raw, &links(), &[]),
+            "<div class=\"container\"><p>See <a href=\"https://www.czso.cz\">the office</a> &amp; <strong>more</strong></p>\
+            <blockquote>A quote</blockquote></div><div class=\"container\"><p>- 2 * 3 [note]</p>\
+            <p>a &lt; b x y</p></div>"
+        );
+    }
+
+    #[test]
+    fn test_legacy_raw_short_text() {
+        let raw = legacy_raw_short_text("Para 1</p><p>Para 2<br>\nLine 2");
+        assert_eq!(raw, "Para 1\n\nPara 2\nLine 2");
+        assert_eq!(process_short_text(&raw), "Para 1</p><p>Para 2<br>\nLine 2");
+    }
 }
@@ -2,8 +2,8 @@ use lazy_static::lazy_static;
 use regex::Regex;
 use thiserror::Error;
 use TextValidationError::{
-    InvalidCharacter, InvalidLength, RequiredFieldMissing, SearchOnlyAlphanumeric,
-    SearchOnlyAlphanumericAndSpaces, SimpleInputIncorrectCharacter,
+    InvalidCharacter, InvalidEmbedId, InvalidLength, InvalidUrl, RequiredFieldMissing, SearchOnlyAlphanumeric,
+    SearchOnlyAlphanumericAndSpaces, SimpleInputIncorrectCharacter, UnknownEmbedProvider,
 };
 
 lazy_static! {
@@ -12,6 +12,53 @@ lazy_static! {
             eprintln!("CRITICAL ERROR: AUTHOR_NAME_REGEX initialization failed: {}", e);
             std::process::exit(1);
         });
+    static ref YOUTUBE_ID_REGEX: Regex = Regex::new(r"^[A-Za-z0-9_-]{11}$").unwrap_or_else(|e| {
+        eprintln!("CRITICAL ERROR: YOUTUBE_ID_REGEX initialization failed: {}", e);
+        std::process::exit(1);
+    });
+    static ref X_ID_REGEX: Regex = Regex::new(r"^[0-9]{1,20}$").unwrap_or_else(|e| {
+        eprintln!("CRITICAL ERROR: X_ID_REGEX initialization failed: {}", e);
+        std::process::exit(1);
+    });
+    static ref INSTAGRAM_ID_REGEX: Regex = Regex::new(r"^[A-Za-z0-9_-]{5,40}$").unwrap_or_else(|e| {
+        eprintln!("CRITICAL ERROR: INSTAGRAM_ID_REGEX initialization failed: {}", e);
+        std::process::exit(1);
+    });
+}
+
+// zoom of a map embed without one
+pub const MAP_DEFAULT_ZOOM: u8 = 15;
+
+/**
+ * providers which may be embedded in the Article text
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedProvider {
+    YouTube,
+    X,
+    Instagram,
+    Map,
+}
+
+impl EmbedProvider {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "youtube" => Some(Self::YouTube),
+            "x" => Some(Self::X),
+            "instagram" => Some(Self::Instagram),
+            "map" => Some(Self::Map),
+            _ => None,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::YouTube => "youtube",
+            Self::X => "x",
+            Self::Instagram => "instagram",
+            Self::Map => "map",
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -33,6 +80,15 @@ pub enum TextValidationError {
 
     #[error("is required but not set")]
     RequiredFieldMissing,
+
+    #[error("embeds of {0} are not allowed")]
+    UnknownEmbedProvider(String),
+
+    #[error("invalid embed id {0}")]
+    InvalidEmbedId(String),
+
+    #[error("invalid url {0}, use http:// or https://")]
+    InvalidUrl(String),
 }
 
 // TODO use validator framework instead
@@ -130,6 +186,52 @@ pub fn validate_input_simple(input: &str) -> Result<(), TextValidationError> {
     Ok(())
 }
 
+/**
+ * {{provider:id}} of the Article text, only whitelisted providers and well formed ids
+ */
+pub fn validate_embed(provider: &str, id: &str) -> Result<EmbedProvider, TextValidationError> {
+    let provider = EmbedProvider::from_name(provider).ok_or_else(|| UnknownEmbedProvider(provider.to_string()))?;
+    let valid = match provider {
+        EmbedProvider::YouTube => YOUTUBE_ID_REGEX.is_match(id),
+        EmbedProvider::X => X_ID_REGEX.is_match(id),
+        EmbedProvider::Instagram => INSTAGRAM_ID_REGEX.is_match(id),
+        EmbedProvider::Map => map_position(id).is_some(),
+    };
+    if valid { Ok(provider) } else { Err(InvalidEmbedId(id.to_string())) }
+}
+
+/**
+ * latitude, longitude and optional zoom of a map embed, "50.0875,14.4213" or "50.0875,14.4213,12"
+ */
+pub fn map_position(id: &str) -> Option<(f64, f64, u8)> {
+    let mut parts = id.split(',');
+    let latitude: f64 = parts.next()?.trim().parse().ok()?;
+    let longitude: f64 = parts.next()?.trim().parse().ok()?;
+    let zoom: u8 = parts.next().map_or(Some(MAP_DEFAULT_ZOOM), |zoom| zoom.trim().parse().ok())?;
+    let valid = parts.next().is_none()
+        && (-90.0..=90.0).contains(&latitude)
+        && (-180.0..=180.0).contains(&longitude)
+        && (1..=19).contains(&zoom);
+    valid.then_some((latitude, longitude, zoom))
+}
+
+/**
+ * absolute http(s) URL of a web page, e.g. a source of the Article
+ */
+pub fn validate_url(input: &str) -> Result<(), TextValidationError> {
+    if input.len() > 2000 {
+        return Err(InvalidLength);
+    }
+    let host = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .and_then(|rest| rest.split(['/', '?', '#']).next())
+        .unwrap_or_default();
+    let host_valid = !host.is_empty() && !host.starts_with('.') && !host.contains('@');
+    let chars_valid = input.chars().all(|c| !c.is_whitespace() && !c.is_control() && !matches!(c, '"' | '<' | '>'));
+    if host_valid && chars_valid { Ok(()) } else { Err(InvalidUrl(input.to_string())) }
+}
+
 const fn validate_required(input: &str) -> bool {
     !input.is_empty()
 }
@@ -183,6 +285,41 @@ mod tests {
         assert!(validate_input_simple("Hello-World").is_err()); // Hyphen is not allowed
     }
 
+    #[test]
+    fn test_validate_embed() {
+        assert_eq!(validate_embed("youtube", "dQw4w9WgXcQ").ok(), Some(EmbedProvider::YouTube));
+        assert!(validate_embed("youtube", "dQw4w9WgXc\"").is_err());
+        assert!(validate_embed("youtube", "short").is_err());
+        assert_eq!(validate_embed("x", "1585341984679469056").ok(), Some(EmbedProvider::X));
+        assert!(validate_embed("x", "jack/status/20").is_err());
+        assert_eq!(validate_embed("instagram", "C8x_Yz-AbCd").ok(), Some(EmbedProvider::Instagram));
+        assert!(validate_embed("instagram", "../../evil").is_err());
+        assert_eq!(validate_embed("map", "50.0875,14.4213").ok(), Some(EmbedProvider::Map));
+        assert!(validate_embed("map", "50.0875,14.4213,25").is_err());
+        assert!(matches!(validate_embed("vimeo", "123"), Err(UnknownEmbedProvider(_))));
+    }
+
+    #[test]
+    fn test_map_position() {
+        assert_eq!(map_position("50.0875,14.4213"), Some((50.0875, 14.4213, MAP_DEFAULT_ZOOM)));
+        assert_eq!(map_position("-33.86, 151.21, 12"), Some((-33.86, 151.21, 12)));
+        assert_eq!(map_position("91,14"), None);
+        assert_eq!(map_position("50,14,12,1"), None);
+        assert_eq!(map_position("NaN,14"), None);
+    }
+
+    #[test]
+    fn test_validate_url() {
+        assert!(validate_url("https://www.czso.cz/csu/czso/inflace").is_ok());
+        assert!(validate_url("http://example.com?q=1#top").is_ok());
+        assert!(validate_url("https://").is_err());
+        assert!(validate_url("ftp://example.com").is_err());
+        assert!(validate_url("javascript:alert(1)").is_err());
+        assert!(validate_url("https://example.com/a b").is_err());
+        assert!(validate_url("https://example.com/\"onmouseover=\"x").is_err());
+        assert!(validate_url("https://user@evil.com").is_err());
+    }
+
     #[test]
     fn test_validate_search_query_too_short() {
         assert!(validate_search_query("").is_err());
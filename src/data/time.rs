@@ -1,7 +1,68 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use chrono_tz::Tz;
 use chrono_tz::Tz::Europe__Prague;
 
+// format of the HTML datetime-local input
+const LOCAL_INPUT_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
 pub fn to_prague_time(utc: DateTime<Utc>) -> DateTime<Tz> {
     utc.with_timezone(&Europe__Prague)
 }
+
+/**
+ * Prague local time from a form input to UTC
+ * - the earlier moment is used if the local time is ambiguous
+ */
+pub fn from_prague_local_input(local: &str) -> Option<DateTime<Utc>> {
+    let naive = NaiveDateTime::parse_from_str(local.trim(), LOCAL_INPUT_FORMAT).ok()?;
+    naive.and_local_timezone(Europe__Prague).earliest().map(|t| t.with_timezone(&Utc))
+}
+
+/**
+ * UTC to Prague local time, as expected by the form input
+ */
+pub fn to_prague_local_input(utc: DateTime<Utc>) -> String {
+    to_prague_time(utc).format(LOCAL_INPUT_FORMAT).to_string()
+}
+
+/**
+ * UTC moment of the Prague midnight which starts the date
+ */
+pub fn prague_midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    let naive = date.and_hms_opt(0, 0, 0)?;
+    naive.and_local_timezone(Europe__Prague).earliest().map(|t| t.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_from_prague_local_input() {
+        // winter time, UTC+1
+        let utc = from_prague_local_input("2024-01-15T10:00");
+        assert_eq!(utc, Some(Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap()));
+
+        // summer time, UTC+2
+        let utc = from_prague_local_input("2024-07-15T10:00");
+        assert_eq!(utc, Some(Utc.with_ymd_and_hms(2024, 7, 15, 8, 0, 0).unwrap()));
+
+        assert_eq!(from_prague_local_input("not a date"), None);
+    }
+
+    #[test]
+    fn test_prague_midnight() {
+        let date = NaiveDate::from_ymd_opt(2025, 11, 17).unwrap();
+        assert_eq!(prague_midnight(date), Some(Utc.with_ymd_and_hms(2025, 11, 16, 23, 0, 0).unwrap()));
+
+        let date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        assert_eq!(prague_midnight(date), Some(Utc.with_ymd_and_hms(2025, 6, 30, 22, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_to_prague_local_input() {
+        let utc = Utc.with_ymd_and_hms(2024, 7, 15, 8, 0, 0).unwrap();
+        assert_eq!(to_prague_local_input(utc), "2024-07-15T10:00");
+    }
+}
@@ -1,7 +1,12 @@
+use crate::data::{library, text_processor};
+use crate::db::database_article_data::PublishState;
+use crate::db::database_category::{default_categories, Category, HOMEPAGE_SECTIONS};
 use std::convert::Infallible;
 use surrealdb::engine::any::Any;
 use surrealdb::Surreal;
+use surrealdb_types::SurrealValue;
 use thiserror::Error;
+use tracing::error;
 
 const DATABASE_DEV: &str = "rocksdb://axiomatik.db";
 const DATABASE_TEST: &str = "mem://";
@@ -80,11 +85,38 @@ async fn prepare_as_if_empty(surreal: &DatabaseSurreal) -> Result<(), SurrealErr
         DEFINE TABLE article SCHEMALESS;        
         DEFINE INDEX username ON article FIELDS user;
         DEFINE INDEX article_file_name ON article FIELDS article_file_name UNIQUE;
+        DEFINE INDEX uuid ON article FIELDS uuid UNIQUE;
         
         /* Article Update Status */
         DEFINE TABLE article_update_status SCHEMALESS;
         DEFINE INDEX article_file_name ON article_update_status FIELDS article_file_name UNIQUE;
         
+        /* Article Revision */
+        DEFINE TABLE article_revision SCHEMALESS;
+        DEFINE INDEX article_uuid ON article_revision FIELDS article_uuid;
+
+        /* Category */
+        DEFINE TABLE category SCHEMALESS;
+        DEFINE INDEX slug ON category FIELDS slug UNIQUE;
+
+        /* Live Blog Entry */
+        DEFINE TABLE live_blog_entry SCHEMALESS;
+        DEFINE INDEX article_uuid ON live_blog_entry FIELDS article_uuid;
+
+        /* Correction */
+        DEFINE TABLE correction SCHEMALESS;
+        DEFINE INDEX article_uuid ON correction FIELDS article_uuid;
+
+        /* Homepage Slot */
+        DEFINE TABLE homepage_slot SCHEMALESS;
+        DEFINE INDEX uuid ON homepage_slot FIELDS uuid UNIQUE;
+
+        /* Breaking News */
+        DEFINE TABLE breaking_news SCHEMALESS;
+
+        /* Import Source */
+        DEFINE TABLE import_source SCHEMALESS;
+
         /* Article Views */
         DEFINE TABLE article_views SCHEMALESS;
         DEFINE INDEX article_file_name ON article_views FIELDS article_file_name UNIQUE;
@@ -95,5 +127,331 @@ async fn prepare_as_if_empty(surreal: &DatabaseSurreal) -> Result<(), SurrealErr
         ",
         )
         .await?;
+
+    // Articles used to be keyed by the file name and related to each other by it
+    surreal
+        .db
+        .query(
+            r#"
+        REMOVE INDEX IF EXISTS article_file_name ON article_revision;
+        BEGIN;
+        LET $legacy = SELECT * OMIT id FROM article WHERE previous_file_names = NONE;
+        DELETE article WHERE previous_file_names = NONE;
+        FOR $article IN $legacy {
+            CREATE type::record("article", <string> $article.uuid) CONTENT $article;
+        };
+        UPDATE article SET
+            related_articles = (SELECT VALUE uuid FROM article WHERE article_file_name IN $parent.related_articles),
+            previous_file_names = []
+            WHERE previous_file_names = NONE;
+        UPDATE article_revision SET
+            article_uuid = article.uuid,
+            article.related_articles = (SELECT VALUE uuid FROM article WHERE article_file_name IN $parent.article.related_articles),
+            article.previous_file_names = []
+            WHERE article.previous_file_names = NONE;
+        COMMIT;
+        "#,
+        )
+        .await?
+        .check()
+        .map_err(|e| {
+            error!("article migration failed: {}", e);
+            SurrealError::InvalidStatement
+        })?;
+
+    // Articles stored before the edit form have only the processed text
+    backfill_raw_texts(surreal).await?;
+
+    // Articles stored before the publish states were introduced are live
+    surreal
+        .db
+        .query("UPDATE article SET publish_state = $published WHERE publish_state = NONE")
+        .bind(("published", PublishState::Published))
+        .await?;
+
+    // Users stored before the author pages have no profile
+    surreal
+        .db
+        .query("UPDATE user SET bio = '', photo_path = '' WHERE bio = NONE")
+        .await?;
+
+    // the review workflow records state transitions
+    surreal
+        .db
+        .query("UPDATE article SET state_transitions = [] WHERE state_transitions = NONE")
+        .await?;
+    surreal
+        .db
+        .query("UPDATE article_revision SET article.state_transitions = [] WHERE article.state_transitions = NONE")
+        .await?;
+
+    // Articles stored before live blogs are regular Articles
+    surreal
+        .db
+        .query("UPDATE article SET is_live_blog = false WHERE is_live_blog = NONE")
+        .await?;
+    surreal
+        .db
+        .query("UPDATE article_revision SET article.is_live_blog = false WHERE article.is_live_blog = NONE")
+        .await?;
+
+    // Articles stored before inline images have only the main image
+    surreal.db.query("UPDATE article SET images = [] WHERE images = NONE").await?;
+    surreal
+        .db
+        .query("UPDATE article_revision SET article.images = [] WHERE article.images = NONE")
+        .await?;
+
+    // images uploaded before alt text and credits were required have them empty
+    surreal
+        .db
+        .query(
+            r"
+        UPDATE article SET image_alt = '', image_credit = '', image_source = '' WHERE image_alt = NONE;
+        UPDATE article SET images = images.map(|$image| { alt: '', credit: '', source: '' }.extend($image))
+            WHERE images.any(|$image| $image.alt = NONE);
+        UPDATE article_revision SET article.image_alt = '', article.image_credit = '', article.image_source = ''
+            WHERE article.image_alt = NONE;
+        UPDATE article_revision
+            SET article.images = article.images.map(|$image| { alt: '', credit: '', source: '' }.extend($image))
+            WHERE article.images.any(|$image| $image.alt = NONE);
+        ",
+        )
+        .await?;
+
+    // Articles stored before the sources section cite nothing
+    surreal.db.query("UPDATE article SET sources = [] WHERE sources = NONE").await?;
+    surreal
+        .db
+        .query("UPDATE article_revision SET article.sources = [] WHERE article.sources = NONE")
+        .await?;
+
+    // Articles stored before the reading time was shown were never edited
+    surreal.db.query("UPDATE article SET updated = created WHERE updated = NONE").await?;
+    surreal
+        .db
+        .query("UPDATE article_revision SET article.updated = article.created WHERE article.updated = NONE")
+        .await?;
+    backfill_word_counts(surreal).await?;
+
+    // the homepage sections used to be fixed
+    for (slug, label) in HOMEPAGE_SECTIONS {
+        surreal
+            .db
+            .query("UPDATE type::record('category', $slug) SET homepage_label = $label WHERE homepage_label = NONE")
+            .bind(("slug", slug))
+            .bind(("label", label))
+            .await?;
+    }
+    surreal.db.query("UPDATE category SET homepage_label = '' WHERE homepage_label = NONE").await?;
+
+    // categories used to be hardcoded, a fresh database starts with them
+    let mut response = surreal.db.query("SELECT VALUE slug FROM category LIMIT 1").await?;
+    let any_category: Vec<String> = response.take(0)?;
+    if any_category.is_empty() {
+        for category in default_categories() {
+            let _: Option<Category> =
+                surreal.db.create(("category", category.slug.clone())).content(category).await?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(SurrealValue)]
+struct LegacyText {
+    key: String,
+    text: String,
+    short_text: String,
+    mini_text: String,
+}
+
+/*
+ * the raw text is rebuilt from the HTML, the edit form must not show the tags
+ */
+async fn backfill_raw_texts(surreal: &DatabaseSurreal) -> Result<(), SurrealError> {
+    let mut response = surreal
+        .db
+        .query(
+            r"
+        SELECT record::id(id) AS key, text ?? '' AS text, short_text ?? '' AS short_text,
+            mini_text ?? '' AS mini_text FROM article WHERE text_raw = NONE;
+        SELECT record::id(id) AS key, article.text ?? '' AS text, article.short_text ?? '' AS short_text,
+            article.mini_text ?? '' AS mini_text FROM article_revision WHERE article.text_raw = NONE;
+        ",
+        )
+        .await?;
+    let articles: Vec<LegacyText> = response.take(0)?;
+    let revisions: Vec<LegacyText> = response.take(1)?;
+
+    for (table, prefix, legacy) in [("article", "", articles), ("article_revision", "article.", revisions)] {
+        for text in legacy {
+            surreal
+                .db
+                .query(format!(
+                    "UPDATE type::record($table, $key) SET {prefix}text_raw = $text_raw, \
+                    {prefix}short_text_raw = $short_text_raw, {prefix}mini_text_raw = $mini_text_raw"
+                ))
+                .bind(("table", table))
+                .bind(("key", text.key))
+                .bind(("text_raw", text_processor::legacy_raw_text(&text.text)))
+                .bind(("short_text_raw", text_processor::legacy_raw_short_text(&text.short_text)))
+                .bind(("mini_text_raw", text_processor::legacy_raw_short_text(&text.mini_text)))
+                .await?;
+        }
+    }
     Ok(())
 }
+
+#[derive(SurrealValue)]
+struct UncountedText {
+    key: String,
+    text_raw: String,
+}
+
+/*
+ * counted by the same rules as new Articles
+ */
+async fn backfill_word_counts(surreal: &DatabaseSurreal) -> Result<(), SurrealError> {
+    let mut response = surreal
+        .db
+        .query(
+            r"
+        SELECT record::id(id) AS key, text_raw ?? '' AS text_raw FROM article WHERE word_count = NONE;
+        SELECT record::id(id) AS key, article.text_raw ?? '' AS text_raw FROM article_revision
+            WHERE article.word_count = NONE;
+        ",
+        )
+        .await?;
+    let articles: Vec<UncountedText> = response.take(0)?;
+    let revisions: Vec<UncountedText> = response.take(1)?;
+
+    for (table, prefix, uncounted) in [("article", "", articles), ("article_revision", "article.", revisions)] {
+        for text in uncounted {
+            let words = text_processor::word_count(&text.text_raw);
+            surreal
+                .db
+                .query(format!(
+                    "UPDATE type::record($table, $key) SET {prefix}word_count = $words, {prefix}reading_minutes = $minutes"
+                ))
+                .bind(("table", table))
+                .bind(("key", text.key))
+                .bind(("words", words))
+                .bind(("minutes", library::reading_minutes(words)))
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::text_processor;
+    use crate::db::database::{init_in_memory_db_connection, prepare_as_if_empty};
+    use crate::db::database_article::DatabaseArticle;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use crate::trust::me::TrustError;
+    use surrealdb::types::Uuid;
+
+    #[tokio::test]
+    async fn test_prepare_rekeys_legacy_articles() -> Result<(), TrustError> {
+        let surreal = init_in_memory_db_connection().await?;
+        surreal
+            .db
+            .query(
+                r#"
+            CREATE article:`legacy-related.html` CONTENT {
+                uuid: rand::uuid(), article_file_name: "legacy-related.html", related_articles: []
+            };
+            CREATE article:`legacy.html` CONTENT {
+                uuid: rand::uuid(), article_file_name: "legacy.html", related_articles: ["legacy-related.html"]
+            };
+            "#,
+            )
+            .await?;
+
+        prepare_as_if_empty(&surreal).await?;
+
+        let mut response = surreal
+            .db
+            .query(
+                r"
+            SELECT VALUE [<string> uuid, record::id(id)] FROM article WHERE article_file_name = 'legacy.html';
+            SELECT VALUE uuid FROM article WHERE article_file_name = 'legacy-related.html';
+            SELECT VALUE related_articles FROM article WHERE article_file_name = 'legacy.html';
+            SELECT VALUE previous_file_names FROM article;
+            ",
+            )
+            .await?;
+        let keys: Vec<Vec<String>> = response.take(0)?;
+        let related_uuid: Vec<Uuid> = response.take(1)?;
+        let related: Vec<Vec<Uuid>> = response.take(2)?;
+        let previous: Vec<Vec<String>> = response.take(3)?;
+
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0][0], keys[0][1]);
+        assert_eq!(related, vec![related_uuid]);
+        assert_eq!(previous, vec![Vec::<String>::new(); 2]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prepare_backfills_legacy_fields() -> Result<(), TrustError> {
+        let surreal = init_in_memory_db_connection().await?;
+        surreal
+            .db
+            .query(
+                r#"
+            CREATE article:`legacy-fields.html` CONTENT {
+                uuid: rand::uuid(), article_file_name: "legacy-fields.html",
+                author: "Author", username: "legacy", created: d"2024-05-01T10:00:00Z",
+                title: "Legacy Fields", mini_text: "mini",
+                text: "<div class=\"container\"><p>Jedno <a href=\"https://www.czso.cz\">dva</a> tři.</p></div>",
+                short_text: "short<br>\nline</p><p>next",
+                image_desc: "desc", image_50_path: "u/legacy-fields_image_50.png",
+                image_288_path: "u/legacy-fields_image_288.png", image_440_path: "u/legacy-fields_image_440.png",
+                image_820_path: "u/legacy-fields_image_820.png",
+                has_video: false, video_path: "", has_audio: false, audio_path: "",
+                category: "zahranici", related_articles: [], is_main: false, is_exclusive: false
+            };
+            "#,
+            )
+            .await?;
+
+        prepare_as_if_empty(&surreal).await?;
+
+        let mut response = surreal
+            .db
+            .query(
+                r"
+            SELECT VALUE [text_raw, short_text_raw, mini_text_raw] FROM article;
+            SELECT VALUE array::len(images) FROM article;
+            SELECT VALUE array::len(sources) FROM article;
+            SELECT VALUE [image_alt, image_credit, image_source] FROM article;
+            ",
+            )
+            .await?;
+        let raw: Vec<Vec<String>> = response.take(0)?;
+        let images: Vec<u32> = response.take(1)?;
+        let sources: Vec<u32> = response.take(2)?;
+        let credits: Vec<Vec<String>> = response.take(3)?;
+        assert_eq!(
+            raw,
+            vec![vec!["Jedno [dva](https://www.czso.cz) tři.".to_string(), "short\nline\n\nnext".into(), "mini".into()]]
+        );
+        assert_eq!(images, vec![0]);
+        assert_eq!(sources, vec![0]);
+        assert_eq!(credits, vec![vec![String::new(); 3]]);
+
+        // the legacy Article is a complete one now
+        let article = DatabaseArticle::new(Arc::new(surreal)).article_by_file_name("legacy-fields.html").await?;
+        assert_eq!(article.updated, article.created);
+        assert_eq!(article.word_count, 3);
+        assert_eq!(article.reading_minutes, 1);
+
+        // the first edit renders the page as it was, the tags don't show up as text
+        assert_eq!(text_processor::process_text(&article.text_raw, &HashMap::new(), &article.images), article.text);
+        assert_eq!(text_processor::process_short_text(&article.short_text_raw), article.short_text);
+        Ok(())
+    }
+}
@@ -1,19 +1,27 @@
+use crate::data::slug;
 use crate::db::database;
 use crate::db::database::{DatabaseSurreal, SurrealError};
-use crate::db::database_article::SurrealArticleError::ArticleNotFound;
+use crate::db::database_article::SurrealArticleError::{ArticleNotFound, RevisionNotFound};
 use crate::db::database_article_data::{
-    AccountArticleData, Article, MainArticleData, MiniArticleData, ShortArticleData, TopArticleData,
+    AccountArticleData, Article, ArticleListData, ArticleListSort, ArticleRevision, Correction, ImageCreditData,
+    LiveBlogEntry, MainArticleData, MiniArticleData, LinkedArticleData, PublishState, ReviewArticleData,
+    ShortArticleData, SimilarArticleData,
 };
 use crate::db::database_system::SurrealSystemError;
+use chrono::{DateTime, Utc};
 use regex;
 use std::convert::Into;
 use std::string::ToString;
 use std::sync::Arc;
+use surrealdb::types::Uuid;
 use thiserror::Error;
 use tracing::log::debug;
 use tracing::warn;
 
 const ARTICLE: &str = "article";
+const ARTICLE_REVISION: &str = "article_revision";
+const LIVE_BLOG_ENTRY: &str = "live_blog_entry";
+const CORRECTION: &str = "correction";
 
 #[derive(Debug, Error)]
 pub enum SurrealArticleError {
@@ -22,6 +30,9 @@ pub enum SurrealArticleError {
 
     #[error("article not found {0}")]
     ArticleNotFound(String),
+
+    #[error("article revision not found {0}")]
+    RevisionNotFound(String),
 }
 
 /**
@@ -51,12 +62,173 @@ impl DatabaseArticle {
         let _: Option<Article> = self
             .surreal
             .db
-            .create((ARTICLE, article.article_file_name.clone()))
+            .create((ARTICLE, article.uuid.to_string()))
+            .content(article)
+            .await?;
+        Ok(())
+    }
+
+    /**
+     * used for
+     * - edit of an existing article, the record key is the uuid, the file name may change
+     */
+    pub async fn update_article(&self, article: Article) -> Result<(), SurrealArticleError> {
+        debug!("update_article: {:?}", article);
+        let _: Option<Article> = self
+            .surreal
+            .db
+            .update((ARTICLE, article.uuid.to_string()))
             .content(article)
             .await?;
         Ok(())
     }
 
+    /**
+     * keep the current version of an Article before it is changed
+     */
+    pub async fn create_revision(
+        &self,
+        article: Article,
+        editor: &str,
+    ) -> Result<(), SurrealArticleError> {
+        debug!("create_revision: article_file_name={}", article.article_file_name);
+        let revision_id = Uuid::new().to_string();
+        let _: Option<ArticleRevision> = self
+            .surreal
+            .db
+            .create((ARTICLE_REVISION, revision_id.clone()))
+            .content(ArticleRevision {
+                revision_id,
+                article_uuid: article.uuid,
+                editor: editor.to_string(),
+                revised: Utc::now(),
+                article,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /**
+     * used for
+     * - revision history of an Article, newest first
+     */
+    pub async fn revisions_by_article(
+        &self,
+        article: Uuid,
+    ) -> Result<Vec<ArticleRevision>, SurrealArticleError> {
+        debug!("revisions_by_article: article={article}");
+
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM article_revision \
+                    WHERE article_uuid = $article \
+                    ORDER BY revised DESC",
+            )
+            .bind(("article", article))
+            .await?;
+        let revisions: Vec<ArticleRevision> = response.take(0)?;
+        Ok(revisions)
+    }
+
+    pub async fn revision_by_id(
+        &self,
+        revision_id: &str,
+    ) -> Result<ArticleRevision, SurrealArticleError> {
+        debug!("revision_by_id: revision_id={revision_id}");
+
+        let revision_o: Option<ArticleRevision> =
+            self.surreal.db.select((ARTICLE_REVISION, revision_id.to_string())).await?;
+        revision_o.map_or_else(|| Err(RevisionNotFound(revision_id.into())), Ok)
+    }
+
+    pub async fn create_live_blog_entry(&self, entry: LiveBlogEntry) -> Result<(), SurrealArticleError> {
+        debug!("create_live_blog_entry: article={}", entry.article_uuid);
+        let _: Option<LiveBlogEntry> =
+            self.surreal.db.create((LIVE_BLOG_ENTRY, entry.uuid.to_string())).content(entry).await?;
+        Ok(())
+    }
+
+    /**
+     * used for
+     * - the live blog Article, newest first
+     */
+    pub async fn live_blog_entries(&self, article: Uuid) -> Result<Vec<LiveBlogEntry>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM live_blog_entry \
+                    WHERE article_uuid = $article \
+                    ORDER BY created DESC",
+            )
+            .bind(("article", article))
+            .await?;
+        let entries: Vec<LiveBlogEntry> = response.take(0)?;
+        Ok(entries)
+    }
+
+    /**
+     * used for
+     * - an open live blog page, which asks for entries it doesn't show yet, newest first
+     */
+    pub async fn live_blog_entries_since(
+        &self,
+        article: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<LiveBlogEntry>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM live_blog_entry \
+                    WHERE article_uuid = $article AND created > $since \
+                    ORDER BY created DESC",
+            )
+            .bind(("article", article))
+            .bind(("since", since))
+            .await?;
+        let entries: Vec<LiveBlogEntry> = response.take(0)?;
+        Ok(entries)
+    }
+
+    pub async fn create_correction(&self, correction: Correction) -> Result<(), SurrealArticleError> {
+        debug!("create_correction: article={}", correction.article_uuid);
+        let _: Option<Correction> =
+            self.surreal.db.create((CORRECTION, correction.uuid.to_string())).content(correction).await?;
+        Ok(())
+    }
+
+    /**
+     * used for
+     * - the box at the end of the Article, oldest first
+     */
+    pub async fn corrections_by_article(&self, article: Uuid) -> Result<Vec<Correction>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM correction \
+                    WHERE article_uuid = $article \
+                    ORDER BY created ASC",
+            )
+            .bind(("article", article))
+            .await?;
+        let corrections: Vec<Correction> = response.take(0)?;
+        Ok(corrections)
+    }
+
+    /**
+     * used for
+     * - opravy.html, corrections across the site, newest first
+     */
+    pub async fn all_corrections(&self) -> Result<Vec<Correction>, SurrealArticleError> {
+        let mut response = self.surreal.db.query("SELECT * FROM correction ORDER BY created DESC").await?;
+        let corrections: Vec<Correction> = response.take(0)?;
+        Ok(corrections)
+    }
+
     /**
      * used for
      * - articles on the account page
@@ -73,7 +245,7 @@ impl DatabaseArticle {
             .db
             .query(
                 "SELECT * FROM article \
-                    WHERE $username = $username \
+                    WHERE username = $username \
                     ORDER BY created DESC \
                     LIMIT $limit",
             )
@@ -84,6 +256,50 @@ impl DatabaseArticle {
         Ok(account_articles)
     }
 
+    /**
+     * used for
+     * - one page of the public author page, newest first
+     */
+    pub async fn articles_by_username_from(
+        &self,
+        username: &str,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<ShortArticleData>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM article \
+                    WHERE username = $username AND publish_state = $published \
+                    ORDER BY created DESC \
+                    LIMIT $limit START $start",
+            )
+            .bind(("username", username.to_string()))
+            .bind(("published", PublishState::Published))
+            .bind(("limit", limit))
+            .bind(("start", start))
+            .await?;
+        let author_articles: Vec<ShortArticleData> = response.take(0)?;
+        Ok(author_articles)
+    }
+
+    pub async fn count_articles_by_username(&self, username: &str) -> Result<u32, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT count() FROM article \
+                    WHERE username = $username AND publish_state = $published \
+                    GROUP ALL",
+            )
+            .bind(("username", username.to_string()))
+            .bind(("published", PublishState::Published))
+            .await?;
+        let count: Option<i64> = response.take("count")?;
+        Ok(u32::try_from(count.unwrap_or(0)).unwrap_or(u32::MAX))
+    }
+
     /**
      * used for
      * - rendering Article template
@@ -95,18 +311,226 @@ impl DatabaseArticle {
     ) -> Result<Article, SurrealArticleError> {
         debug!("article_by_file_name: article_file_name={article_file_name}");
 
-        let article_o: Option<Article> =
-            self.surreal.db.select((ARTICLE, article_file_name.to_string())).await?;
+        let article_o = self.article_by_file_name_optional(article_file_name).await?;
         article_o.map_or_else(|| Err(ArticleNotFound(article_file_name.into())), Ok)
     }
 
+    /**
+     * used for
+     * - permanent redirect from an old slug to the current one
+     */
+    pub async fn article_by_previous_file_name(
+        &self,
+        article_file_name: &str,
+    ) -> Result<Option<Article>, SurrealArticleError> {
+        debug!("article_by_previous_file_name: article_file_name={article_file_name}");
+
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT * FROM article WHERE $article_file_name IN previous_file_names LIMIT 1")
+            .bind(("article_file_name", article_file_name.to_string()))
+            .await?;
+        let articles: Vec<Article> = response.take(0)?;
+        Ok(articles.into_iter().next())
+    }
+
+    /**
+     * used for
+     * - file name of a new or renamed Article, before any media are written
+     * - a file name used now or in the past by another Article gets a numeric suffix
+     * - so does a file name of a category page
+     * - the Article itself may keep its current or any of its previous file names
+     */
+    pub async fn unique_base_file_name(
+        &self,
+        base_file_name: &str,
+        article: Option<Uuid>,
+    ) -> Result<String, SurrealArticleError> {
+        let mut candidate = base_file_name.to_string();
+        let mut n = 2;
+        while self.file_name_taken(&format!("{}.html", candidate), article).await? {
+            candidate = slug::with_suffix(base_file_name, n);
+            n += 1;
+        }
+        Ok(candidate)
+    }
+
+    async fn file_name_taken(
+        &self,
+        article_file_name: &str,
+        article: Option<Uuid>,
+    ) -> Result<bool, SurrealArticleError> {
+        if article_file_name.strip_suffix(".html").is_some_and(slug::is_reserved) {
+            return Ok(true);
+        }
+
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT VALUE uuid FROM article WHERE article_file_name = $article_file_name OR $article_file_name IN previous_file_names")
+            .bind(("article_file_name", article_file_name.to_string()))
+            .await?;
+        let ids: Vec<Uuid> = response.take(0)?;
+        if ids.iter().any(|id| Some(*id) != article) {
+            return Ok(true);
+        }
+
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT VALUE slug FROM category WHERE slug + '.html' = $article_file_name")
+            .bind(("article_file_name", article_file_name.to_string()))
+            .await?;
+        let categories: Vec<String> = response.take(0)?;
+        Ok(!categories.is_empty())
+    }
+
+    /**
+     * used for
+     * - a category can be deleted only without any Articles, drafts included
+     */
+    pub async fn category_in_use(&self, category: &str) -> Result<bool, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT VALUE uuid FROM article WHERE category = $category LIMIT 1")
+            .bind(("category", category.to_string()))
+            .await?;
+        let ids: Vec<Uuid> = response.take(0)?;
+        Ok(!ids.is_empty())
+    }
+
+    /**
+     * used for
+     * - related articles typed in the form as file names
+     * - unknown file names are skipped
+     */
+    pub async fn article_ids_by_file_names(
+        &self,
+        article_file_names: &[String],
+    ) -> Result<Vec<Uuid>, SurrealArticleError> {
+        debug!("article_ids_by_file_names: article_file_names={:?}", article_file_names);
+
+        if article_file_names.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT VALUE uuid FROM article WHERE article_file_name IN $article_file_names")
+            .bind(("article_file_names", article_file_names.to_vec()))
+            .await?;
+        let ids: Vec<Uuid> = response.take(0)?;
+        if ids.len() != article_file_names.len() {
+            warn!("some related articles not found: {:?}", article_file_names);
+        }
+        Ok(ids)
+    }
+
+    /**
+     * used for
+     * - validation of related articles typed in the form
+     */
+    pub async fn existing_article_file_names(
+        &self,
+        article_file_names: &[String],
+    ) -> Result<Vec<String>, SurrealArticleError> {
+        debug!("existing_article_file_names: article_file_names={:?}", article_file_names);
+
+        if article_file_names.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT VALUE article_file_name FROM article WHERE article_file_name IN $article_file_names")
+            .bind(("article_file_names", article_file_names.to_vec()))
+            .await?;
+        let existing: Vec<String> = response.take(0)?;
+        Ok(existing)
+    }
+
+    /**
+     * used for
+     * - [[slug]] links in Article text, the link shows the title
+     */
+    pub async fn linked_articles(
+        &self,
+        article_file_names: &[String],
+    ) -> Result<Vec<LinkedArticleData>, SurrealArticleError> {
+        debug!("linked_articles: article_file_names={:?}", article_file_names);
+
+        if article_file_names.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT article_file_name, title FROM article WHERE article_file_name IN $article_file_names")
+            .bind(("article_file_names", article_file_names.to_vec()))
+            .await?;
+        let linked: Vec<LinkedArticleData> = response.take(0)?;
+        Ok(linked)
+    }
+
+    /**
+     * used for
+     * - related article suggestions, newest published Articles are compared to the draft
+     */
+    pub async fn similar_article_candidates(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<SimilarArticleData>, SurrealArticleError> {
+        debug!("similar_article_candidates: limit={limit}");
+
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT article_file_name, title, text_raw, created FROM article \
+                    WHERE publish_state = $published \
+                    ORDER BY created DESC \
+                    LIMIT $limit",
+            )
+            .bind(("published", PublishState::Published))
+            .bind(("limit", limit))
+            .await?;
+        let articles: Vec<SimilarArticleData> = response.take(0)?;
+        Ok(articles)
+    }
+
+    /**
+     * used for
+     * - invalidation of related articles, the HTML files are named by the file name
+     * - related articles in the edit form
+     */
+    pub async fn article_file_names_by_ids(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<Vec<String>, SurrealArticleError> {
+        debug!("article_file_names_by_ids: ids={:?}", ids);
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT VALUE article_file_name FROM article WHERE uuid IN $ids")
+            .bind(("ids", ids.to_vec()))
+            .await?;
+        let article_file_names: Vec<String> = response.take(0)?;
+        Ok(article_file_names)
+    }
+
     /**
      * used for
      * - related articles on the Article page
      */
     pub async fn related_articles(
         &self,
-        related: Vec<String>,
+        related: Vec<Uuid>,
     ) -> Result<Vec<ShortArticleData>, SurrealArticleError> {
         debug!("related_articles: related={:?}", related);
 
@@ -117,34 +541,36 @@ impl DatabaseArticle {
             .surreal
             .db
             .query(
-                "SELECT article_file_name, category, title, short_text, image_288_path, image_desc, created \
+                "SELECT article_file_name, category, title, short_text, image_288_path, \
+                    image_desc, image_alt, image_credit, image_source, created \
                     FROM article \
-                    WHERE article_file_name IN $related \
+                    WHERE uuid IN $related AND publish_state = $published \
                     ORDER BY created DESC",
             )
             .bind(("related", related.to_vec()))
+            .bind(("published", PublishState::Published))
             .await?;
         let short_article_data: Vec<ShortArticleData> = response.take(0)?;
         Ok(short_article_data)
     }
 
     /*
-     * Increase related article
+     * Increase related article, an article already related is kept once
      */
     pub async fn add_related_article(
         &self,
-        article_file_name: String,
-        new_related: String,
+        article: Uuid,
+        new_related: Uuid,
     ) -> Result<(), SurrealArticleError> {
-        debug!(
-            "add_related_article: article_file_name={}, new_related={}",
-            article_file_name, new_related
-        );
+        debug!("add_related_article: article={}, new_related={}", article, new_related);
 
         self.surreal
             .db
-            .query("UPDATE type::record('article', $article_file_name) SET related_articles += $new_related")
-            .bind(("article_file_name", article_file_name))
+            .query(
+                "UPDATE article SET related_articles += $new_related \
+                WHERE uuid = $article AND $new_related NOTINSIDE related_articles",
+            )
+            .bind(("article", article))
             .bind(("new_related", new_related))
             .await?;
         Ok(())
@@ -154,51 +580,220 @@ impl DatabaseArticle {
         &self,
         category: &str,
         limit: u32,
+    ) -> Result<Vec<ShortArticleData>, SurrealArticleError> {
+        self.articles_by_category_from(category, 0, limit).await
+    }
+
+    /**
+     * one page of a category, newest first
+     * - start is the number of newer articles skipped
+     */
+    pub async fn articles_by_category_from(
+        &self,
+        category: &str,
+        start: u32,
+        limit: u32,
     ) -> Result<Vec<ShortArticleData>, SurrealArticleError> {
         let mut response = self
             .surreal
             .db
             .query(
-                "SELECT * FROM article WHERE category = $category ORDER BY created DESC LIMIT $limit",
+                "SELECT * FROM article \
+                    WHERE category = $category AND publish_state = $published \
+                    ORDER BY created DESC \
+                    LIMIT $limit START $start",
             )
             .bind(("category", category.to_string()))
+            .bind(("published", PublishState::Published))
             .bind(("limit", limit))
+            .bind(("start", start))
             .await?;
         let category_articles: Vec<ShortArticleData> = response.take(0)?;
         Ok(category_articles)
     }
 
-    pub async fn article_top_three(
-        &self,
-    ) -> Result<(MainArticleData, TopArticleData, TopArticleData), SurrealArticleError> {
-        let mut query_response_set = self
+    pub async fn count_articles_in_category(&self, category: &str) -> Result<u32, SurrealArticleError> {
+        let mut response = self
             .surreal
             .db
-            .query("SELECT * FROM article WHERE is_main = true ORDER BY created LIMIT 3")
+            .query(
+                "SELECT count() FROM article \
+                    WHERE category = $category AND publish_state = $published \
+                    GROUP ALL",
+            )
+            .bind(("category", category.to_string()))
+            .bind(("published", PublishState::Published))
             .await?;
-        let mut top_articles: Vec<MainArticleData> = query_response_set.take(0)?;
-        let main: MainArticleData = top_articles.pop().unwrap_or_else(MainArticleData::empty);
-        let second_m: MainArticleData = top_articles.pop().unwrap_or_else(MainArticleData::empty);
-        let third_m: MainArticleData = top_articles.pop().unwrap_or_else(MainArticleData::empty);
-
-        let tad2 = TopArticleData::from(second_m);
-        let tad3 = TopArticleData::from(third_m);
-        Ok((main, tad2, tad3))
+        let count: Option<i64> = response.take("count")?;
+        Ok(u32::try_from(count.unwrap_or(0)).unwrap_or(u32::MAX))
     }
 
     /**
-     * returns Articles with most views
+     * how many published articles of the same category are newer
+     * - decides on which category page the article is listed
      */
-    // TODO keep these in memory, refresh every hour or so
-    pub async fn most_read_all_by_views(&self) -> Result<Vec<MiniArticleData>, SurrealSystemError> {
-        #[rustfmt::skip]
-        let mut result_response_set = self.surreal.db
-            .query("SELECT (type::record('article', article_file_name)).* AS article, views FROM article_views ORDER BY views DESC LIMIT 3")
+    pub async fn article_position_in_category(&self, article: &Article) -> Result<u32, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT count() FROM article \
+                    WHERE category = $category AND publish_state = $published AND created > $created \
+                    GROUP ALL",
+            )
+            .bind(("category", article.category.clone()))
+            .bind(("published", PublishState::Published))
+            .bind(("created", article.created))
             .await?;
+        let count: Option<i64> = response.take("count")?;
+        Ok(u32::try_from(count.unwrap_or(0)).unwrap_or(u32::MAX))
+    }
 
-        let most_read_articles: Vec<MiniArticleData> = result_response_set.take("article")?;
+    /**
+     * publish moments of all published articles in the range
+     * - used for the archive calendar
+     */
+    pub async fn published_created_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT created FROM article \
+                    WHERE publish_state = $published AND created >= $from AND created < $to",
+            )
+            .bind(("published", PublishState::Published))
+            .bind(("from", from))
+            .bind(("to", to))
+            .await?;
+        let created: Vec<DateTime<Utc>> = response.take("created")?;
+        Ok(created)
+    }
+
+    /**
+     * published articles in the range, oldest first
+     * - used for the archive of a day
+     */
+    pub async fn articles_created_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ShortArticleData>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM article \
+                    WHERE publish_state = $published AND created >= $from AND created < $to \
+                    ORDER BY created ASC",
+            )
+            .bind(("published", PublishState::Published))
+            .bind(("from", from))
+            .bind(("to", to))
+            .await?;
+        let articles: Vec<ShortArticleData> = response.take(0)?;
+        Ok(articles)
+    }
 
-        Ok(most_read_articles)
+    /**
+     * images of the articles published in the range, oldest first
+     * - used for the image sources report
+     */
+    pub async fn image_credits_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ImageCreditData>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT article_file_name, image_credit, image_source, images, created FROM article \
+                    WHERE publish_state = $published AND created >= $from AND created < $to \
+                    ORDER BY created ASC",
+            )
+            .bind(("published", PublishState::Published))
+            .bind(("from", from))
+            .bind(("to", to))
+            .await?;
+        let articles: Vec<ImageCreditData> = response.take(0)?;
+        Ok(articles)
+    }
+
+    /**
+     * used for
+     * - top of index.html, newest main Articles first
+     */
+    pub async fn main_articles(&self, limit: u32) -> Result<Vec<MainArticleData>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM article \
+                    WHERE is_main = true AND publish_state = $published \
+                    ORDER BY created DESC \
+                    LIMIT $limit",
+            )
+            .bind(("published", PublishState::Published))
+            .bind(("limit", limit))
+            .await?;
+        let articles: Vec<MainArticleData> = response.take(0)?;
+        Ok(articles)
+    }
+
+    /**
+     * used for
+     * - Articles pinned to the top of index.html, None if not published
+     */
+    pub async fn main_article_by_id(
+        &self,
+        article: Uuid,
+    ) -> Result<Option<MainArticleData>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT * FROM article WHERE uuid = $article AND publish_state = $published LIMIT 1")
+            .bind(("article", article))
+            .bind(("published", PublishState::Published))
+            .await?;
+        let articles: Vec<MainArticleData> = response.take(0)?;
+        Ok(articles.into_iter().next())
+    }
+
+    /**
+     * used for
+     * - Articles pinned to a section of index.html, None if not published
+     */
+    pub async fn short_article_by_id(
+        &self,
+        article: Uuid,
+    ) -> Result<Option<ShortArticleData>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT * FROM article WHERE uuid = $article AND publish_state = $published LIMIT 1")
+            .bind(("article", article))
+            .bind(("published", PublishState::Published))
+            .await?;
+        let articles: Vec<ShortArticleData> = response.take(0)?;
+        Ok(articles.into_iter().next())
+    }
+
+    /**
+     * returns Articles with most views
+     */
+    // TODO keep these in memory, refresh every hour or so
+    pub async fn most_read_all_by_views(&self) -> Result<Vec<MiniArticleData>, SurrealSystemError> {
+        #[rustfmt::skip]
+        let mut result_response_set = self.surreal.db
+            .query("SELECT article_file_name, views FROM article_views ORDER BY views DESC LIMIT 3")
+            .await?;
+
+        let article_file_names: Vec<String> = result_response_set.take("article_file_name")?;
+        self.mini_articles_in_order(article_file_names).await
     }
 
     pub async fn most_read_in_category_by_views(
@@ -207,13 +802,35 @@ impl DatabaseArticle {
     ) -> Result<Vec<MiniArticleData>, SurrealSystemError> {
         #[rustfmt::skip]
         let mut result_response_set = self.surreal.db
-            .query("SELECT (type::record('article', article_file_name)).* AS article, views FROM article_views WHERE category = $category ORDER BY views DESC LIMIT 3")
+            .query("SELECT article_file_name, views FROM article_views WHERE category = $category ORDER BY views DESC LIMIT 3")
             .bind(("category", category.to_string()))
             .await?;
 
-        let most_read_articles: Vec<MiniArticleData> = result_response_set.take("article")?;
+        let article_file_names: Vec<String> = result_response_set.take("article_file_name")?;
+        self.mini_articles_in_order(article_file_names).await
+    }
+
+    /*
+     * views are keyed by the file name, articles by uuid
+     * - keep the order of the given file names
+     */
+    async fn mini_articles_in_order(
+        &self,
+        article_file_names: Vec<String>,
+    ) -> Result<Vec<MiniArticleData>, SurrealSystemError> {
+        if article_file_names.is_empty() {
+            return Ok(Vec::new());
+        }
+        #[rustfmt::skip]
+        let mut result_response_set = self.surreal.db
+            .query("SELECT * FROM article WHERE article_file_name IN $article_file_names AND publish_state = $published")
+            .bind(("article_file_names", article_file_names.clone()))
+            .bind(("published", PublishState::Published))
+            .await?;
 
-        Ok(most_read_articles)
+        let mut articles: Vec<MiniArticleData> = result_response_set.take(0)?;
+        articles.sort_by_key(|a| article_file_names.iter().position(|name| *name == a.article_file_name));
+        Ok(articles)
     }
 
     /*
@@ -242,6 +859,33 @@ impl DatabaseArticle {
         )
     }
 
+    /*
+     * the article file name changed, keep the view count
+     */
+    pub async fn rename_article_views(
+        &self,
+        old_file_name: &str,
+        new_file_name: &str,
+    ) -> Result<(), SurrealSystemError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("DELETE type::record(\"article_views\", $old_file_name) RETURN BEFORE")
+            .bind(("old_file_name", old_file_name.to_string()))
+            .await?;
+        let views: Option<u64> = response.take("views")?;
+
+        self.surreal
+            .db
+            .query(
+                "UPSERT type::record(\"article_views\", $new_file_name) SET views += $views, article_file_name = $new_file_name"
+            )
+            .bind(("new_file_name", new_file_name.to_string()))
+            .bind(("views", views.unwrap_or_default()))
+            .await?;
+        Ok(())
+    }
+
     /*
      * used for
      * - search query in the topbar
@@ -269,7 +913,7 @@ impl DatabaseArticle {
          */
         let query = format!(
             "SELECT * FROM article
-         WHERE {}
+         WHERE ({}) AND publish_state = $published
          ORDER BY created DESC
          LIMIT $limit",
             conditions.join(" OR ")
@@ -280,6 +924,7 @@ impl DatabaseArticle {
             q = q.bind((format!("w{i}"), pattern));
         }
         q = q.bind(("limit", limit));
+        q = q.bind(("published", PublishState::Published));
 
         /*
          * execute search query
@@ -290,14 +935,130 @@ impl DatabaseArticle {
     }
 
     // TODO add limit
-    pub async fn list_all_articles(&self) -> Result<Vec<ShortArticleData>, SurrealArticleError> {
-        let articles: Vec<ShortArticleData> =
-            self.surreal.db.query("SELECT * FROM article ORDER BY created DESC").await?.take(0)?;
+    pub async fn list_all_articles(
+        &self,
+        sort: ArticleListSort,
+        descending: bool,
+    ) -> Result<Vec<ArticleListData>, SurrealArticleError> {
+        // the column comes from the enum, never from the request
+        let query = format!(
+            "SELECT article_file_name, title, category, created, updated, word_count, reading_minutes FROM article \
+                WHERE publish_state = $published ORDER BY {} {}",
+            sort.field(),
+            if descending { "DESC" } else { "ASC" }
+        );
+        let articles: Vec<ArticleListData> = self
+            .surreal
+            .db
+            .query(query)
+            .bind(("published", PublishState::Published))
+            .await?
+            .take(0)?;
+        Ok(articles)
+    }
+
+    /**
+     * used for
+     * - publish worker, scheduled Articles which should be published by now
+     */
+    pub async fn articles_to_publish(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Article>, SurrealArticleError> {
+        debug!("articles_to_publish: now={now}");
+
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM article \
+                    WHERE publish_state = $scheduled AND publish_at <= $now \
+                    ORDER BY publish_at",
+            )
+            .bind(("scheduled", PublishState::Scheduled))
+            .bind(("now", now))
+            .await?;
+        let articles: Vec<Article> = response.take(0)?;
         Ok(articles)
     }
 
-    pub async fn delete_article(&self, article_file_name: &str) -> Result<(), SurrealArticleError> {
-        let _: Option<Article> = self.surreal.db.delete(("article", article_file_name)).await?;
+    /**
+     * used for
+     * - expire worker, published Articles which should be taken down by now
+     */
+    pub async fn articles_to_expire(&self, now: DateTime<Utc>) -> Result<Vec<Article>, SurrealArticleError> {
+        debug!("articles_to_expire: now={now}");
+
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM article \
+                    WHERE publish_state = $published AND expires_at != NONE AND expires_at <= $now \
+                    ORDER BY expires_at",
+            )
+            .bind(("published", PublishState::Published))
+            .bind(("now", now))
+            .await?;
+        let articles: Vec<Article> = response.take(0)?;
+        Ok(articles)
+    }
+
+    /**
+     * used for
+     * - review queue, oldest submission first
+     */
+    pub async fn articles_in_review(&self) -> Result<Vec<ReviewArticleData>, SurrealArticleError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT * FROM article WHERE publish_state = $in_review ORDER BY created ASC")
+            .bind(("in_review", PublishState::InReview))
+            .await?;
+        let articles: Vec<ReviewArticleData> = response.take(0)?;
+        Ok(articles)
+    }
+
+    /**
+     * the Article goes live
+     * - created is moved to the publish moment, listings are ordered by it
+     * - updated too, a scheduled Article wasn't changed after it went live
+     */
+    pub async fn publish_article(
+        &self,
+        article: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<(), SurrealArticleError> {
+        debug!("publish_article: article={article}");
+
+        self.surreal
+            .db
+            .query("UPDATE article SET publish_state = $published, created = $now, updated = $now WHERE uuid = $article")
+            .bind(("article", article))
+            .bind(("published", PublishState::Published))
+            .bind(("now", now))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete_article(&self, article: Uuid) -> Result<(), SurrealArticleError> {
+        let _: Option<Article> = self.surreal.db.delete((ARTICLE, article.to_string())).await?;
+        // revisions have nothing to restore into anymore
+        self.surreal
+            .db
+            .query("DELETE article_revision WHERE article_uuid = $article")
+            .bind(("article", article))
+            .await?;
+        self.surreal
+            .db
+            .query("DELETE live_blog_entry WHERE article_uuid = $article")
+            .bind(("article", article))
+            .await?;
+        self.surreal
+            .db
+            .query("DELETE correction WHERE article_uuid = $article")
+            .bind(("article", article))
+            .await?;
         Ok(())
     }
 
@@ -305,16 +1066,27 @@ impl DatabaseArticle {
         &self,
         article_file_name: &str,
     ) -> Result<Option<Article>, SurrealArticleError> {
-        Ok(self.surreal.db.select(("article", article_file_name)).await?)
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT * FROM article WHERE article_file_name = $article_file_name LIMIT 1")
+            .bind(("article_file_name", article_file_name.to_string()))
+            .await?;
+        let articles: Vec<Article> = response.take(0)?;
+        Ok(articles.into_iter().next())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::db::database_article::DatabaseArticle;
-    use crate::db::database_article_data::easy_article;
+    use crate::db::database_article_data::{
+        easy_article, Article, ArticleListData, ArticleListSort, Correction, LiveBlogEntry, PublishState,
+    };
     use crate::trust::app::article::create_article_easy_builder::ArticleBuilder;
     use crate::trust::me::TrustError;
+    use chrono::{Duration, TimeZone, Utc};
+    use surrealdb::types::Uuid;
 
     #[tokio::test]
     async fn test_create_article() -> Result<(), TrustError> {
@@ -325,11 +1097,152 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_update_article() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        db.create_article(easy_article("Test Title U", "user_u", "text")).await?;
+
+        let mut article = db.article_by_file_name("test-title-u.html").await?;
+        article.title = "Test Title Updated".into();
+        article.text = "updated text".into();
+        db.update_article(article).await?;
+
+        let updated = db.article_by_file_name("test-title-u.html").await?;
+        assert_eq!(updated.title, "Test Title Updated");
+        assert_eq!(updated.text, "updated text");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_article_revisions() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        db.create_article(easy_article("Test Title R", "user_r", "text 1")).await?;
+
+        let article = db.article_by_file_name("test-title-r.html").await?;
+        db.create_revision(article.clone(), "user_r").await?;
+        db.create_revision(Article { text: "text 2".into(), ..article.clone() }, "admin").await?;
+
+        let revisions = db.revisions_by_article(article.uuid).await?;
+        assert_eq!(revisions.len(), 2);
+        // newest first
+        assert_eq!(revisions[0].editor, "admin");
+        assert_eq!(revisions[0].article.text, "text 2");
+        assert_eq!(revisions[1].article.text, "text 1");
+
+        let revision = db.revision_by_id(&revisions[1].revision_id).await?;
+        assert_eq!(revision.editor, "user_r");
+        assert!(db.revision_by_id("unknown").await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_live_blog_entries() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let article = easy_article("Live", "user_l", "intro");
+        let start = Utc.with_ymd_and_hms(2025, 11, 17, 10, 0, 0).unwrap();
+        for minutes in 0..3 {
+            db.create_live_blog_entry(LiveBlogEntry {
+                uuid: Uuid::new(),
+                article_uuid: article.uuid,
+                created: start + Duration::minutes(minutes),
+                author: "Live Author".into(),
+                username: "user_l".into(),
+                text: format!("entry {}", minutes),
+                image_path: String::new(),
+                image_desc: String::new(),
+            })
+            .await?;
+        }
+
+        let entries = db.live_blog_entries(article.uuid).await?;
+        assert_eq!(entries.iter().map(|e| e.text.as_str()).collect::<Vec<_>>(), ["entry 2", "entry 1", "entry 0"]);
+
+        let newer = db.live_blog_entries_since(article.uuid, start).await?;
+        assert_eq!(newer.len(), 2);
+        assert_eq!(newer[0].text, "entry 2");
+
+        db.create_article(article.clone()).await?;
+        db.delete_article(article.uuid).await?;
+        assert!(db.live_blog_entries(article.uuid).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_corrections() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let article = easy_article("Corrected", "user_c", "text");
+        let other = easy_article("Other", "user_c", "text");
+        let start = Utc.with_ymd_and_hms(2025, 11, 17, 10, 0, 0).unwrap();
+        for (days, uuid) in [(0, article.uuid), (1, other.uuid), (2, article.uuid)] {
+            db.create_correction(Correction {
+                uuid: Uuid::new(),
+                article_uuid: uuid,
+                created: start + Duration::days(days),
+                username: "editor".into(),
+                text: format!("correction {}", days),
+            })
+            .await?;
+        }
+
+        let corrections = db.corrections_by_article(article.uuid).await?;
+        assert_eq!(corrections.iter().map(|c| c.text.as_str()).collect::<Vec<_>>(), ["correction 0", "correction 2"]);
+
+        let all = db.all_corrections().await?;
+        assert_eq!(all.iter().map(|c| c.text.as_str()).collect::<Vec<_>>(), ["correction 2", "correction 1", "correction 0"]);
+
+        db.create_article(article.clone()).await?;
+        db.delete_article(article.uuid).await?;
+        assert!(db.corrections_by_article(article.uuid).await?.is_empty());
+        assert_eq!(db.all_corrections().await?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_existing_article_file_names() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        db.create_article(easy_article("Existing One", "user_e", "text")).await?;
+
+        let names = vec!["existing-one.html".to_string(), "missing.html".to_string()];
+        assert_eq!(db.existing_article_file_names(&names).await?, ["existing-one.html"]);
+        assert!(db.existing_article_file_names(&[]).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_linked_articles() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        db.create_article(easy_article("Linked One", "user_l", "text")).await?;
+
+        let names = vec!["linked-one.html".to_string(), "missing.html".to_string()];
+        let linked = db.linked_articles(&names).await?;
+        assert_eq!(linked.len(), 1);
+        assert_eq!(linked[0].article_file_name, "linked-one.html");
+        assert_eq!(linked[0].title, "Linked One");
+        assert!(db.linked_articles(&[]).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_similar_article_candidates() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        db.create_article(easy_article("Candidate", "user_s", "Vláda schválila rozpočet")).await?;
+        let mut draft = easy_article("Draft", "user_s", "Rozpočet");
+        draft.publish_state = PublishState::Draft;
+        db.create_article(draft).await?;
+
+        let candidates = db.similar_article_candidates(10).await?;
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].article_file_name, "candidate.html");
+        assert_eq!(candidates[0].text_raw, "Vláda schválila rozpočet");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_articles_by_username() -> Result<(), TrustError> {
         let db = DatabaseArticle::new_from_scratch().await?;
         // prepare user article
         db.create_article(easy_article("Test Title 1", "user_xx", "text")).await?;
+        db.create_article(easy_article("Test Title 2", "user_yy", "text")).await?;
 
         let articles = db.articles_by_username("user_xx", 100).await?;
         assert_eq!(articles.len(), 1);
@@ -338,6 +1251,35 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_articles_by_username_from() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        for i in 1..=3 {
+            #[rustfmt::skip]
+            db.create_article(
+                ArticleBuilder::article()
+                    .title(&format!("Author Article {}", i))
+                    .author("user_xx")
+                    .created(Utc::now() + Duration::minutes(i))
+                    .build(),
+            ).await?;
+        }
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Author Draft")
+                .author("user_xx")
+                .draft()
+                .build(),
+        ).await?;
+
+        assert_eq!(db.count_articles_by_username("user_xx").await?, 3);
+        let page = db.articles_by_username_from("user_xx", 1, 5).await?;
+        let titles: Vec<&str> = page.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["Author Article 2", "Author Article 1"]);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_article_by_file_name() -> Result<(), TrustError> {
         let db = DatabaseArticle::new_from_scratch().await?;
@@ -379,8 +1321,11 @@ mod tests {
         db.create_article(easy_article("Related 2", "userC", "text")).await?;
 
         // execute
-        let related_articles =
-            db.related_articles(vec!["related-1.html".into(), "related-2.html".into()]).await?;
+        let ids = db
+            .article_ids_by_file_names(&["related-1.html".into(), "related-2.html".into(), "unknown.html".into()])
+            .await?;
+        assert_eq!(ids.len(), 2);
+        let related_articles = db.related_articles(ids).await?;
 
         assert_eq!(related_articles.len(), 2);
         // descending order by created
@@ -389,6 +1334,61 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_unique_base_file_name() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        assert_eq!(db.unique_base_file_name("same-title", None).await?, "same-title");
+
+        let article = easy_article("Same Title", "userE", "text");
+        let uuid = article.uuid;
+        db.create_article(article).await?;
+        assert_eq!(db.unique_base_file_name("same-title", None).await?, "same-title-2");
+        // the article itself keeps its name
+        assert_eq!(db.unique_base_file_name("same-title", Some(uuid)).await?, "same-title");
+
+        db.create_article(Article {
+            article_file_name: "same-title-2.html".into(),
+            ..easy_article("Same Title", "userE", "text")
+        })
+        .await?;
+        assert_eq!(db.unique_base_file_name("same-title", None).await?, "same-title-3");
+
+        // old names of renamed articles are still redirected
+        db.create_article(Article {
+            previous_file_names: vec!["old-title.html".into()],
+            ..easy_article("New Title", "userE", "text")
+        })
+        .await?;
+        assert_eq!(db.unique_base_file_name("old-title", None).await?, "old-title-2");
+
+        // category pages
+        assert_eq!(db.unique_base_file_name("veda", None).await?, "veda-2");
+
+        // pages of the web router
+        assert_eq!(db.unique_base_file_name("index", None).await?, "index-2");
+        assert_eq!(db.unique_base_file_name("news", None).await?, "news-2");
+        assert_eq!(db.unique_base_file_name("opravy", None).await?, "opravy-2");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_article_by_previous_file_name() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let article = easy_article("New Title", "userD", "text");
+        let uuid = article.uuid;
+        db.create_article(Article {
+            previous_file_names: vec!["old-title.html".into()],
+            ..article
+        })
+        .await?;
+
+        let renamed = db.article_by_previous_file_name("old-title.html").await?;
+        assert_eq!(renamed.map(|a| a.uuid), Some(uuid));
+        assert!(db.article_by_previous_file_name("new-title.html").await?.is_none());
+        assert_eq!(db.article_file_names_by_ids(&[uuid]).await?, vec!["new-title.html".to_string()]);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_articles_by_category() -> Result<(), TrustError> {
         let db = DatabaseArticle::new_from_scratch().await?;
@@ -408,6 +1408,296 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_articles_by_category_from() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+
+        for i in 1..=5 {
+            #[rustfmt::skip]
+            db.create_article(
+                ArticleBuilder::article()
+                    .title(&format!("Article {}", i))
+                    .category("republika")
+                    .created(Utc::now() + Duration::minutes(i))
+                    .build(),
+            ).await?;
+        }
+
+        assert_eq!(db.count_articles_in_category("republika").await?, 5);
+        assert_eq!(db.count_articles_in_category("veda").await?, 0);
+
+        let page = db.articles_by_category_from("republika", 2, 2).await?;
+        let titles: Vec<&str> = page.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["Article 3", "Article 2"]);
+        assert_eq!(db.articles_by_category_from("republika", 4, 2).await?.len(), 1);
+
+        let oldest = db.article_by_file_name("article-1.html").await?;
+        assert_eq!(db.article_position_in_category(&oldest).await?, 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_articles_created_between() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let day = Utc.with_ymd_and_hms(2025, 11, 17, 10, 0, 0).unwrap();
+
+        for (title, created) in [
+            ("Article Before", day - Duration::days(1)),
+            ("Article Later", day + Duration::hours(2)),
+            ("Article First", day),
+        ] {
+            #[rustfmt::skip]
+            db.create_article(
+                ArticleBuilder::article()
+                    .title(title)
+                    .created(created)
+                    .build(),
+            ).await?;
+        }
+
+        let from = day - Duration::hours(1);
+        let to = day + Duration::hours(3);
+        let articles = db.articles_created_between(from, to).await?;
+        let titles: Vec<&str> = articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["Article First", "Article Later"]);
+        assert_eq!(db.published_created_between(from, to).await?.len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_image_credits_between() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let day = Utc.with_ymd_and_hms(2025, 11, 17, 10, 0, 0).unwrap();
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Agency")
+                .image_credit("Jan Novák", "ČTK")
+                .created(day)
+                .build(),
+        ).await?;
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Draft Agency")
+                .image_credit("Jan Novák", "ČTK")
+                .created(day)
+                .draft()
+                .build(),
+        ).await?;
+
+        let credits = db.image_credits_between(day - Duration::hours(1), day + Duration::hours(1)).await?;
+        assert_eq!(credits.len(), 1);
+        assert_eq!(credits[0].article_file_name, "article-agency.html");
+        assert_eq!(credits[0].image_credit, "Jan Novák");
+        assert_eq!(credits[0].image_source, "ČTK");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_articles_by_category_not_published() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Draft")
+                .category("republika")
+                .draft()
+                .build(),
+        ).await?;
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Scheduled")
+                .category("republika")
+                .scheduled(Utc::now() + Duration::hours(1))
+                .build(),
+        ).await?;
+
+        assert!(db.articles_by_category("republika", 100).await?.is_empty());
+        assert!(db.list_all_articles(ArticleListSort::Created, true).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_all_articles_sorted() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let now = Utc::now();
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Bravo")
+                .text("one two three")
+                .created(now - Duration::days(2))
+                .build(),
+        ).await?;
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Alpha")
+                .text("one")
+                .created(now - Duration::days(3))
+                .updated(now)
+                .build(),
+        ).await?;
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Charlie")
+                .text("one two")
+                .created(now - Duration::days(1))
+                .build(),
+        ).await?;
+
+        let titles = |articles: Vec<ArticleListData>| {
+            articles.into_iter().map(|a| a.title).collect::<Vec<_>>()
+        };
+        assert_eq!(titles(db.list_all_articles(ArticleListSort::Created, true).await?), ["Charlie", "Bravo", "Alpha"]);
+        assert_eq!(titles(db.list_all_articles(ArticleListSort::Updated, true).await?), ["Alpha", "Charlie", "Bravo"]);
+        assert_eq!(titles(db.list_all_articles(ArticleListSort::WordCount, true).await?), ["Bravo", "Charlie", "Alpha"]);
+        assert_eq!(titles(db.list_all_articles(ArticleListSort::Title, false).await?), ["Alpha", "Bravo", "Charlie"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_articles_to_publish() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let now = Utc::now();
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Due")
+                .category("veda")
+                .scheduled(now - Duration::minutes(1))
+                .build(),
+        ).await?;
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Later")
+                .category("veda")
+                .scheduled(now + Duration::hours(1))
+                .build(),
+        ).await?;
+
+        let due = db.articles_to_publish(now).await?;
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].article_file_name, "article-due.html");
+
+        db.publish_article(due[0].uuid, now).await?;
+
+        assert!(db.articles_to_publish(now).await?.is_empty());
+        let articles = db.articles_by_category("veda", 100).await?;
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].title, "Article Due");
+        let published = db.article_by_file_name("article-due.html").await?;
+        assert_eq!(published.created, now);
+        assert_eq!(published.updated, now);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_articles_to_expire() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let now = Utc::now();
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Expired")
+                .category("veda")
+                .expires(now - Duration::minutes(1))
+                .build(),
+        ).await?;
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Expires Later")
+                .category("veda")
+                .expires(now + Duration::hours(1))
+                .build(),
+        ).await?;
+
+        #[rustfmt::skip]
+        db.create_article(
+            ArticleBuilder::article()
+                .title("Article Forever")
+                .category("veda")
+                .build(),
+        ).await?;
+
+        let due = db.articles_to_expire(now).await?;
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].article_file_name, "article-expired.html");
+
+        let mut expired = due[0].clone();
+        expired.transition(PublishState::Expired, "system", "");
+        db.update_article(expired).await?;
+
+        assert!(db.articles_to_expire(now).await?.is_empty());
+        assert_eq!(db.articles_by_category("veda", 100).await?.len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_main_articles_newest_first() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+        let now = Utc::now();
+
+        for (title, hours) in [("Main Old", 3), ("Main Newest", 1), ("Main Middle", 2)] {
+            #[rustfmt::skip]
+            db.create_article(
+                ArticleBuilder::article().title(title).main(true).created(now - Duration::hours(hours)).build(),
+            ).await?;
+        }
+        db.create_article(ArticleBuilder::article().title("Main Draft").main(true).draft().build()).await?;
+
+        let titles: Vec<String> = db.main_articles(2).await?.into_iter().map(|a| a.title).collect();
+        assert_eq!(titles, vec!["Main Newest", "Main Middle"]);
+
+        let draft = db.article_by_file_name("main-draft.html").await?;
+        assert!(db.main_article_by_id(draft.uuid).await?.is_none());
+        assert!(db.short_article_by_id(draft.uuid).await?.is_none());
+        let old = db.article_by_file_name("main-old.html").await?;
+        assert_eq!(db.main_article_by_id(old.uuid).await?.map(|a| a.title), Some("Main Old".into()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_articles_in_review() -> Result<(), TrustError> {
+        let db = DatabaseArticle::new_from_scratch().await?;
+
+        #[rustfmt::skip]
+        db.create_article(ArticleBuilder::article().title("Article Submitted").in_review().build()).await?;
+        db.create_article(ArticleBuilder::article().title("Article Draft").draft().build()).await?;
+        db.create_article(ArticleBuilder::article().title("Article Live").build()).await?;
+
+        let queue = db.articles_in_review().await?;
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0].article_file_name, "article-submitted.html");
+
+        let mut article = db.article_by_file_name(&queue[0].article_file_name).await?;
+        article.transition(PublishState::ChangesRequested, "reviewer", "Doplnit zdroje.");
+        db.update_article(article).await?;
+
+        assert!(db.articles_in_review().await?.is_empty());
+        let stored = db.article_by_file_name("article-submitted.html").await?;
+        assert_eq!(stored.publish_state, PublishState::ChangesRequested);
+        assert_eq!(stored.state_transitions.len(), 1);
+        assert_eq!(stored.state_transitions[0].from, PublishState::InReview);
+        assert_eq!(stored.state_transitions[0].comment, "Doplnit zdroje.");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_articles_by_words() -> Result<(), TrustError> {
         let db = DatabaseArticle::new_from_scratch().await?;
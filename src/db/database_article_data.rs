@@ -1,7 +1,7 @@
-use crate::application::form_create_article::create_article_parser::ArticleUpload;
-use crate::data::library::safe_article_file_name;
-use crate::data::text_processor::{process_short_text, process_text};
-use crate::data::{library, processor};
+use crate::application::form_create_article::create_article_parser::{ArticleUpload, ImageUpload};
+use crate::data::slug::slugify;
+use crate::data::text_processor::{process_short_text, process_text, word_count};
+use crate::data::library;
 use crate::db::database::SurrealError;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -19,6 +19,9 @@ pub struct MainArticleData {
     pub category: String,
     pub image_440_path: String,
     pub image_desc: String,
+    pub image_alt: String,
+    pub image_credit: String,
+    pub image_source: String,
 }
 
 impl MainArticleData {
@@ -32,8 +35,15 @@ impl MainArticleData {
             category: "".into(),
             image_440_path: "".into(),
             image_desc: "".into(),
+            image_alt: "".into(),
+            image_credit: "".into(),
+            image_source: "".into(),
         }
     }
+
+    pub fn image_credit_display(&self) -> String {
+        library::display_image_credit(&self.image_credit, &self.image_source)
+    }
 }
 
 /*
@@ -46,28 +56,157 @@ pub struct TopArticleData {
     pub short_text: String,
 }
 
+/**
+ * lifecycle of an Article
+ * - only published Articles are listed and rendered
+ * - Articles of contributors go through a review first
+ */
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SurrealValue)]
+#[serde(rename_all = "lowercase")]
+pub enum PublishState {
+    Draft,
+    InReview,
+    ChangesRequested,
+    Rejected,
+    Scheduled,
+    Published,
+    // taken down by the expire worker, the URL gives 410 Gone
+    Expired,
+}
+
+/**
+ * change of the PublishState, kept on the Article
+ * - the comment of a reviewer is shown to the author
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, SurrealValue)]
+pub struct StateTransition {
+    pub from: PublishState,
+    pub to: PublishState,
+    pub username: String,
+    pub comment: String,
+    pub changed: DateTime<Utc>,
+}
+
+impl StateTransition {
+    pub fn changed_display(&self) -> String {
+        library::display_date_time(self.changed)
+    }
+}
+
+/**
+ * additional image of an Article, placed in the text by [[image:N]] or [[gallery:N,M]]
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, SurrealValue)]
+pub struct ArticleImage {
+    // unique within the Article, the text refers to it
+    pub number: u32,
+    pub caption: String,
+    // read by screen readers, the caption may assume the picture is seen
+    pub alt: String,
+    // photographer
+    pub credit: String,
+    // agency or license, ČTK, Reuters, own work
+    pub source: String,
+    pub image_50_path: String,
+    pub image_288_path: String,
+    pub image_440_path: String,
+    pub image_820_path: String,
+}
+
+impl ArticleImage {
+    /**
+     * the same size variants as the main image, named after the Article
+     */
+    pub fn from_upload(upload: &ImageUpload, base_file_name: &str) -> Self {
+        let file_base = Self::file_base(base_file_name, upload.number);
+        Self {
+            number: upload.number,
+            caption: upload.caption.clone(),
+            alt: upload.alt.clone(),
+            credit: upload.credit.clone(),
+            source: upload.source.clone(),
+            image_50_path: format!("u/{}_image_50.{}", file_base, upload.ext),
+            image_288_path: format!("u/{}_image_288.{}", file_base, upload.ext),
+            image_440_path: format!("u/{}_image_440.{}", file_base, upload.ext),
+            image_820_path: format!("u/{}_image_820.{}", file_base, upload.ext),
+        }
+    }
+
+    pub fn file_base(base_file_name: &str, number: u32) -> String {
+        format!("{}_gallery_{}", base_file_name, number)
+    }
+
+    pub fn paths(&self) -> [&str; 4] {
+        [&self.image_50_path, &self.image_288_path, &self.image_440_path, &self.image_820_path]
+    }
+
+    pub fn credit_display(&self) -> String {
+        library::display_image_credit(&self.credit, &self.source)
+    }
+}
+
+/**
+ * source of an Article, cited in the text by the footnote [^N]
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default, SurrealValue)]
+pub struct ArticleSource {
+    // unique within the Article, the text refers to it
+    pub number: u32,
+    pub title: String,
+    pub url: String,
+    // YYYY-MM-DD, when the editor last saw the page
+    pub accessed: String,
+}
+
+impl ArticleSource {
+    pub fn accessed_display(&self) -> String {
+        library::display_iso_date(&self.accessed)
+    }
+}
+
 /**
  * Article database object
  */
 #[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
 pub struct Article {
+    // the record id
     pub uuid: Uuid,
     pub article_file_name: String,
+    // old slugs of a renamed Article, requests for them are redirected
+    pub previous_file_names: Vec<String>,
 
     pub author: String,
     pub username: String,
     pub created: DateTime<Utc>,
+    // last change of the content, the same as created until edited
+    pub updated: DateTime<Utc>,
 
     pub title: String,
     pub text: String,
     pub short_text: String,
     pub mini_text: String,
+    // computed from the raw text on every change
+    pub word_count: u32,
+    pub reading_minutes: u32,
+
+    // raw text as typed by the editor, used to prefill the edit form
+    pub text_raw: String,
+    pub short_text_raw: String,
+    pub mini_text_raw: String,
 
+    // caption of the main image
     pub image_desc: String,
+    pub image_alt: String,
+    pub image_credit: String,
+    pub image_source: String,
     pub image_50_path: String,
     pub image_288_path: String,
     pub image_440_path: String,
     pub image_820_path: String,
+    // ordered by number
+    pub images: Vec<ArticleImage>,
+    // ordered by number, listed at the end of the Article
+    pub sources: Vec<ArticleSource>,
 
     pub has_video: bool,
     pub video_path: String,
@@ -76,10 +215,80 @@ pub struct Article {
     pub audio_path: String,
 
     pub category: String,
-    pub related_articles: Vec<String>,
+    pub related_articles: Vec<Uuid>,
 
     pub is_main: bool,
     pub is_exclusive: bool,
+    // the text is an introduction, LiveBlogEntries are added after publication
+    pub is_live_blog: bool,
+
+    pub publish_state: PublishState,
+    // set only for Scheduled articles
+    pub publish_at: Option<DateTime<Utc>>,
+    // sponsored or embargoed content is taken down at this moment
+    pub expires_at: Option<DateTime<Utc>>,
+    // oldest first
+    pub state_transitions: Vec<StateTransition>,
+}
+
+/**
+ * previous version of an Article, stored on every change
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct ArticleRevision {
+    pub revision_id: String,
+    pub article_uuid: Uuid,
+    // username of who made the change
+    pub editor: String,
+    pub revised: DateTime<Utc>,
+    pub article: Article,
+}
+
+impl ArticleRevision {
+    pub fn revised_display(&self) -> String {
+        library::display_date_time(self.revised)
+    }
+}
+
+/**
+ * timestamped update of a live blog Article
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct LiveBlogEntry {
+    pub uuid: Uuid,
+    pub article_uuid: Uuid,
+    pub created: DateTime<Utc>,
+    pub author: String,
+    pub username: String,
+    pub text: String,
+    // empty if the entry has no image
+    pub image_path: String,
+    pub image_desc: String,
+}
+
+impl LiveBlogEntry {
+    pub fn created_display(&self) -> String {
+        library::display_date_time(self.created)
+    }
+}
+
+/**
+ * disclosed fix of a factual error in a published Article
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct Correction {
+    pub uuid: Uuid,
+    pub article_uuid: Uuid,
+    pub created: DateTime<Utc>,
+    // editor who attached the note
+    pub username: String,
+    pub text: String,
+}
+
+impl Correction {
+    pub fn created_display(&self) -> String {
+        library::display_date(self.created)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
@@ -89,6 +298,9 @@ pub struct ShortArticleData {
     pub short_text: String,
     pub image_288_path: String,
     pub image_desc: String,
+    pub image_alt: String,
+    pub image_credit: String,
+    pub image_source: String,
     pub created: DateTime<Utc>,
     pub category: String,
 }
@@ -97,6 +309,87 @@ impl ShortArticleData {
     pub fn created_display(&self) -> String {
         library::display_date(self.created)
     }
+
+    pub fn image_credit_display(&self) -> String {
+        library::display_image_credit(&self.image_credit, &self.image_source)
+    }
+}
+
+/**
+ * published Article in the admin list
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct ArticleListData {
+    pub article_file_name: String,
+    pub title: String,
+    pub category: String,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub word_count: u32,
+    pub reading_minutes: u32,
+}
+
+impl ArticleListData {
+    pub fn created_display(&self) -> String {
+        library::display_date(self.created)
+    }
+
+    pub fn updated_display(&self) -> String {
+        library::display_date(self.updated)
+    }
+
+    pub fn reading_time_display(&self) -> String {
+        library::display_reading_time(self.reading_minutes)
+    }
+}
+
+/**
+ * column the admin Article list is ordered by
+ */
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ArticleListSort {
+    Created,
+    Updated,
+    // the same order as the reading time
+    WordCount,
+    Title,
+}
+
+impl ArticleListSort {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "created" => Some(Self::Created),
+            "updated" => Some(Self::Updated),
+            "words" => Some(Self::WordCount),
+            "title" => Some(Self::Title),
+            _ => None,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::WordCount => "words",
+            Self::Title => "title",
+        }
+    }
+
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::WordCount => "word_count",
+            Self::Title => "title",
+        }
+    }
+
+    /**
+     * the newest and the longest first, titles alphabetically
+     */
+    pub const fn descending_by_default(&self) -> bool {
+        !matches!(self, Self::Title)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
@@ -106,6 +399,37 @@ pub struct MiniArticleData {
     pub mini_text: String,
     pub image_50_path: String,
     pub image_desc: String,
+    pub image_alt: String,
+}
+
+/**
+ * target of a [[slug]] link in Article text
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct LinkedArticleData {
+    pub article_file_name: String,
+    pub title: String,
+}
+
+/**
+ * published Article compared to a draft when suggesting related Articles
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct SimilarArticleData {
+    pub article_file_name: String,
+    pub title: String,
+    pub text_raw: String,
+}
+
+/**
+ * all images of a published Article, for the agency billing report
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct ImageCreditData {
+    pub article_file_name: String,
+    pub image_credit: String,
+    pub image_source: String,
+    pub images: Vec<ArticleImage>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
@@ -117,11 +441,42 @@ pub struct AccountArticleData {
     pub image_desc: String,
     pub category: String,
     pub created: DateTime<Utc>,
+    pub publish_state: PublishState,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub state_transitions: Vec<StateTransition>,
+    pub is_live_blog: bool,
 }
 
 impl AccountArticleData {
-    pub fn category_display(&self) -> String {
-        processor::process_category(&self.category)
+    pub fn is_published(&self) -> bool {
+        self.publish_state == PublishState::Published
+    }
+
+    pub fn publish_state_display(&self) -> String {
+        match (self.publish_state, self.publish_at) {
+            (PublishState::Draft, _) => "koncept".into(),
+            (PublishState::InReview, _) => "čeká na schválení".into(),
+            (PublishState::ChangesRequested, _) => "vráceno k úpravám".into(),
+            (PublishState::Rejected, _) => "zamítnuto".into(),
+            (PublishState::Scheduled, Some(publish_at)) => {
+                format!("naplánováno na {}", library::display_date_time(publish_at))
+            }
+            (PublishState::Scheduled, None) => "naplánováno".into(),
+            (PublishState::Published, _) => "".into(),
+            (PublishState::Expired, _) => "staženo, platnost vypršela".into(),
+        }
+    }
+
+    /*
+     * comment of the last review, empty if there is none
+     */
+    pub fn review_comment(&self) -> String {
+        self.state_transitions
+            .iter()
+            .rev()
+            .find(|t| t.from == PublishState::InReview)
+            .map(|t| t.comment.clone())
+            .unwrap_or_default()
     }
 
     pub fn created_display(&self) -> String {
@@ -129,6 +484,26 @@ impl AccountArticleData {
     }
 }
 
+/**
+ * Article waiting in the review queue
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct ReviewArticleData {
+    pub article_file_name: String,
+    pub title: String,
+    pub short_text: String,
+    pub author: String,
+    pub username: String,
+    pub category: String,
+    pub created: DateTime<Utc>,
+}
+
+impl ReviewArticleData {
+    pub fn created_display(&self) -> String {
+        library::display_date_time(self.created)
+    }
+}
+
 impl From<MainArticleData> for TopArticleData {
     fn from(value: MainArticleData) -> Self {
         Self {
@@ -144,25 +519,44 @@ impl TryFrom<ArticleUpload> for Article {
 
     fn try_from(data: ArticleUpload) -> Result<Self, Self::Error> {
         let now = Utc::now();
+        let images: Vec<ArticleImage> = data
+            .images
+            .iter()
+            .filter(|upload| upload.is_new())
+            .map(|upload| ArticleImage::from_upload(upload, &data.base_file_name))
+            .collect();
+        let words = word_count(&data.text_raw);
         Ok(Self {
-            // TODO Use uuid as Article ID, so that it is possible to change article name
             uuid: Uuid::new(),
             author: data.author,
             username: data.username,
             created: now,
+            updated: now,
 
             title: data.title,
-            text: process_text(&data.text_raw),
+            text: process_text(&data.text_raw, &data.internal_links, &images),
+            word_count: words,
+            reading_minutes: library::reading_minutes(words),
             short_text: process_short_text(&data.short_text_raw),
             mini_text: process_short_text(&data.mini_text_raw),
 
+            text_raw: data.text_raw,
+            short_text_raw: data.short_text_raw,
+            mini_text_raw: data.mini_text_raw,
+
             // everything should be relative to web/
             article_file_name: format!("{}.html", data.base_file_name.clone()),
+            previous_file_names: Vec::new(),
             image_desc: data.image_desc,
+            image_alt: data.image_alt,
+            image_credit: data.image_credit,
+            image_source: data.image_source,
             image_50_path: format!("u/{}_image_50.{}", data.base_file_name, data.image_ext),
             image_288_path: format!("u/{}_image_288.{}", data.base_file_name, data.image_ext),
             image_440_path: format!("u/{}_image_440.{}", data.base_file_name, data.image_ext),
             image_820_path: format!("u/{}_image_820.{}", data.base_file_name, data.image_ext),
+            images,
+            sources: data.sources,
 
             has_video: data.has_video,
             video_path: if data.has_video {
@@ -179,33 +573,196 @@ impl TryFrom<ArticleUpload> for Article {
             },
 
             category: data.category,
-            // TODO use UUIDs to refference related articles
-            related_articles: data.related_articles,
+            // the form has file names, resolved to uuids by the caller
+            related_articles: Vec::new(),
 
             is_main: data.is_main,
             is_exclusive: data.is_exclusive,
+            is_live_blog: data.is_live_blog,
+
+            publish_state: data.publish_state,
+            publish_at: data.publish_at,
+            expires_at: data.expires_at,
+            state_transitions: Vec::new(),
         })
     }
 }
 
+impl Article {
+    pub fn is_published(&self) -> bool {
+        self.publish_state == PublishState::Published
+    }
+
+    /**
+     * change the PublishState and remember who did it
+     */
+    pub fn transition(&mut self, to: PublishState, username: &str, comment: &str) {
+        let from = self.publish_state;
+        self.publish_state = to;
+        self.record_transition(from, username, comment);
+    }
+
+    /**
+     * remember a change to the current PublishState, made by a form
+     */
+    pub fn record_transition(&mut self, from: PublishState, username: &str, comment: &str) {
+        self.state_transitions.push(StateTransition {
+            from,
+            to: self.publish_state,
+            username: username.to_string(),
+            comment: comment.to_string(),
+            changed: Utc::now(),
+        });
+    }
+
+    /**
+     * apply edited data to an existing Article
+     * - identity, author and created are kept
+     * - a draft or schedule published from the form is created now, as by the publish worker or an approval
+     * - the file name follows the title, the old one is remembered
+     * - media paths change only if new media were uploaded
+     * - a published Article can't go back to draft
+     */
+    pub fn edited(self, data: ArticleUpload, related_articles: Vec<Uuid>) -> Self {
+        let base = data.base_file_name.clone();
+        let article_file_name = format!("{}.html", base);
+        let mut previous_file_names = self.previous_file_names.clone();
+        if article_file_name != self.article_file_name {
+            previous_file_names.retain(|name| *name != article_file_name);
+            previous_file_names.push(self.article_file_name.clone());
+        }
+        let has_new_image = !data.image_data.is_empty();
+        let was_published = self.is_published();
+        let now = Utc::now();
+        let goes_live = !was_published && data.publish_state == PublishState::Published;
+        let images = edited_images(self.images.clone(), &data.images, &base);
+        let words = word_count(&data.text_raw);
+        Self {
+            article_file_name,
+            previous_file_names,
+            created: if goes_live { now } else { self.created },
+            updated: now,
+
+            title: data.title,
+            text: process_text(&data.text_raw, &data.internal_links, &images),
+            word_count: words,
+            reading_minutes: library::reading_minutes(words),
+            short_text: process_short_text(&data.short_text_raw),
+            mini_text: process_short_text(&data.mini_text_raw),
+
+            text_raw: data.text_raw,
+            short_text_raw: data.short_text_raw,
+            mini_text_raw: data.mini_text_raw,
+
+            image_desc: data.image_desc,
+            image_alt: data.image_alt,
+            image_credit: data.image_credit,
+            image_source: data.image_source,
+            image_50_path: if has_new_image {
+                format!("u/{}_image_50.{}", base, data.image_ext)
+            } else {
+                self.image_50_path
+            },
+            image_288_path: if has_new_image {
+                format!("u/{}_image_288.{}", base, data.image_ext)
+            } else {
+                self.image_288_path
+            },
+            image_440_path: if has_new_image {
+                format!("u/{}_image_440.{}", base, data.image_ext)
+            } else {
+                self.image_440_path
+            },
+            image_820_path: if has_new_image {
+                format!("u/{}_image_820.{}", base, data.image_ext)
+            } else {
+                self.image_820_path
+            },
+            images,
+            sources: data.sources,
+
+            has_video: self.has_video || data.has_video,
+            video_path: if data.has_video {
+                format!("u/{}_video.{}", base, data.video_ext)
+            } else {
+                self.video_path
+            },
+
+            has_audio: self.has_audio || data.has_audio,
+            audio_path: if data.has_audio {
+                format!("u/{}_audio.{}", base, data.audio_ext)
+            } else {
+                self.audio_path
+            },
+
+            category: data.category,
+            related_articles,
+
+            is_main: data.is_main,
+            is_exclusive: data.is_exclusive,
+            is_live_blog: data.is_live_blog,
+
+            publish_state: if was_published { PublishState::Published } else { data.publish_state },
+            publish_at: if was_published { self.publish_at } else { data.publish_at },
+            expires_at: data.expires_at,
+            ..self
+        }
+    }
+}
+
+/*
+ * images of the edit form
+ * - removed and replaced images are dropped, a replacement gets new paths
+ * - an image without a new file keeps its paths, only the caption, alt text and credit change
+ */
+fn edited_images(mut images: Vec<ArticleImage>, uploads: &[ImageUpload], base_file_name: &str) -> Vec<ArticleImage> {
+    for upload in uploads {
+        if upload.remove || upload.is_new() {
+            images.retain(|image| image.number != upload.number);
+        }
+        if upload.is_new() {
+            images.push(ArticleImage::from_upload(upload, base_file_name));
+        } else if let Some(image) = images.iter_mut().find(|image| image.number == upload.number) {
+            image.caption = upload.caption.clone();
+            image.alt = upload.alt.clone();
+            image.credit = upload.credit.clone();
+            image.source = upload.source.clone();
+        }
+    }
+    images.sort_by_key(|image| image.number);
+    images
+}
+
 pub fn easy_article(title: &str, author: &str, text: &str) -> Article {
     let now = Utc::now();
-    let base = safe_article_file_name(title);
+    let base = slugify(title);
     Article {
         uuid: Uuid::new(),
         author: author.to_string(),
         username: author.to_string(),
         created: now,
+        updated: now,
         title: title.to_string(),
         text: text.to_string(),
         short_text: "short text here".to_string(),
         mini_text: "mini text".to_string(),
+        word_count: word_count(text),
+        reading_minutes: library::reading_minutes(word_count(text)),
+        text_raw: text.to_string(),
+        short_text_raw: "short text here".to_string(),
+        mini_text_raw: "mini text".to_string(),
         article_file_name: format!("{}.html", base),
+        previous_file_names: vec![],
         image_desc: "desc".to_string(),
+        image_alt: "alt".to_string(),
+        image_credit: "credit".to_string(),
+        image_source: "source".to_string(),
         image_50_path: format!("{}_image_50.jpg", base),
         image_288_path: format!("{}_image_288.jpg", base),
         image_440_path: format!("{}_image_440.jpg", base),
         image_820_path: format!("{}_image_820.jpg", base),
+        images: vec![],
+        sources: vec![],
         has_video: false,
         video_path: "".to_string(),
         has_audio: false,
@@ -214,5 +771,73 @@ pub fn easy_article(title: &str, author: &str, text: &str) -> Article {
         related_articles: vec![],
         is_main: false,
         is_exclusive: false,
+        is_live_blog: false,
+        publish_state: PublishState::Published,
+        publish_at: None,
+        expires_at: None,
+        state_transitions: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trust::app::article::create_article_easy_builder::ArticleBuilder;
+    use chrono::Duration;
+    use std::collections::HashMap;
+
+    fn upload(publish_state: PublishState) -> ArticleUpload {
+        ArticleUpload {
+            is_main: false,
+            is_exclusive: false,
+            is_live_blog: false,
+            author: "Author".into(),
+            username: "user".into(),
+            title: "Edited".into(),
+            text_raw: "Edited text.".into(),
+            short_text_raw: "Short.".into(),
+            mini_text_raw: "Mini.".into(),
+            category: "veda".into(),
+            image_desc: "desc".into(),
+            image_alt: "alt".into(),
+            image_credit: "credit".into(),
+            image_source: "source".into(),
+            image_ext: String::new(),
+            image_data: Vec::new(),
+            has_video: false,
+            video_data: Vec::new(),
+            video_ext: String::new(),
+            has_audio: false,
+            audio_data: Vec::new(),
+            audio_ext: String::new(),
+            images: Vec::new(),
+            sources: Vec::new(),
+            related_articles: Vec::new(),
+            internal_links: HashMap::new(),
+            base_file_name: "edited".into(),
+            publish_state,
+            publish_at: None,
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn test_edited_created() {
+        let drafted = Utc::now() - Duration::days(3);
+        let draft = ArticleBuilder::article().title("Edited").draft().created(drafted).updated(drafted).build();
+
+        // still a draft, only updated
+        let edited = draft.clone().edited(upload(PublishState::Draft), Vec::new());
+        assert_eq!(edited.created, drafted);
+        assert!(edited.updated > drafted);
+
+        // published now, it wasn't updated since
+        let published = draft.edited(upload(PublishState::Published), Vec::new());
+        assert!(published.created > drafted);
+        assert_eq!(published.updated, published.created);
+
+        // a published Article keeps its date
+        let corrected = published.clone().edited(upload(PublishState::Published), Vec::new());
+        assert_eq!(corrected.created, published.created);
     }
 }
@@ -0,0 +1,245 @@
+use crate::db::database;
+use crate::db::database::{DatabaseSurreal, SurrealError};
+use crate::db::database_category::SurrealCategoryError::CategoryNotFound;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb_types::SurrealValue;
+use thiserror::Error;
+use tracing::log::debug;
+
+const CATEGORY: &str = "category";
+
+#[derive(Debug, Error)]
+pub enum SurrealCategoryError {
+    #[error("surreal db error {0}")]
+    Surreal(#[from] surrealdb::Error),
+
+    #[error("category not found {0}")]
+    CategoryNotFound(String),
+}
+
+/*
+ * key is slug
+ * - the category page is {slug}.html
+ * - articles refer to the category by slug
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, SurrealValue)]
+pub struct Category {
+    pub slug: String,
+    // label in the navigation and on article cards
+    pub name: String,
+    // heading of the category page
+    pub title: String,
+    pub description: String,
+    // order in the navigation and on news.html
+    pub position: i64,
+    pub in_navigation: bool,
+    // link below the section of the category on index.html, empty if it has no section
+    pub homepage_label: String,
+}
+
+/**
+ * access to a database
+ * - categories of Articles, managed by admins
+ */
+#[derive(Debug)]
+pub struct DatabaseCategory {
+    surreal: Arc<DatabaseSurreal>,
+}
+
+impl DatabaseCategory {
+    pub const fn new(db: Arc<DatabaseSurreal>) -> Self {
+        Self { surreal: db }
+    }
+
+    /*
+     * use only for unit tests
+     */
+    pub async fn new_from_scratch() -> Result<Self, SurrealError> {
+        let surreal = Arc::new(database::init_in_memory_db_connection().await?);
+        Ok(Self { surreal })
+    }
+
+    pub async fn create_category(&self, category: Category) -> Result<(), SurrealCategoryError> {
+        debug!("create_category: slug={}", category.slug);
+        let _: Option<Category> =
+            self.surreal.db.create((CATEGORY, category.slug.clone())).content(category).await?;
+        Ok(())
+    }
+
+    pub async fn update_category(&self, category: Category) -> Result<(), SurrealCategoryError> {
+        debug!("update_category: slug={}", category.slug);
+        let updated: Option<Category> =
+            self.surreal.db.update((CATEGORY, category.slug.clone())).content(category.clone()).await?;
+        updated.map(|_| ()).ok_or(CategoryNotFound(category.slug))
+    }
+
+    pub async fn delete_category(&self, slug: &str) -> Result<(), SurrealCategoryError> {
+        debug!("delete_category: slug={}", slug);
+        let _: Option<Category> = self.surreal.db.delete((CATEGORY, slug)).await?;
+        Ok(())
+    }
+
+    pub async fn category_by_slug(&self, slug: &str) -> Result<Option<Category>, SurrealCategoryError> {
+        let category: Option<Category> = self.surreal.db.select((CATEGORY, slug)).await?;
+        Ok(category)
+    }
+
+    /**
+     * used for
+     * - admin list, select in the article forms, news.html
+     */
+    pub async fn all_categories(&self) -> Result<Vec<Category>, SurrealCategoryError> {
+        let mut response =
+            self.surreal.db.query("SELECT * FROM category ORDER BY position ASC, slug ASC").await?;
+        let categories: Vec<Category> = response.take(0)?;
+        Ok(categories)
+    }
+
+    /**
+     * used for
+     * - the navigation bar of all web pages
+     */
+    pub async fn navigation(&self) -> Result<Vec<Category>, SurrealCategoryError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT * FROM category WHERE in_navigation = true ORDER BY position ASC, slug ASC")
+            .await?;
+        let categories: Vec<Category> = response.take(0)?;
+        Ok(categories)
+    }
+
+    /**
+     * used for
+     * - sections of index.html and their pinned slots
+     */
+    pub async fn homepage_sections(&self) -> Result<Vec<Category>, SurrealCategoryError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT * FROM category WHERE homepage_label != '' ORDER BY position ASC, slug ASC")
+            .await?;
+        let categories: Vec<Category> = response.take(0)?;
+        Ok(categories)
+    }
+
+    /**
+     * display name of the category, empty if there is no such category
+     */
+    pub async fn category_name(&self, slug: &str) -> Result<String, SurrealCategoryError> {
+        Ok(self.category_by_slug(slug).await?.map(|c| c.name).unwrap_or_default())
+    }
+}
+
+// sections of index.html in a fresh database, category slug and label of the link to the category
+pub const HOMEPAGE_SECTIONS: [(&str, &str); 2] =
+    [("republika", "Více domácích zpráv"), ("zahranici", "Více zahraničních zpráv")];
+
+/*
+ * categories of a fresh database
+ */
+pub fn default_categories() -> Vec<Category> {
+    let category = |slug: &str, name: &str, title: &str, description: &str, position: i64| Category {
+        slug: slug.into(),
+        name: name.into(),
+        title: title.into(),
+        description: description.into(),
+        position,
+        in_navigation: true,
+        homepage_label: HOMEPAGE_SECTIONS
+            .iter()
+            .find(|(section, _)| *section == slug)
+            .map(|(_, label)| (*label).into())
+            .unwrap_or_default(),
+    };
+    vec![
+        category(
+            "republika",
+            "Republika",
+            "Z naší republiky",
+            "Aktuální dění z Čech, Moravy a Slezska. Zprávy, reportáže i exkluzivní rozhovory.\nDění v parlamentu i na stadionech. Historie, i naše současnost. Témata, která nás spojují i rozdělují, konflikty, které nás posouvají vpřed.",
+            1,
+        ),
+        category(
+            "zahranici",
+            "Zahraničí",
+            "Ze zahraničí",
+            "Témata, která hýbou světem. Zpravodajství, analýzy i souvislosti z mezinárodní politiky, ekonomiky i kultury.\nKlíčové globální dění, diplomatické vztahy i příběhy lidí, které formují mezinárodní scénu.",
+            2,
+        ),
+        category(
+            "technologie",
+            "Technologie",
+            "Ze světa technologií",
+            "Novinky, trendy a analýzy ze světa digitálních inovací a průmyslového technického vývoje.\nPokrok v oblasti umělé inteligence, elektroniky, kyberbezpečnosti i spotřebitelských zařízení.\nPřehledné a srozumitelné informace o směřování budoucích technologií.",
+            3,
+        ),
+        category(
+            "finance",
+            "Finance",
+            "Ze světa financí",
+            "Dění ve světě peněz, investic a ekonomiky. Vývoj kapitálových trhů, podnikatelského prostředí;\ni rozhodnutí centrálních bank. Spolehlivý a srozumitelný přehled hospodářského dění.",
+            4,
+        ),
+        category(
+            "veda",
+            "Věda",
+            "Věda a výzkum",
+            "Objevy z naší domácí i vědecké scény světové.\nPrůlomové studie, technologické inovace, vesmírné mise, medicínské pokroky i zajímavosti z přírodních a společenských věd.",
+            5,
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trust::me::TrustError;
+
+    #[tokio::test]
+    async fn test_default_categories() -> Result<(), TrustError> {
+        let dbc = DatabaseCategory::new_from_scratch().await?;
+
+        let categories = dbc.all_categories().await?;
+        let slugs: Vec<&str> = categories.iter().map(|c| c.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["republika", "zahranici", "technologie", "finance", "veda"]);
+        assert_eq!(dbc.category_name("veda").await?, "Věda");
+        assert_eq!(dbc.category_name("invalid").await?, "");
+
+        let sections = dbc.homepage_sections().await?;
+        let slugs: Vec<&str> = sections.iter().map(|c| c.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["republika", "zahranici"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_category_crud() -> Result<(), TrustError> {
+        let dbc = DatabaseCategory::new_from_scratch().await?;
+
+        let sport = Category {
+            slug: "sport".into(),
+            name: "Sport".into(),
+            title: "Sport".into(),
+            description: "".into(),
+            position: 0,
+            in_navigation: false,
+            homepage_label: "".into(),
+        };
+        dbc.create_category(sport.clone()).await?;
+        assert_eq!(dbc.all_categories().await?[0].slug, "sport");
+        assert!(!dbc.navigation().await?.iter().any(|c| c.slug == "sport"));
+
+        dbc.update_category(Category {
+            in_navigation: true,
+            ..sport.clone()
+        })
+        .await?;
+        assert_eq!(dbc.navigation().await?[0].slug, "sport");
+
+        dbc.delete_category("sport").await?;
+        assert!(dbc.category_by_slug("sport").await?.is_none());
+        assert!(dbc.update_category(sport).await.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use crate::db::database;
+use crate::db::database::{DatabaseSurreal, SurrealError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb::types::{SurrealValue, Uuid};
+use thiserror::Error;
+use tracing::log::debug;
+
+const HOMEPAGE_SLOT: &str = "homepage_slot";
+
+#[derive(Debug, Error)]
+pub enum SurrealHomepageError {
+    #[error("surreal db error {0}")]
+    Surreal(#[from] surrealdb::Error),
+
+    #[error("homepage slot not found {0}")]
+    SlotNotFound(String),
+}
+
+/*
+ * Article pinned to index.html
+ * - section is empty for the top of the page, position 0 is the main Article,
+ *   1 the second and 2 the third one
+ * - otherwise section is a category slug, its highlights are listed by position
+ *   before the latest Articles of the section
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, SurrealValue)]
+pub struct HomepageSlot {
+    pub uuid: Uuid,
+    pub section: String,
+    pub position: i64,
+    pub article_uuid: Uuid,
+    // None until unpinned
+    pub pinned_until: Option<DateTime<Utc>>,
+}
+
+/**
+ * access to a database
+ * - the layout of index.html, managed by editors
+ */
+#[derive(Debug)]
+pub struct DatabaseHomepage {
+    surreal: Arc<DatabaseSurreal>,
+}
+
+impl DatabaseHomepage {
+    pub const fn new(db: Arc<DatabaseSurreal>) -> Self {
+        Self { surreal: db }
+    }
+
+    /*
+     * use only for unit tests
+     */
+    pub async fn new_from_scratch() -> Result<Self, SurrealError> {
+        let surreal = Arc::new(database::init_in_memory_db_connection().await?);
+        Ok(Self { surreal })
+    }
+
+    pub async fn create_slot(&self, slot: HomepageSlot) -> Result<(), SurrealHomepageError> {
+        debug!("create_slot: section={} position={}", slot.section, slot.position);
+        let _: Option<HomepageSlot> =
+            self.surreal.db.create((HOMEPAGE_SLOT, slot.uuid.to_string())).content(slot).await?;
+        Ok(())
+    }
+
+    pub async fn slot_by_id(&self, uuid: Uuid) -> Result<HomepageSlot, SurrealHomepageError> {
+        let slot: Option<HomepageSlot> = self.surreal.db.select((HOMEPAGE_SLOT, uuid.to_string())).await?;
+        slot.ok_or(SurrealHomepageError::SlotNotFound(uuid.to_string()))
+    }
+
+    pub async fn update_position(&self, uuid: Uuid, position: i64) -> Result<(), SurrealHomepageError> {
+        debug!("update_position: slot={} position={}", uuid, position);
+        self.surreal
+            .db
+            .query("UPDATE homepage_slot SET position = $position WHERE uuid = $uuid")
+            .bind(("uuid", uuid))
+            .bind(("position", position))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete_slot(&self, uuid: Uuid) -> Result<(), SurrealHomepageError> {
+        debug!("delete_slot: slot={}", uuid);
+        let _: Option<HomepageSlot> = self.surreal.db.delete((HOMEPAGE_SLOT, uuid.to_string())).await?;
+        Ok(())
+    }
+
+    /**
+     * used for
+     * - the layout screen, including expired pins the worker didn't remove yet
+     */
+    pub async fn all_slots(&self) -> Result<Vec<HomepageSlot>, SurrealHomepageError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT * FROM homepage_slot ORDER BY section ASC, position ASC")
+            .await?;
+        let slots: Vec<HomepageSlot> = response.take(0)?;
+        Ok(slots)
+    }
+
+    /**
+     * used for
+     * - index.html
+     * - the top of the page or the highlights of a section, ordered by position
+     */
+    pub async fn active_slots(
+        &self,
+        section: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<HomepageSlot>, SurrealHomepageError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(
+                "SELECT * FROM homepage_slot \
+                    WHERE section = $section AND (pinned_until = NONE OR pinned_until > $now) \
+                    ORDER BY position ASC",
+            )
+            .bind(("section", section.to_string()))
+            .bind(("now", now))
+            .await?;
+        let slots: Vec<HomepageSlot> = response.take(0)?;
+        Ok(slots)
+    }
+
+    /**
+     * returns the number of removed pins
+     */
+    pub async fn delete_expired(&self, now: DateTime<Utc>) -> Result<usize, SurrealHomepageError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("DELETE homepage_slot WHERE pinned_until != NONE AND pinned_until <= $now RETURN BEFORE")
+            .bind(("now", now))
+            .await?;
+        let removed: Vec<HomepageSlot> = response.take(0)?;
+        Ok(removed.len())
+    }
+
+    /*
+     * the Article was deleted
+     */
+    pub async fn delete_slots_of_article(&self, article: Uuid) -> Result<(), SurrealHomepageError> {
+        self.surreal
+            .db
+            .query("DELETE homepage_slot WHERE article_uuid = $article")
+            .bind(("article", article))
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trust::me::TrustError;
+    use chrono::Duration;
+
+    fn slot(section: &str, position: i64, pinned_until: Option<DateTime<Utc>>) -> HomepageSlot {
+        HomepageSlot {
+            uuid: Uuid::new(),
+            section: section.into(),
+            position,
+            article_uuid: Uuid::new(),
+            pinned_until,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_active_and_expired_slots() -> Result<(), TrustError> {
+        let dbh = DatabaseHomepage::new_from_scratch().await?;
+        let now = Utc::now();
+
+        let main = slot("", 0, None);
+        dbh.create_slot(main.clone()).await?;
+        dbh.create_slot(slot("", 1, Some(now - Duration::minutes(1)))).await?;
+        dbh.create_slot(slot("republika", 1, Some(now + Duration::hours(1)))).await?;
+        dbh.create_slot(slot("republika", 0, None)).await?;
+
+        let top = dbh.active_slots("", now).await?;
+        assert_eq!(top, vec![main.clone()]);
+        let section = dbh.active_slots("republika", now).await?;
+        assert_eq!(section.iter().map(|s| s.position).collect::<Vec<_>>(), vec![0, 1]);
+
+        assert_eq!(dbh.all_slots().await?.len(), 4);
+        assert_eq!(dbh.delete_expired(now).await?, 1);
+        assert_eq!(dbh.all_slots().await?.len(), 3);
+
+        dbh.update_position(main.uuid, 2).await?;
+        assert_eq!(dbh.slot_by_id(main.uuid).await?.position, 2);
+
+        dbh.delete_slots_of_article(main.article_uuid).await?;
+        assert!(dbh.slot_by_id(main.uuid).await.is_err());
+        Ok(())
+    }
+}
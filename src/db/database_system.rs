@@ -1,14 +1,21 @@
 use crate::db::database;
 use crate::db::database::{DatabaseSurreal, SurrealError};
 use crate::db::database_system::ArticleStatus::DoesNotExist;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 use std::sync::Arc;
+use surrealdb::types::Uuid;
 use surrealdb_types::SurrealValue;
 use thiserror::Error;
 use tracing::warn;
-use ArticleStatus::{Invalid, Valid};
+use ArticleStatus::{Gone, Invalid, Valid};
 
 const ARTICLE_STATUS_TABLE: &str = "article_update_status";
+const BREAKING_NEWS_TABLE: &str = "breaking_news";
+// there is at most one breaking news at a time
+const BREAKING_NEWS_ID: &str = "current";
+const IMPORT_SOURCE_TABLE: &str = "import_source";
 
 #[derive(Debug, Error)]
 pub enum SurrealSystemError {
@@ -28,6 +35,8 @@ pub enum ArticleStatus {
     Valid,
     Invalid,
     DoesNotExist,
+    // expired Article, the HTML file says the content is no longer available
+    Gone,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
@@ -36,6 +45,70 @@ pub struct ArticleUpdateStatus {
     pub article_status: ArticleStatus,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SurrealValue)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Important,
+    Critical,
+}
+
+impl Severity {
+    pub fn from_form(value: &str) -> Option<Self> {
+        match value {
+            "info" => Some(Self::Info),
+            "important" => Some(Self::Important),
+            "critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+
+    // used by the templates as a css class and as a form value
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Important => "important",
+            Self::Critical => "critical",
+        }
+    }
+
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Info => "AKTUÁLNĚ",
+            Self::Important => "DŮLEŽITÉ",
+            Self::Critical => "MIMOŘÁDNĚ",
+        }
+    }
+}
+
+/*
+ * banner on top of all rendered pages
+ * - removed by the breaking news worker once expired
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, SurrealValue)]
+pub struct BreakingNews {
+    pub text: String,
+    pub article_file_name: String,
+    pub severity: Severity,
+    pub expires: DateTime<Utc>,
+}
+
+impl BreakingNews {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires <= now
+    }
+}
+
+/*
+ * an Article imported from the previous system
+ * - source is the id of the bundle there, the file name may change after the import
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, SurrealValue)]
+pub struct ImportSource {
+    pub source: String,
+    pub article_uuid: Uuid,
+}
+
 /**
  * access to a database
  * - anything system-related
@@ -76,8 +149,12 @@ impl DatabaseSystem {
     pub async fn invalidate_all_article(&self) -> Result<(), SurrealSystemError> {
         self.surreal
             .db
-            .query(format!("UPDATE {} SET article_status = $status", ARTICLE_STATUS_TABLE))
+            .query(format!(
+                "UPDATE {} SET article_status = $status WHERE article_status != $gone",
+                ARTICLE_STATUS_TABLE
+            ))
             .bind(("status", Invalid))
+            .bind(("gone", Gone))
             .await?;
         Ok(())
     }
@@ -89,13 +166,27 @@ impl DatabaseSystem {
         self.write_article_record(&article_file_name, Invalid).await
     }
 
+    /*
+     * an expired Article stays gone, e.g. when a related Article changes
+     * only publishing it again creates a new record
+     */
     pub async fn invalidate_article(
         &self,
         article_file_name: String,
     ) -> Result<(), SurrealSystemError> {
+        if self.read_article_validity(&article_file_name).await? == Gone {
+            return Ok(());
+        }
         self.write_article_record(&article_file_name, Invalid).await
     }
 
+    pub async fn gone_article(
+        &self,
+        article_file_name: String,
+    ) -> Result<(), SurrealSystemError> {
+        self.write_article_record(&article_file_name, Gone).await
+    }
+
     pub async fn validate_article(
         &self,
         article_file_name: String,
@@ -103,6 +194,18 @@ impl DatabaseSystem {
         self.write_article_record(&article_file_name, Valid).await
     }
 
+    /*
+     * the article HTML file doesn't exist anymore, e.g. after a rename
+     */
+    pub async fn delete_article_record(
+        &self,
+        article_file_name: &str,
+    ) -> Result<(), SurrealSystemError> {
+        let _: Option<ArticleUpdateStatus> =
+            self.surreal.db.delete((ARTICLE_STATUS_TABLE, article_file_name)).await?;
+        Ok(())
+    }
+
     pub async fn read_article_validity(
         &self,
         article_file_name: &str,
@@ -116,6 +219,88 @@ impl DatabaseSystem {
             }, |status| Ok(status.article_status))
     }
 
+    pub async fn write_breaking_news(&self, news: BreakingNews) -> Result<(), SurrealSystemError> {
+        let _: Option<BreakingNews> =
+            self.surreal.db.upsert((BREAKING_NEWS_TABLE, BREAKING_NEWS_ID)).content(news).await?;
+        Ok(())
+    }
+
+    pub async fn read_breaking_news(&self) -> Result<Option<BreakingNews>, SurrealSystemError> {
+        let news: Option<BreakingNews> =
+            self.surreal.db.select((BREAKING_NEWS_TABLE, BREAKING_NEWS_ID)).await?;
+        Ok(news)
+    }
+
+    pub async fn delete_breaking_news(&self) -> Result<(), SurrealSystemError> {
+        let _: Option<BreakingNews> =
+            self.surreal.db.delete((BREAKING_NEWS_TABLE, BREAKING_NEWS_ID)).await?;
+        Ok(())
+    }
+
+    pub async fn write_import_source(&self, source: &str, article: Uuid) -> Result<(), SurrealSystemError> {
+        let _: Option<ImportSource> = self
+            .surreal
+            .db
+            .upsert((IMPORT_SOURCE_TABLE, source))
+            .content(ImportSource {
+                source: source.into(),
+                article_uuid: article,
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn read_import_source(&self, source: &str) -> Result<Option<Uuid>, SurrealSystemError> {
+        let response: Option<ImportSource> = self.surreal.db.select((IMPORT_SOURCE_TABLE, source)).await?;
+        Ok(response.map(|import_source| import_source.article_uuid))
+    }
+
+    /**
+     * used for
+     * - restore of a backup, the gone pages are rendered again
+     */
+    pub async fn gone_article_file_names(&self) -> Result<Vec<String>, SurrealSystemError> {
+        let mut response = self
+            .surreal
+            .db
+            .query(format!("SELECT VALUE article_file_name FROM {} WHERE article_status = $gone", ARTICLE_STATUS_TABLE))
+            .bind(("gone", Gone))
+            .await?;
+        let names: Vec<String> = response.take(0)?;
+        Ok(names)
+    }
+
+    /**
+     * nothing was created yet, the default categories don't count
+     */
+    pub async fn is_empty(&self) -> Result<bool, SurrealSystemError> {
+        let mut response = self
+            .surreal
+            .db
+            .query("SELECT VALUE article_file_name FROM article LIMIT 1; SELECT VALUE username FROM user LIMIT 1;")
+            .await?;
+        let articles: Vec<String> = response.take(0)?;
+        let users: Vec<String> = response.take(1)?;
+        Ok(articles.is_empty() && users.is_empty())
+    }
+
+    /**
+     * every table, definitions and records, as SurrealQL statements
+     */
+    pub async fn export_database(&self, file: &Path) -> Result<(), SurrealSystemError> {
+        self.surreal.db.export(file).await?;
+        Ok(())
+    }
+
+    /**
+     * into an empty database only, the default categories are replaced by the exported ones
+     */
+    pub async fn import_database(&self, file: &Path) -> Result<(), SurrealSystemError> {
+        self.surreal.db.query("DELETE category").await?;
+        self.surreal.db.import(file).await?;
+        Ok(())
+    }
+
     pub async fn health(&self) -> Result<String, SurrealSystemError> {
         match self.surreal.db.health().await {
             Ok(()) => Ok("ok".into()),
@@ -165,6 +350,85 @@ mod tests {
         assert_eq!(s1, Invalid);
         assert_eq!(s2, Invalid);
 
+        // expired, nothing brings it back but publishing again
+        dbs.gone_article(article_name2.clone()).await?;
+        dbs.invalidate_article(article_name2.clone()).await?;
+        dbs.invalidate_all_article().await?;
+        assert_eq!(dbs.read_article_validity(&article_name2).await?, Gone);
+        dbs.create_article_record(article_name2.clone()).await?;
+        assert_eq!(dbs.read_article_validity(&article_name2).await?, Invalid);
+
+        // delete it
+        dbs.delete_article_record(&article_name).await?;
+        let s = dbs.read_article_validity(&article_name).await?;
+        assert_eq!(s, DoesNotExist);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_export_import_database() -> Result<(), TrustError> {
+        let dbs = DatabaseSystem::new_from_scratch().await?;
+        assert!(dbs.is_empty().await?);
+        dbs.create_article_record("test-exported.html".into()).await?;
+        dbs.gone_article("test-exported-gone.html".into()).await?;
+        let news = BreakingNews {
+            text: "Exported".into(),
+            article_file_name: "test-exported.html".into(),
+            severity: Severity::Info,
+            expires: Utc::now(),
+        };
+        dbs.write_breaking_news(news.clone()).await?;
+
+        let file = std::env::temp_dir().join("axiomatik-test-export.surql");
+        dbs.export_database(&file).await?;
+
+        let restored = DatabaseSystem::new_from_scratch().await?;
+        restored.import_database(&file).await?;
+        std::fs::remove_file(&file)?;
+
+        assert_eq!(restored.read_article_validity("test-exported.html").await?, Invalid);
+        assert_eq!(restored.gone_article_file_names().await?, ["test-exported-gone.html"]);
+        assert_eq!(restored.read_breaking_news().await?, Some(news));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_import_source() -> Result<(), TrustError> {
+        let dbs = DatabaseSystem::new_from_scratch().await?;
+        let source = "Old Title|2019-05-01T10:00:00+00:00";
+        assert_eq!(dbs.read_import_source(source).await?, None);
+
+        let article = Uuid::new();
+        dbs.write_import_source(source, article).await?;
+        assert_eq!(dbs.read_import_source(source).await?, Some(article));
+        assert_eq!(dbs.read_import_source("old-42").await?, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_breaking_news() -> Result<(), TrustError> {
+        let dbs = DatabaseSystem::new_from_scratch().await?;
+        assert_eq!(dbs.read_breaking_news().await?, None);
+
+        let now = Utc::now();
+        let news = BreakingNews {
+            text: "Test breaking".into(),
+            article_file_name: "test-breaking.html".into(),
+            severity: Severity::Critical,
+            expires: now + chrono::Duration::hours(1),
+        };
+        dbs.write_breaking_news(news.clone()).await?;
+        assert_eq!(dbs.read_breaking_news().await?, Some(news.clone()));
+        assert!(!news.is_expired(now));
+
+        // replaces the current one
+        let update = BreakingNews { severity: Severity::Info, ..news };
+        dbs.write_breaking_news(update.clone()).await?;
+        assert_eq!(dbs.read_breaking_news().await?, Some(update));
+
+        dbs.delete_breaking_news().await?;
+        assert_eq!(dbs.read_breaking_news().await?, None);
         Ok(())
     }
 }
@@ -45,6 +45,10 @@ pub enum SurrealUserError {
 pub struct User {
     pub username: String,
     pub author_name: String,
+    // shown on the public author page
+    pub bio: String,
+    // empty if the author has no photo
+    pub photo_path: String,
     pub password_hash: String,
     pub needs_password_change: bool,
     pub role: Role,
@@ -62,17 +66,71 @@ impl AuthUser for User {
     }
 }
 
+/**
+ * - Admin manages users, categories and everything else
+ * - Reviewer approves, returns or rejects submitted Articles
+ * - Editor publishes own Articles directly
+ * - Contributor only submits Articles for review
+ */
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, SurrealValue)]
 pub enum Role {
     Admin,
+    Reviewer,
     Editor,
+    Contributor,
+}
+
+/**
+ * actions checked per route and in the forms
+ */
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Permission {
+    // create and edit own Articles, own account
+    WriteArticle,
+    // publish or schedule without a review
+    PublishArticle,
+    // the review queue
+    ReviewArticle,
+    // edit Articles of other users
+    EditAnyArticle,
+    // layout of index.html
+    ManageHomepage,
+    // users, categories, deleting Articles
+    Administer,
+}
+
+impl Role {
+    pub const fn can(&self, permission: Permission) -> bool {
+        match permission {
+            Permission::WriteArticle => true,
+            Permission::PublishArticle | Permission::ManageHomepage => !matches!(self, Self::Contributor),
+            Permission::ReviewArticle | Permission::EditAnyArticle => {
+                matches!(self, Self::Admin | Self::Reviewer)
+            }
+            Permission::Administer => matches!(self, Self::Admin),
+        }
+    }
+
+    /*
+     * value of the role select in the admin user form
+     */
+    pub fn from_form(value: &str) -> Option<Self> {
+        match value {
+            "reviewer" => Some(Self::Reviewer),
+            "editor" => Some(Self::Editor),
+            "contributor" => Some(Self::Contributor),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for Role {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Admin => write!(f, "Admin"),
+            Self::Reviewer => write!(f, "Reviewer"),
             Self::Editor => write!(f, "Editor"),
+            Self::Contributor => write!(f, "Contributor"),
         }
     }
 }
@@ -110,6 +168,30 @@ impl DatabaseUser {
         Ok(())
     }
 
+    pub async fn update_user_bio(&self, user_name: &str, new_bio: &str) -> Result<(), SurrealUserError> {
+        let _: Option<User> = self
+            .surreal
+            .db
+            .update(("user", user_name.to_string()))
+            .merge(json!({"bio": new_bio.to_string()}))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn update_user_photo(
+        &self,
+        user_name: &str,
+        new_photo_path: &str,
+    ) -> Result<(), SurrealUserError> {
+        let _: Option<User> = self
+            .surreal
+            .db
+            .update(("user", user_name.to_string()))
+            .merge(json!({"photo_path": new_photo_path.to_string()}))
+            .await?;
+        Ok(())
+    }
+
     pub async fn update_user_password(
         &self,
         user_name: String,
@@ -174,6 +256,8 @@ mod tests {
             username: "tester".to_string(),
             author_name: "Test Author".to_string(),
             password_hash: "hash".to_string(),
+            bio: String::new(),
+            photo_path: String::new(),
             needs_password_change: false,
             role: Role::Editor,
         };
@@ -191,6 +275,13 @@ mod tests {
         let fetched_user = db.get_user_by_name("tester").await?.unwrap();
         assert_eq!(fetched_user.author_name, "New Author Name");
 
+        db.update_user_bio("tester", "Píše o vědě.").await?;
+        db.update_user_photo("tester", "u/autor-tester.png").await?;
+
+        let fetched_user = db.get_user_by_name("tester").await?.unwrap();
+        assert_eq!(fetched_user.bio, "Píše o vědě.");
+        assert_eq!(fetched_user.photo_path, "u/autor-tester.png");
+
         // delete
         db.delete_user("tester").await?;
 
@@ -200,6 +291,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_role_permissions() {
+        assert!(Role::Contributor.can(Permission::WriteArticle));
+        assert!(!Role::Contributor.can(Permission::PublishArticle));
+        assert!(Role::Editor.can(Permission::PublishArticle));
+        assert!(Role::Editor.can(Permission::ManageHomepage));
+        assert!(!Role::Contributor.can(Permission::ManageHomepage));
+        assert!(!Role::Editor.can(Permission::ReviewArticle));
+        assert!(Role::Reviewer.can(Permission::ReviewArticle));
+        assert!(Role::Reviewer.can(Permission::EditAnyArticle));
+        assert!(!Role::Reviewer.can(Permission::Administer));
+        assert!(Role::Admin.can(Permission::Administer));
+    }
+
     #[tokio::test]
     async fn test_get_nonexistent_user() -> Result<(), SurrealUserError> {
         let db = DatabaseUser::new_from_scratch().await?;
@@ -208,6 +313,8 @@ mod tests {
             username: "tester1".to_string(),
             author_name: "Test Author".to_string(),
             password_hash: "hash".to_string(),
+            bio: String::new(),
+            photo_path: String::new(),
             needs_password_change: false,
             role: Role::Editor,
         };
@@ -233,6 +340,8 @@ mod tests {
             username: "lukas".to_string(),
             author_name: "Any author".to_string(),
             password_hash: "hash".to_string(),
+            bio: String::new(),
+            photo_path: String::new(),
             needs_password_change: false,
             role: Role::Admin,
         };
@@ -253,6 +362,8 @@ mod tests {
             username: "editor".to_string(),
             author_name: "Editor Author".to_string(),
             password_hash: "hash".to_string(),
+            bio: String::new(),
+            photo_path: String::new(),
             needs_password_change: false,
             role: Role::Editor,
         };
@@ -18,24 +18,60 @@ pub mod application {
     pub mod admin_form_article {
         pub mod admin_article;
     }
+    pub mod admin_form_breaking_news {
+        pub mod admin_breaking_news;
+    }
+    pub mod admin_form_category {
+        pub mod admin_category;
+    }
+    pub mod admin_form_image_sources {
+        pub mod admin_image_sources;
+    }
     pub mod admin_form_user {
         pub mod admin_user;
     }
     pub mod form_account {
         pub mod account;
     }
+    pub mod form_article_revisions {
+        pub mod article_revisions;
+    }
+    pub mod form_review {
+        pub mod review;
+    }
+    pub mod form_live_blog {
+        pub mod live_blog;
+    }
+    pub mod form_correction {
+        pub mod correction;
+    }
+    pub mod form_homepage {
+        pub mod homepage;
+    }
     pub mod page_article {
         pub mod article;
+        pub mod live_entries;
+    }
+    pub mod page_author {
+        pub mod author;
+    }
+    pub mod page_category {
+        pub mod category;
+    }
+    pub mod page_corrections {
+        pub mod corrections;
     }
     pub mod form_change_password {
         pub mod change_password;
     }
-    pub mod category_finance {
-        pub mod finance;
-    }
     pub mod form_create_article {
         pub mod create_article;
         pub mod create_article_parser;
+        pub mod preview_article;
+        pub mod suggest_related;
+    }
+    pub mod form_edit_article {
+        pub mod edit_article;
     }
     pub mod page_index {
         pub mod index;
@@ -46,26 +82,19 @@ pub mod application {
     pub mod page_all_news {
         pub mod all_news;
     }
-    pub mod category_republika {
-        pub mod republika;
+    pub mod page_archive {
+        pub mod archive;
     }
     pub mod page_search {
         pub mod search;
     }
-    pub mod category_technologie {
-        pub mod technologie;
-    }
-    pub mod category_veda {
-        pub mod veda;
-    }
-    pub mod category_zahranici {
-        pub mod zahranici;
-    }
 }
 pub mod db {
     pub mod database;
     pub mod database_article;
     pub mod database_article_data;
+    pub mod database_category;
+    pub mod database_homepage;
     pub mod database_system;
     pub mod database_user;
 }
@@ -73,11 +102,15 @@ pub mod data {
     pub mod audio_extractor;
     pub mod audio_processor;
     pub mod audio_validator;
+    pub mod embed_processor;
     pub mod image_extractor;
     pub mod image_processor;
     pub mod image_validator;
     pub mod library;
     pub mod processor;
+    pub mod similarity;
+    pub mod slug;
+    pub mod text_diff;
     pub mod text_extractor;
     pub mod text_processor;
     pub mod text_validator;
@@ -93,19 +126,26 @@ pub mod feature {
 }
 pub mod system {
     pub mod authentication;
+    pub mod backup;
     pub mod commands;
     pub mod configuration;
     pub mod data_system;
     pub mod data_updates;
     pub mod health;
+    pub mod import;
     pub mod logger;
     pub mod router_app;
     pub mod router_web;
     pub mod server;
 }
 pub mod worker {
+    pub mod breaking_news_worker;
+    pub mod expire_worker;
     pub mod heartbeat;
+    pub mod homepage_worker;
+    pub mod interval_worker;
     pub mod midnight_worker;
+    pub mod publish_worker;
     pub mod weather_worker;
 }
 /*
@@ -122,6 +162,14 @@ pub mod trust {
                 pub mod admin_article_data;
                 pub mod admin_delete_article_controller;
             }
+            pub mod admin_breaking_news {
+                pub mod admin_clear_breaking_news_controller;
+                pub mod admin_set_breaking_news_controller;
+            }
+            pub mod admin_category {
+                pub mod admin_category_data;
+                pub mod admin_create_category_controller;
+            }
             pub mod admin_user {
                 pub mod admin_create_user_controller;
                 pub mod admin_delete_user_controller;
@@ -133,16 +181,31 @@ pub mod trust {
             pub mod create_article_controller;
             pub mod create_article_data;
             pub mod create_article_easy_builder;
+            pub mod edit_article_controller;
+            pub mod restore_revision_controller;
+            pub mod suggest_related_controller;
         }
         pub mod change_password {
             pub mod change_password_controller;
             pub mod change_password_data;
         }
+        pub mod correction {
+            pub mod correction_controller;
+        }
+        pub mod homepage {
+            pub mod homepage_pin_controller;
+        }
+        pub mod live_blog {
+            pub mod live_blog_controller;
+        }
         pub mod login {
             pub mod login_controller;
             pub mod login_data;
             pub mod response_verifier_login;
         }
+        pub mod review {
+            pub mod review_controller;
+        }
         pub mod user {
             pub mod user_data;
         }
@@ -151,6 +214,9 @@ pub mod trust {
         }
     }
     pub mod app_controller;
+    pub mod command {
+        pub mod command_controller;
+    }
     pub mod db {
         pub mod db_article_controller;
         pub mod db_article_verifier;
@@ -169,4 +235,7 @@ pub mod trust {
         pub mod auth_web_controller;
         pub mod web_controller;
     }
+    pub mod worker {
+        pub mod worker_controller;
+    }
 }
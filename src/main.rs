@@ -2,20 +2,29 @@ use crate::ApplicationError::UnrecognizedParameters;
 use axiomatik_web::db::database;
 use axiomatik_web::db::database::SurrealError;
 use axiomatik_web::db::database_article::DatabaseArticle;
-use axiomatik_web::db::database_system::DatabaseSystem;
+use axiomatik_web::db::database_category::DatabaseCategory;
+use axiomatik_web::db::database_homepage::DatabaseHomepage;
+use axiomatik_web::db::database_system::{DatabaseSystem, SurrealSystemError};
 use axiomatik_web::db::database_user::{DatabaseUser, SurrealUserError};
+use axiomatik_web::system::backup::{backup, restore, BackupError, MEDIA_DIR};
 use axiomatik_web::system::commands::{create_admin_user, CommandError};
 use axiomatik_web::system::configuration::ConfigurationError;
+use axiomatik_web::system::import::{import_directory, ImportError};
 use axiomatik_web::system::server::{ServerError, TheState};
 use axiomatik_web::system::{configuration, logger};
 use axiomatik_web::system::{data_system, data_updates, server};
+use axiomatik_web::worker::breaking_news_worker::start_breaking_news_worker;
+use axiomatik_web::worker::expire_worker::start_expire_worker;
 use axiomatik_web::worker::heartbeat;
+use axiomatik_web::worker::homepage_worker::start_homepage_worker;
 use axiomatik_web::worker::midnight_worker::{start_midnight_worker, MidnightWorkerError};
+use axiomatik_web::worker::publish_worker::start_publish_worker;
 use axiomatik_web::worker::weather_worker::{start_weather_worker, WeatherWorkerError};
 use fs::create_dir_all;
 use std::env;
 use std::fs;
 use std::io::Error;
+use std::path::Path;
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::net::TcpListener;
@@ -51,8 +60,17 @@ pub enum ApplicationError {
     #[error("weather error")]
     Weather(#[from] WeatherWorkerError),
 
+    #[error("surreal system error")]
+    SurrealSystem(#[from] SurrealSystemError),
+
     #[error("surreal user error")]
     SurrealUser(#[from] SurrealUserError),
+
+    #[error("import error")]
+    Import(#[from] ImportError),
+
+    #[error("backup error")]
+    Backup(#[from] BackupError),
 }
 
 #[tokio::main]
@@ -64,9 +82,14 @@ async fn main() -> Result<(), ApplicationError> {
      */
     let args: Vec<String> = env::args().collect();
     info!("{:?}", args);
-    if args.len() > 1 {
-        return Err(UnrecognizedParameters);
-    }
+    let command = match args.as_slice() {
+        [_] => None,
+        // migration from the previous system, full site backup and restore, all with the server stopped
+        [_, command, path] if matches!(command.as_str(), "import" | "backup" | "restore") => {
+            Some((command.clone(), path.clone()))
+        }
+        _ => return Err(UnrecognizedParameters),
+    };
 
     /*
      * databases
@@ -75,6 +98,8 @@ async fn main() -> Result<(), ApplicationError> {
     let dba = Arc::new(DatabaseArticle::new(surreal.clone()));
     let dbu = Arc::new(DatabaseUser::new(surreal.clone()));
     let dbs = Arc::new(DatabaseSystem::new(surreal.clone()));
+    let dbc = Arc::new(DatabaseCategory::new(surreal.clone()));
+    let dbh = Arc::new(DatabaseHomepage::new(surreal.clone()));
 
     /*
      * in memory application data
@@ -88,11 +113,24 @@ async fn main() -> Result<(), ApplicationError> {
     let config = configuration::get_config()?;
     #[rustfmt::skip]
     let state = TheState {
-        dba, dbu, dbs, ds, dv,
+        dba, dbu, dbs, dbc, dbh, ds, dv,
         start_time: chrono::Utc::now(),
         config: config.clone(),
     };
 
+    // restore needs the instance still empty, before any admin is created
+    match &command {
+        Some((command, path)) if command == "backup" => {
+            backup(&state, Path::new(path), Path::new(MEDIA_DIR)).await?;
+            return Ok(());
+        }
+        Some((command, path)) if command == "restore" => {
+            restore(&state, Path::new(path), Path::new(MEDIA_DIR)).await?;
+            return Ok(());
+        }
+        _ => {}
+    }
+
     if !state.dbu.admin_exists().await? {
         warn!("no admin found");
 
@@ -100,6 +138,15 @@ async fn main() -> Result<(), ApplicationError> {
         create_admin_user(&state).await?;
     }
 
+    if let Some((_, dir)) = command {
+        let report = import_directory(&state, Path::new(&dir)).await?;
+        report.log();
+        return Ok(());
+    }
+
+    // the banner survives a restart
+    state.ds.set_breaking_news(state.dbs.read_breaking_news().await?);
+
     /*
      * server
      */
@@ -124,6 +171,10 @@ async fn main() -> Result<(), ApplicationError> {
     heartbeat::start_heart_beat();
     start_weather_worker(state.clone())?;
     start_midnight_worker(state.clone())?;
+    start_publish_worker(state.clone());
+    start_homepage_worker(state.clone());
+    start_breaking_news_worker(state.clone());
+    start_expire_worker(state.clone());
 
     /*
      * routers
@@ -0,0 +1,381 @@
+/*
+ * full site backup and restore
+ * - axiomatik-web backup <file.tar.gz>, axiomatik-web restore <file.tar.gz>
+ * - the archive has the database as SurrealQL, the uploaded media and a manifest with checksums
+ * - generated HTML isn't archived, every page is rendered again after a restore
+ * - run with the server stopped, both open the same database and RocksDB allows only one process at a time
+ */
+
+use crate::application::page_article::article;
+use crate::application::page_article::article::ArticleError;
+use crate::db::database_system::SurrealSystemError;
+use crate::system::server::TheState;
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::process;
+use tar::{Archive, Builder, Header};
+use thiserror::Error;
+use tracing::{info, warn};
+use BackupError::{ChecksumMismatch, ManifestMissing, MissingFile, NotEmpty, UnexpectedFile, UnsupportedVersion};
+
+pub const MEDIA_DIR: &str = "web/u";
+const BACKUP_FORMAT: &str = "axiomatik-backup";
+const BACKUP_VERSION: u32 = 1;
+const MANIFEST: &str = "manifest.json";
+const DATABASE: &str = "database.surql";
+// archive path of the media directory
+const MEDIA: &str = "media";
+
+#[derive(Debug, Error)]
+pub enum BackupError {
+    #[error("io error {0}")]
+    BackupIo(#[from] io::Error),
+
+    #[error("invalid manifest {0}")]
+    BackupJson(#[from] serde_json::Error),
+
+    #[error("surreal system error {0}")]
+    BackupSurrealSystem(#[from] SurrealSystemError),
+
+    #[error("render error {0}")]
+    BackupRender(#[from] ArticleError),
+
+    #[error("restore needs an empty instance")]
+    NotEmpty,
+
+    #[error("unsupported backup {0} version {1}")]
+    UnsupportedVersion(String, u32),
+
+    #[error("manifest missing")]
+    ManifestMissing,
+
+    #[error("file {0} missing")]
+    MissingFile(String),
+
+    #[error("unexpected file {0}")]
+    UnexpectedFile(String),
+
+    #[error("checksum mismatch {0}")]
+    ChecksumMismatch(String),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format: String,
+    pub version: u32,
+    pub created: DateTime<Utc>,
+    pub files: Vec<BackupFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupFile {
+    // path in the archive
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/*
+ * hash and length of the data while it is being archived
+ */
+struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+    length: u64,
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        self.length += n as u64;
+        Ok(n)
+    }
+}
+
+/**
+ * database and media into a single archive
+ * - the manifest is the last file of the archive, the checksums are known only then
+ * - media deleted while the backup runs are left out
+ */
+pub async fn backup(state: &TheState, archive: &Path, media_dir: &Path) -> Result<BackupManifest, BackupError> {
+    info!("backup: {}", archive.display());
+    let staging = staging_dir("backup")?;
+    let result = backup_staged(state, archive, media_dir, &staging).await;
+    fs::remove_dir_all(&staging)?;
+    result
+}
+
+async fn backup_staged(
+    state: &TheState,
+    archive: &Path,
+    media_dir: &Path,
+    staging: &Path,
+) -> Result<BackupManifest, BackupError> {
+    let database = staging.join(DATABASE);
+    state.dbs.export_database(&database).await?;
+
+    let mut sources = vec![(DATABASE.to_string(), database)];
+    for media in media_files(media_dir)? {
+        let relative = media.strip_prefix(media_dir).unwrap_or(&media);
+        sources.push((archive_path(&Path::new(MEDIA).join(relative)), media));
+    }
+
+    let mut out = Builder::new(GzEncoder::new(BufWriter::new(File::create(archive)?), Compression::default()));
+    let mut files = Vec::new();
+    for (path, source) in sources {
+        let file = match File::open(&source) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                warn!("skipped deleted {}", source.display());
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let size = file.metadata()?.len();
+        let mut reader = HashingReader { inner: BufReader::new(file).take(size), hasher: Sha256::new(), length: 0 };
+        out.append_data(&mut file_header(size), &path, &mut reader)?;
+        // the header already has the size, a file cut short spoils the archive
+        if reader.length != size {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, path).into());
+        }
+        files.push(BackupFile { path, size, sha256: hex::encode(reader.hasher.finalize()) });
+    }
+
+    let manifest = BackupManifest {
+        format: BACKUP_FORMAT.into(),
+        version: BACKUP_VERSION,
+        created: Utc::now(),
+        files,
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)?;
+    out.append_data(&mut file_header(manifest_json.len() as u64), MANIFEST, manifest_json.as_slice())?;
+    out.into_inner()?.finish()?.flush()?;
+    info!("backup done, {} files", manifest.files.len());
+    Ok(manifest)
+}
+
+/**
+ * archive into an empty instance
+ * - everything is verified before anything is restored
+ * - all pages are invalid, they are rendered again on the first request
+ */
+pub async fn restore(state: &TheState, archive: &Path, media_dir: &Path) -> Result<BackupManifest, BackupError> {
+    info!("restore: {}", archive.display());
+    if !state.dbs.is_empty().await? {
+        return Err(NotEmpty);
+    }
+    let staging = staging_dir("restore")?;
+    let result = restore_staged(state, archive, media_dir, &staging).await;
+    fs::remove_dir_all(&staging)?;
+    result
+}
+
+async fn restore_staged(
+    state: &TheState,
+    archive: &Path,
+    media_dir: &Path,
+    staging: &Path,
+) -> Result<BackupManifest, BackupError> {
+    let extracted = extract(archive, staging)?;
+    let manifest = verify(staging, &extracted)?;
+
+    state.dbs.import_database(&staging.join(DATABASE)).await?;
+    for file in &manifest.files {
+        if let Some(relative) = file.path.strip_prefix(&format!("{}/", MEDIA)) {
+            let target = media_dir.join(relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(staging.join(&file.path), target)?;
+        }
+    }
+
+    state.dbs.invalidate_all_article().await?;
+    state.dv.invalidate_index_and_categories();
+    for gone in state.dbs.gone_article_file_names().await? {
+        article::render_article_gone(&gone)?;
+    }
+    info!("restore done, {} files", manifest.files.len());
+    Ok(manifest)
+}
+
+/*
+ * returns archive paths of the extracted files
+ */
+fn extract(archive: &Path, staging: &Path) -> Result<Vec<String>, BackupError> {
+    let mut input = Archive::new(GzDecoder::new(BufReader::new(File::open(archive)?)));
+    let mut extracted = Vec::new();
+    for entry in input.entries()? {
+        let mut entry = entry?;
+        let path = archive_path(&entry.path()?);
+        // a backup has regular files only, no links or directories
+        if !entry.header().entry_type().is_file() || !is_safe(&path) {
+            return Err(UnexpectedFile(path));
+        }
+        let target = staging.join(&path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        io::copy(&mut entry, &mut File::create(target)?)?;
+        extracted.push(path);
+    }
+    Ok(extracted)
+}
+
+fn verify(staging: &Path, extracted: &[String]) -> Result<BackupManifest, BackupError> {
+    if !extracted.iter().any(|path| path == MANIFEST) {
+        return Err(ManifestMissing);
+    }
+    let manifest: BackupManifest = serde_json::from_slice(&fs::read(staging.join(MANIFEST))?)?;
+    if manifest.format != BACKUP_FORMAT || manifest.version != BACKUP_VERSION {
+        return Err(UnsupportedVersion(manifest.format, manifest.version));
+    }
+    if let Some(unexpected) =
+        extracted.iter().find(|path| *path != MANIFEST && !manifest.files.iter().any(|file| file.path == **path))
+    {
+        return Err(UnexpectedFile(unexpected.clone()));
+    }
+    if !manifest.files.iter().any(|file| file.path == DATABASE) {
+        return Err(MissingFile(DATABASE.into()));
+    }
+    for file in &manifest.files {
+        if !extracted.contains(&file.path) {
+            return Err(MissingFile(file.path.clone()));
+        }
+        let data = fs::read(staging.join(&file.path))?;
+        if data.len() as u64 != file.size || hex::encode(Sha256::digest(&data)) != file.sha256 {
+            return Err(ChecksumMismatch(file.path.clone()));
+        }
+    }
+    Ok(manifest)
+}
+
+/*
+ * only the files a backup writes, nothing outside of the staging directory
+ */
+fn is_safe(path: &str) -> bool {
+    let inside = Path::new(path).components().all(|component| matches!(component, Component::Normal(_)));
+    inside && (path == MANIFEST || path == DATABASE || path.starts_with(&format!("{}/", MEDIA)))
+}
+
+fn media_files(dir: &Path) -> Result<Vec<PathBuf>, BackupError> {
+    let mut files = Vec::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(media_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn file_header(size: u64) -> Header {
+    let mut header = Header::new_gnu();
+    header.set_size(size);
+    header.set_mode(0o644);
+    header
+}
+
+fn archive_path(path: &Path) -> String {
+    path.components().map(|component| component.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/")
+}
+
+fn staging_dir(purpose: &str) -> Result<PathBuf, BackupError> {
+    let dir = std::env::temp_dir().join(format!(
+        "axiomatik-{}-{}-{}",
+        purpose,
+        process::id(),
+        Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_safe() {
+        assert!(is_safe("manifest.json"));
+        assert!(is_safe("database.surql"));
+        assert!(is_safe("media/article_image_50.png"));
+        assert!(!is_safe("media/../../etc/passwd"));
+        assert!(!is_safe("/etc/passwd"));
+        assert!(!is_safe("web/index.html"));
+    }
+
+    #[test]
+    fn test_extract() {
+        let staging = staging_dir("test-extract").unwrap();
+        let archive = staging.join("archive.tar.gz");
+        let long_path = format!("media/{}_image_820.png", "a".repeat(120));
+        let mut out = Builder::new(GzEncoder::new(File::create(&archive).unwrap(), Compression::default()));
+        out.append_data(&mut file_header(3), &long_path, b"png".as_slice()).unwrap();
+        out.append_data(&mut file_header(2), MANIFEST, b"{}".as_slice()).unwrap();
+        out.into_inner().unwrap().finish().unwrap();
+        let extracted = extract(&archive, &staging.join("ok")).unwrap();
+        assert_eq!(extracted, [long_path.clone(), MANIFEST.to_string()]);
+        assert_eq!(fs::read(staging.join("ok").join(&long_path)).unwrap(), b"png");
+
+        // a link could point anywhere
+        let mut out = Builder::new(GzEncoder::new(File::create(&archive).unwrap(), Compression::default()));
+        let mut link = Header::new_gnu();
+        link.set_entry_type(tar::EntryType::Symlink);
+        link.set_size(0);
+        out.append_link(&mut link, "media/passwd", "/etc/passwd").unwrap();
+        out.into_inner().unwrap().finish().unwrap();
+        assert!(matches!(extract(&archive, &staging.join("link")), Err(UnexpectedFile(_))));
+
+        fs::remove_dir_all(&staging).unwrap();
+    }
+
+    #[test]
+    fn test_verify() {
+        let staging = staging_dir("test-verify").unwrap();
+        fs::write(staging.join(DATABASE), "DEFINE TABLE article;").unwrap();
+        let mut manifest = BackupManifest {
+            format: BACKUP_FORMAT.into(),
+            version: BACKUP_VERSION,
+            created: Utc::now(),
+            files: vec![BackupFile {
+                path: DATABASE.into(),
+                size: 21,
+                sha256: hex::encode(Sha256::digest(b"DEFINE TABLE article;")),
+            }],
+        };
+        let extracted = vec![DATABASE.to_string(), MANIFEST.to_string()];
+        fs::write(staging.join(MANIFEST), serde_json::to_vec(&manifest).unwrap()).unwrap();
+        assert!(verify(&staging, &extracted).is_ok());
+
+        assert!(matches!(verify(&staging, &extracted[..1]), Err(ManifestMissing)));
+        let with_extra = vec![DATABASE.to_string(), MANIFEST.to_string(), "media/extra.png".to_string()];
+        assert!(matches!(verify(&staging, &with_extra), Err(UnexpectedFile(_))));
+
+        manifest.files[0].sha256 = hex::encode(Sha256::digest(b"something else"));
+        fs::write(staging.join(MANIFEST), serde_json::to_vec(&manifest).unwrap()).unwrap();
+        assert!(matches!(verify(&staging, &extracted), Err(ChecksumMismatch(_))));
+
+        manifest.version = BACKUP_VERSION + 1;
+        fs::write(staging.join(MANIFEST), serde_json::to_vec(&manifest).unwrap()).unwrap();
+        assert!(matches!(verify(&staging, &extracted), Err(UnsupportedVersion(_, _))));
+
+        fs::remove_dir_all(&staging).unwrap();
+    }
+}
@@ -29,6 +29,8 @@ pub async fn create_admin_user(state: &TheState) -> Result<(), CommandError> {
         username: username.to_string(),
         author_name: username.to_string(),
         password_hash,
+        bio: String::new(),
+        photo_path: String::new(),
         needs_password_change: true,
         role: Role::Admin,
     };
@@ -1,4 +1,5 @@
 use crate::data::library;
+use crate::db::database_system::BreakingNews;
 use crate::feature::{name_days, weather};
 use chrono::{DateTime, Utc};
 use parking_lot::RwLock;
@@ -14,6 +15,8 @@ pub struct DataSystem {
     date: RwLock<String>,
     name_day: RwLock<String>,
     weather: RwLock<String>,
+    // copy of the database record, rendered into every page
+    breaking_news: RwLock<Option<BreakingNews>>,
 
     date_last_update: RwLock<DateTime<Utc>>,
     weather_last_update: RwLock<DateTime<Utc>>,
@@ -25,6 +28,7 @@ pub fn new() -> DataSystem {
         date: RwLock::new("".into()),
         name_day: RwLock::new("".into()),
         weather: RwLock::new("".into()),
+        breaking_news: RwLock::new(None),
         date_last_update: RwLock::new(now),
         weather_last_update: RwLock::new(now),
     }
@@ -43,6 +47,14 @@ impl DataSystem {
         self.weather.read().clone()
     }
 
+    pub fn breaking_news(&self) -> Option<BreakingNews> {
+        self.breaking_news.read().clone()
+    }
+
+    pub fn set_breaking_news(&self, news: Option<BreakingNews>) {
+        *self.breaking_news.write() = news;
+    }
+
     pub fn date_last_update(&self) -> DateTime<Utc> {
         *self.date_last_update.read()
     }
@@ -1,4 +1,5 @@
 use parking_lot::RwLock;
+use std::collections::HashSet;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -14,22 +15,23 @@ pub enum DataUpdatesError {
 pub struct DataValidHtml {
     index_valid: RwLock<bool>,
     news_valid: RwLock<bool>,
-    finance_valid: RwLock<bool>,
-    republika_valid: RwLock<bool>,
-    technologie_valid: RwLock<bool>,
-    veda_valid: RwLock<bool>,
-    zahranici_valid: RwLock<bool>,
+    corrections_valid: RwLock<bool>,
+    // category slug and page number of a valid HTML file
+    categories_valid: RwLock<HashSet<(String, u32)>>,
+    // username and page number of a valid author HTML file
+    authors_valid: RwLock<HashSet<(String, u32)>>,
+    // year and URL of a valid archive HTML file
+    archive_valid: RwLock<HashSet<(i32, String)>>,
 }
 
-pub const fn new() -> DataValidHtml {
+pub fn new() -> DataValidHtml {
     DataValidHtml {
         index_valid: RwLock::new(false),
         news_valid: RwLock::new(false),
-        finance_valid: RwLock::new(false),
-        republika_valid: RwLock::new(false),
-        technologie_valid: RwLock::new(false),
-        veda_valid: RwLock::new(false),
-        zahranici_valid: RwLock::new(false),
+        corrections_valid: RwLock::new(false),
+        categories_valid: RwLock::new(HashSet::new()),
+        authors_valid: RwLock::new(HashSet::new()),
+        archive_valid: RwLock::new(HashSet::new()),
     }
 }
 
@@ -45,17 +47,6 @@ impl DataValidHtml {
         *self.index_valid.write() = false;
     }
 
-    // republika
-    pub fn republika_valid(&self) -> bool {
-        *self.republika_valid.read()
-    }
-    pub fn republika_validate(&self) {
-        *self.republika_valid.write() = true;
-    }
-    pub fn republika_invalidate(&self) {
-        *self.republika_valid.write() = false;
-    }
-
     // news
     pub fn news_valid(&self) -> bool {
         *self.news_valid.read()
@@ -67,50 +58,56 @@ impl DataValidHtml {
         *self.news_valid.write() = false;
     }
 
-    // finance
-    pub fn finance_valid(&self) -> bool {
-        *self.finance_valid.read()
+    // corrections
+    pub fn corrections_valid(&self) -> bool {
+        *self.corrections_valid.read()
     }
-    pub fn finance_validate(&self) {
-        *self.finance_valid.write() = true;
+    pub fn corrections_validate(&self) {
+        *self.corrections_valid.write() = true;
     }
-    pub fn finance_invalidate(&self) {
-        *self.finance_valid.write() = false;
+    pub fn corrections_invalidate(&self) {
+        *self.corrections_valid.write() = false;
     }
 
-    // technologie
-    pub fn technologie_valid(&self) -> bool {
-        *self.technologie_valid.read()
+    // category page by slug and page number
+    pub fn category_valid(&self, slug: &str, page: u32) -> bool {
+        self.categories_valid.read().contains(&(slug.to_string(), page))
+    }
+    pub fn category_validate(&self, slug: &str, page: u32) {
+        self.categories_valid.write().insert((slug.to_string(), page));
     }
-    pub fn technologie_validate(&self) {
-        *self.technologie_valid.write() = true;
+    pub fn category_page_invalidate(&self, slug: &str, page: u32) {
+        self.categories_valid.write().remove(&(slug.to_string(), page));
     }
-    pub fn technologie_invalidate(&self) {
-        *self.technologie_valid.write() = false;
+    // all pages of the category
+    pub fn category_invalidate(&self, slug: &str) {
+        self.categories_valid.write().retain(|(s, _)| s != slug);
     }
 
-    // veda
-    pub fn veda_valid(&self) -> bool {
-        *self.veda_valid.read()
+    // author page by username and page number
+    pub fn author_valid(&self, username: &str, page: u32) -> bool {
+        self.authors_valid.read().contains(&(username.to_string(), page))
     }
-    pub fn veda_validate(&self) {
-        *self.veda_valid.write() = true;
+    pub fn author_validate(&self, username: &str, page: u32) {
+        self.authors_valid.write().insert((username.to_string(), page));
     }
-    pub fn veda_invalidate(&self) {
-        *self.veda_valid.write() = false;
+    // all pages of the author
+    pub fn author_invalidate(&self, username: &str) {
+        self.authors_valid.write().retain(|(u, _)| u != username);
     }
 
-    // zahranici
-    pub fn zahranici_valid(&self) -> bool {
-        *self.zahranici_valid.read()
+    // archive page by year and URL
+    pub fn archive_valid(&self, year: i32, url: &str) -> bool {
+        self.archive_valid.read().contains(&(year, url.to_string()))
     }
-    pub fn zahranici_validate(&self) {
-        *self.zahranici_valid.write() = true;
+    pub fn archive_validate(&self, year: i32, url: &str) {
+        self.archive_valid.write().insert((year, url.to_string()));
     }
-    pub fn zahranici_invalidate(&self) {
-        *self.zahranici_valid.write() = false;
+    // all pages of the year, each of them shows the calendar of the year
+    pub fn archive_year_invalidate(&self, year: i32) {
+        self.archive_valid.write().retain(|(y, _)| *y != year);
     }
-    
+
     /**
      * used when global state changed
      * e.g.: a date was updated by midnight worker
@@ -118,12 +115,11 @@ impl DataValidHtml {
     pub fn invalidate_index_and_categories(&self) {
         self.index_invalidate();
         self.news_invalidate();
-        
-        self.republika_invalidate();
-        self.finance_invalidate();
-        self.technologie_invalidate();
-        self.veda_invalidate();
-        self.zahranici_invalidate();
+        self.corrections_invalidate();
+
+        self.categories_valid.write().clear();
+        self.authors_valid.write().clear();
+        self.archive_valid.write().clear();
     }
 }
 
@@ -136,11 +132,52 @@ mod tests {
         let du = new();
         assert!(!du.index_valid());
         assert!(!du.news_valid());
-        assert!(!du.finance_valid());
-        assert!(!du.republika_valid());
-        assert!(!du.technologie_valid());
-        assert!(!du.veda_valid());
-        assert!(!du.zahranici_valid());
+        assert!(!du.corrections_valid());
+        assert!(!du.category_valid("veda", 1));
+    }
+
+    #[test]
+    fn test_category_validation() {
+        let du = new();
+        du.category_validate("veda", 1);
+        du.category_validate("veda", 2);
+        du.category_validate("sport", 1);
+        assert!(du.category_valid("veda", 2));
+        du.category_page_invalidate("veda", 2);
+        assert!(!du.category_valid("veda", 2));
+        assert!(du.category_valid("veda", 1));
+        du.category_invalidate("veda");
+        assert!(!du.category_valid("veda", 1));
+        assert!(du.category_valid("sport", 1));
+        du.invalidate_index_and_categories();
+        assert!(!du.category_valid("sport", 1));
+    }
+
+    #[test]
+    fn test_author_validation() {
+        let du = new();
+        du.author_validate("editor", 1);
+        du.author_validate("editor", 2);
+        du.author_validate("other", 1);
+        assert!(du.author_valid("editor", 2));
+        du.author_invalidate("editor");
+        assert!(!du.author_valid("editor", 1));
+        assert!(!du.author_valid("editor", 2));
+        assert!(du.author_valid("other", 1));
+    }
+
+    #[test]
+    fn test_archive_validation() {
+        let du = new();
+        du.archive_validate(2025, "/archiv/2025.html");
+        du.archive_validate(2025, "/archiv/2025/11/17.html");
+        du.archive_validate(2024, "/archiv/2024.html");
+        assert!(du.archive_valid(2025, "/archiv/2025/11/17.html"));
+        du.archive_year_invalidate(2025);
+        assert!(!du.archive_valid(2025, "/archiv/2025.html"));
+        assert!(du.archive_valid(2024, "/archiv/2024.html"));
+        du.invalidate_index_and_categories();
+        assert!(!du.archive_valid(2024, "/archiv/2024.html"));
     }
 
     #[test]
@@ -0,0 +1,433 @@
+/*
+ * bulk import of Articles from the previous system
+ * - a directory of .json or .md bundles, media files are next to them
+ * - run with the server stopped: axiomatik-web import <directory>
+ * - an already imported bundle is skipped, the import can be run again
+ * - a bundle is recognized by its source_id, or by the title and the creation time without it
+ */
+
+use crate::application::form_create_article::create_article;
+use crate::application::form_create_article::create_article_parser;
+use crate::application::form_create_article::create_article_parser::{ArticleCreateError, ArticleUpload};
+use crate::application::page_article::article::ArticleError;
+use crate::data::audio_processor::AudioProcessorError;
+use crate::data::image_processor::ImageProcessorError;
+use crate::data::video_processor::VideoProcessorError;
+use crate::data::{audio_processor, image_processor, slug, text_processor, video_processor};
+use crate::db::database::SurrealError;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_article_data::{Article, PublishState};
+use crate::db::database_category::SurrealCategoryError;
+use crate::db::database_system::SurrealSystemError;
+use crate::system::server::TheState;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use thiserror::Error;
+use tracing::{info, warn};
+use ImportError::{FrontMatterMissing, InvalidFrontMatter, MediaExtensionMissing, UnknownCategory, UnsafeMediaPath};
+
+// the previous system didn't know our users
+const IMPORT_USERNAME: &str = "import";
+
+#[derive(Debug, Error)]
+pub enum ImportError {
+    #[error("io error {0}")]
+    ImportIo(#[from] std::io::Error),
+
+    #[error("invalid bundle {0}")]
+    ImportJson(#[from] serde_json::Error),
+
+    #[error("front matter missing, the file must start with ---")]
+    FrontMatterMissing,
+
+    #[error("invalid front matter line {0}")]
+    InvalidFrontMatter(String),
+
+    #[error("unknown category {0}")]
+    UnknownCategory(String),
+
+    #[error("media file {0} without extension")]
+    MediaExtensionMissing(String),
+
+    #[error("media file {0} outside of the bundle directory")]
+    UnsafeMediaPath(String),
+
+    #[error("related article {0} not found")]
+    UnknownRelatedArticle(String),
+
+    #[error("invalid text: {0}")]
+    ImportInvalidText(#[from] ArticleCreateError),
+
+    #[error("image processing failed: {0}")]
+    ImportImageProcessor(#[from] ImageProcessorError),
+
+    #[error("audio processing failed: {0}")]
+    ImportAudioProcessor(#[from] AudioProcessorError),
+
+    #[error("video processing failed: {0}")]
+    ImportVideoProcessor(#[from] VideoProcessorError),
+
+    #[error("surreal error {0}")]
+    ImportSurreal(#[from] SurrealError),
+
+    #[error("surreal article error {0}")]
+    ImportSurrealArticle(#[from] SurrealArticleError),
+
+    #[error("surreal system error {0}")]
+    ImportSurrealSystem(#[from] SurrealSystemError),
+
+    #[error("surreal category error {0}")]
+    ImportSurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("publish failed: {0}")]
+    ImportPublish(#[from] ArticleError),
+}
+
+/**
+ * one Article of the previous system
+ * - media are file names relative to the bundle directory
+ * - text is in the same raw format as typed into the form
+ */
+#[derive(Debug, Deserialize)]
+pub struct ImportBundle {
+    // id of the Article in the previous system
+    pub source_id: Option<String>,
+    pub title: String,
+    pub author: String,
+    pub username: Option<String>,
+    pub category: String,
+    // RFC 3339
+    pub created: DateTime<Utc>,
+    pub text: String,
+    pub short_text: String,
+    pub mini_text: Option<String>,
+    pub image: String,
+    pub image_desc: String,
+    // the old system had no alt text, the description is used
+    pub image_alt: Option<String>,
+    #[serde(default)]
+    pub image_credit: String,
+    #[serde(default)]
+    pub image_source: String,
+    pub video: Option<String>,
+    pub audio: Option<String>,
+    #[serde(default)]
+    pub related_articles: Vec<String>,
+    #[serde(default)]
+    pub is_main: bool,
+    #[serde(default)]
+    pub is_exclusive: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    // article file names
+    pub imported: Vec<String>,
+    pub skipped: Vec<String>,
+    // bundle file name and the reason
+    pub failed: Vec<(String, String)>,
+}
+
+impl ImportReport {
+    pub fn log(&self) {
+        info!("imported {}, skipped {}, failed {}", self.imported.len(), self.skipped.len(), self.failed.len());
+        for (bundle, reason) in &self.failed {
+            warn!("failed {}: {}", bundle, reason);
+        }
+    }
+}
+
+enum Imported {
+    New(Box<Article>, Vec<String>),
+    // related articles are linked again, they may have been imported since
+    Existing(Box<Article>, Vec<String>),
+}
+
+/**
+ * import every bundle of the directory, a failed bundle doesn't stop the others
+ */
+pub async fn import_directory(state: &TheState, dir: &Path) -> Result<ImportReport, ImportError> {
+    info!("import_directory: {}", dir.display());
+    let mut bundles: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| matches!(extension(path).as_deref(), Some("json" | "md")))
+        .collect();
+    bundles.sort();
+    fs::create_dir_all("web/u")?;
+
+    let mut report = ImportReport::default();
+    let mut to_relate = Vec::new();
+    for path in &bundles {
+        let bundle_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        match import_bundle(state, dir, path).await {
+            Ok(Imported::New(article, related)) => {
+                info!("imported {} as {}", bundle_name, article.article_file_name);
+                report.imported.push(article.article_file_name.clone());
+                to_relate.push((bundle_name, article, related));
+            }
+            Ok(Imported::Existing(article, related)) => {
+                info!("skipped {}, {} already exists", bundle_name, article.article_file_name);
+                report.skipped.push(article.article_file_name.clone());
+                to_relate.push((bundle_name, article, related));
+            }
+            Err(e) => {
+                warn!("failed {}: {}", bundle_name, e);
+                report.failed.push((bundle_name, e.to_string()));
+            }
+        }
+    }
+
+    // bundles refer to each other in any order, relate them once all exist
+    for (bundle_name, article, related) in to_relate {
+        if let Err(e) = relate(state, &article, &related).await {
+            warn!("failed {}: {}", bundle_name, e);
+            report.failed.push((bundle_name, e.to_string()));
+        }
+    }
+    Ok(report)
+}
+
+async fn import_bundle(state: &TheState, dir: &Path, path: &Path) -> Result<Imported, ImportError> {
+    let content = fs::read_to_string(path)?;
+    let bundle: ImportBundle = if extension(path).as_deref() == Some("json") {
+        serde_json::from_str(&content)?
+    } else {
+        parse_markdown(&content)?
+    };
+
+    let source = bundle.source();
+    if let Some(article) = imported_article(state, &source).await? {
+        return Ok(Imported::Existing(Box::new(article), bundle.related_articles));
+    }
+    if state.dbc.category_by_slug(&bundle.category).await?.is_none() {
+        return Err(UnknownCategory(bundle.category));
+    }
+    // the same rules as the create form, a bundle has no inline images and no sources
+    create_article_parser::validate_embeds(&bundle.text)?;
+    create_article_parser::validate_image_references(&bundle.text, &[])?;
+    create_article_parser::validate_footnotes(&bundle.text, &[])?;
+
+    // another Article may have the same title
+    let base_file_name = state.dba.unique_base_file_name(&slug::slugify(&bundle.title), None).await?;
+
+    let (image_data, image_ext) = read_media(dir, &bundle.image)?;
+    let (video_data, video_ext) =
+        bundle.video.as_deref().map(|video| read_media(dir, video)).transpose()?.unwrap_or_default();
+    let (audio_data, audio_ext) =
+        bundle.audio.as_deref().map(|audio| read_media(dir, audio)).transpose()?.unwrap_or_default();
+
+    // links to Articles not imported yet stay plain text
+    let linked = state.dba.linked_articles(&text_processor::internal_links(&bundle.text)).await?;
+    let internal_links = linked.into_iter().map(|a| (a.article_file_name, a.title)).collect();
+
+    let upload = ArticleUpload {
+        is_main: bundle.is_main,
+        is_exclusive: bundle.is_exclusive,
+        is_live_blog: false,
+        author: bundle.author,
+        username: bundle.username.unwrap_or_else(|| IMPORT_USERNAME.into()),
+        title: bundle.title,
+        text_raw: bundle.text,
+        mini_text_raw: bundle.mini_text.unwrap_or_else(|| bundle.short_text.clone()),
+        short_text_raw: bundle.short_text,
+        category: bundle.category,
+        image_alt: bundle.image_alt.unwrap_or_else(|| bundle.image_desc.clone()),
+        image_credit: bundle.image_credit,
+        image_source: bundle.image_source,
+        image_desc: bundle.image_desc,
+        image_ext,
+        image_data,
+        has_video: bundle.video.is_some(),
+        video_data,
+        video_ext,
+        has_audio: bundle.audio.is_some(),
+        audio_data,
+        audio_ext,
+        images: Vec::new(),
+        sources: Vec::new(),
+        related_articles: Vec::new(),
+        internal_links,
+        base_file_name,
+        publish_state: PublishState::Published,
+        publish_at: None,
+        expires_at: None,
+    };
+
+    /*
+     * the same processing as the create form
+     */
+    image_processor::process_images(&upload.image_data, &upload.base_file_name, &upload.image_ext)?;
+    if upload.has_audio {
+        audio_processor::process_valid_audio(
+            &upload.audio_data,
+            &format!("{}_audio.{}", upload.base_file_name, upload.audio_ext),
+        )?;
+    }
+    if upload.has_video {
+        video_processor::process_video(
+            &upload.video_data,
+            &format!("{}_video.{}", upload.base_file_name, upload.video_ext),
+        )?;
+    }
+
+    // the previous system didn't track changes
+    let article = Article {
+        created: bundle.created,
+        updated: bundle.created,
+        ..Article::try_from(upload)?
+    };
+    state.dba.create_article(article.clone()).await?;
+    state.dbs.write_import_source(&source, article.uuid).await?;
+    create_article::publish_article(state, &article).await?;
+    Ok(Imported::New(Box::new(article), bundle.related_articles))
+}
+
+impl ImportBundle {
+    fn source(&self) -> String {
+        self.source_id.clone().unwrap_or_else(|| format!("{}|{}", self.title, self.created.to_rfc3339()))
+    }
+}
+
+/*
+ * a deleted Article is imported again
+ */
+async fn imported_article(state: &TheState, source: &str) -> Result<Option<Article>, ImportError> {
+    let Some(uuid) = state.dbs.read_import_source(source).await? else {
+        return Ok(None);
+    };
+    match state.dba.article_file_names_by_ids(&[uuid]).await?.first() {
+        Some(article_file_name) => Ok(state.dba.article_by_file_name_optional(article_file_name).await?),
+        None => Ok(None),
+    }
+}
+
+/*
+ * known related articles are linked both ways, the unknown are reported
+ */
+async fn relate(state: &TheState, article: &Article, related: &[String]) -> Result<(), ImportError> {
+    if related.is_empty() {
+        return Ok(());
+    }
+    let existing = state.dba.existing_article_file_names(related).await?;
+    for related_id in state.dba.article_ids_by_file_names(&existing).await? {
+        state.dba.add_related_article(article.uuid, related_id).await?;
+        state.dba.add_related_article(related_id, article.uuid).await?;
+    }
+    for related_article in &existing {
+        state.dbs.invalidate_article(related_article.clone()).await?;
+    }
+
+    let unknown: Vec<&str> = related.iter().filter(|name| !existing.contains(name)).map(String::as_str).collect();
+    if unknown.is_empty() { Ok(()) } else { Err(ImportError::UnknownRelatedArticle(unknown.join(", "))) }
+}
+
+/**
+ * front matter of "key: value" lines between --- lines, the rest is the text
+ * - related_articles are comma separated
+ */
+pub fn parse_markdown(content: &str) -> Result<ImportBundle, ImportError> {
+    let content = content.replace("\r\n", "\n");
+    let rest = content.strip_prefix("---\n").ok_or(FrontMatterMissing)?;
+    let (front_matter, text) = rest
+        .split_once("\n---\n")
+        .or_else(|| rest.strip_suffix("\n---").map(|front_matter| (front_matter, "")))
+        .ok_or(FrontMatterMissing)?;
+
+    let mut fields = serde_json::Map::new();
+    for line in front_matter.lines().filter(|line| !line.trim().is_empty()) {
+        let (key, value) = line.split_once(':').ok_or_else(|| InvalidFrontMatter(line.into()))?;
+        let (key, value) = (key.trim(), value.trim());
+        let value = match key {
+            "is_main" | "is_exclusive" => {
+                Value::Bool(value.parse().map_err(|_| InvalidFrontMatter(line.into()))?)
+            }
+            "related_articles" => Value::Array(
+                value.split(',').map(str::trim).filter(|s| !s.is_empty()).map(|s| Value::String(s.into())).collect(),
+            ),
+            _ => Value::String(value.into()),
+        };
+        fields.insert(key.into(), value);
+    }
+    fields.insert("text".into(), Value::String(text.trim().into()));
+    Ok(serde_json::from_value(Value::Object(fields))?)
+}
+
+/*
+ * media names come from the bundle, they must not leave its directory
+ */
+fn read_media(dir: &Path, file_name: &str) -> Result<(Vec<u8>, String), ImportError> {
+    let relative = Path::new(file_name);
+    if !relative.components().all(|component| matches!(component, Component::Normal(_) | Component::CurDir)) {
+        return Err(UnsafeMediaPath(file_name.into()));
+    }
+    let path = dir.join(relative);
+    let ext = extension(&path).ok_or_else(|| MediaExtensionMissing(file_name.into()))?;
+    Ok((fs::read(path)?, ext))
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension().map(|ext| ext.to_string_lossy().to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_markdown() {
+        let content = "---\r\n\
+            title: Old Article\r\n\
+            author: Jan Novák\r\n\
+            category: republika\r\n\
+            created: 2019-05-01T10:00:00Z\r\n\
+            short_text: Short.\r\n\
+            image: old.png\r\n\
+            image_desc: desc: with colon\r\n\
+            related_articles: a.html, b.html\r\n\
+            is_main: true\r\n\
+            ---\r\n\
+            First paragraph.\r\n\r\nSecond paragraph.\r\n";
+        let bundle = parse_markdown(content).unwrap();
+        assert_eq!(bundle.title, "Old Article");
+        assert_eq!(bundle.author, "Jan Novák");
+        assert_eq!(bundle.created.to_rfc3339(), "2019-05-01T10:00:00+00:00");
+        assert_eq!(bundle.image_desc, "desc: with colon");
+        assert_eq!(bundle.related_articles, ["a.html", "b.html"]);
+        assert!(bundle.is_main);
+        assert!(!bundle.is_exclusive);
+        assert_eq!(bundle.username, None);
+        assert_eq!(bundle.text, "First paragraph.\n\nSecond paragraph.");
+    }
+
+    #[test]
+    fn test_parse_markdown_invalid() {
+        assert!(matches!(parse_markdown("title: No front matter"), Err(FrontMatterMissing)));
+        assert!(matches!(parse_markdown("---\ntitle Old\n---\ntext"), Err(InvalidFrontMatter(_))));
+        assert!(matches!(parse_markdown("---\ntitle: Old\n---\ntext"), Err(ImportError::ImportJson(_))));
+    }
+
+    #[test]
+    fn test_read_media_outside() {
+        let dir = Path::new("tests/data");
+        assert!(matches!(read_media(dir, "../data/image_1024.png"), Err(UnsafeMediaPath(_))));
+        assert!(matches!(read_media(dir, "/etc/passwd"), Err(UnsafeMediaPath(_))));
+        assert!(matches!(read_media(dir, "image_1024"), Err(MediaExtensionMissing(_))));
+        let (data, ext) = read_media(dir, "./image_1024.png").unwrap();
+        assert!(!data.is_empty());
+        assert_eq!(ext, "png");
+    }
+
+    #[test]
+    fn test_bundle_source() {
+        let mut bundle = parse_markdown("---\ntitle: Old\nauthor: A\ncategory: republika\n\
+            created: 2019-05-01T10:00:00Z\nshort_text: S\nimage: old.png\nimage_desc: D\n---\ntext")
+            .unwrap();
+        assert_eq!(bundle.source(), "Old|2019-05-01T10:00:00+00:00");
+        bundle.source_id = Some("old-42".into());
+        assert_eq!(bundle.source(), "old-42");
+    }
+}
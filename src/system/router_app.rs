@@ -1,24 +1,44 @@
 use crate::application::admin_form_article::admin_article;
 use crate::application::admin_form_article::admin_article::AdminArticleError;
+use crate::application::admin_form_breaking_news::admin_breaking_news;
+use crate::application::admin_form_breaking_news::admin_breaking_news::AdminBreakingNewsError;
+use crate::application::admin_form_category::admin_category;
+use crate::application::admin_form_category::admin_category::AdminCategoryError;
+use crate::application::admin_form_image_sources::admin_image_sources;
+use crate::application::admin_form_image_sources::admin_image_sources::AdminImageSourcesError;
 use crate::application::admin_form_user::admin_user;
 use crate::application::admin_form_user::admin_user::AdminUserError;
-use crate::application::category_finance::finance::FinanceError;
-use crate::application::category_republika::republika::RepublikaError;
-use crate::application::category_technologie::technologie::TechnologieError;
-use crate::application::category_veda::veda::VedaError;
-use crate::application::category_zahranici::zahranici::ZahraniciError;
 use crate::application::form_account::account;
 use crate::application::form_account::account::AccountError;
+use crate::application::form_article_revisions::article_revisions;
+use crate::application::form_article_revisions::article_revisions::ArticleRevisionsError;
 use crate::application::form_change_password;
 use crate::application::form_change_password::change_password::ChangePasswordError;
+use crate::application::form_correction::correction;
+use crate::application::form_correction::correction::CorrectionError;
 use crate::application::form_create_article::create_article;
 use crate::application::form_create_article::create_article::FormArticleCreateError;
 use crate::application::form_create_article::create_article_parser::ArticleCreateError;
+use crate::application::form_create_article::preview_article;
+use crate::application::form_create_article::preview_article::PreviewArticleError;
+use crate::application::form_create_article::suggest_related;
+use crate::application::form_create_article::suggest_related::SuggestRelatedError;
+use crate::application::form_edit_article::edit_article;
+use crate::application::form_edit_article::edit_article::EditArticleError;
+use crate::application::form_homepage::homepage;
+use crate::application::form_homepage::homepage::HomepageError;
+use crate::application::form_live_blog::live_blog;
+use crate::application::form_live_blog::live_blog::LiveBlogError;
 use crate::application::form_login::login;
+use crate::application::form_review::review;
+use crate::application::form_review::review::ReviewError;
 use crate::application::page_all_news::all_news::NewsError;
 use crate::application::page_article::article::ArticleError;
+use crate::application::page_category::category::CategoryError;
 use crate::application::page_index::index::IndexError;
 use crate::db::database::SurrealError;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_homepage::SurrealHomepageError;
 use crate::db::database_user;
 use crate::system::authentication::Backend;
 use crate::system::data_system::DataSystemError;
@@ -32,7 +52,8 @@ use axum::routing::{get, post};
 use axum::{middleware, Router};
 use axum_core::extract::Request;
 use axum_login::AuthManagerLayerBuilder;
-use database_user::Role::{Admin, Editor};
+use axum::extract::State;
+use database_user::Permission;
 use http::StatusCode;
 use thiserror::Error;
 use tower_http::services::{ServeDir, ServeFile};
@@ -54,23 +75,11 @@ pub enum AppRouterError {
     #[error("index error: {0}")]
     RouterIndexError(#[from] IndexError),
 
-    #[error("finance error: {0}")]
-    RouterFinanceError(#[from] FinanceError),
-
     #[error("news error: {0}")]
     RouterNewsError(#[from] NewsError),
 
-    #[error("republika error: {0}")]
-    RouterRepublikaError(#[from] RepublikaError),
-
-    #[error("technologie error: {0}")]
-    RouterTechnologieError(#[from] TechnologieError),
-
-    #[error("veda error: {0}")]
-    RouterVedaError(#[from] VedaError),
-
-    #[error("zahranici error: {0}")]
-    RouterZahraniciError(#[from] ZahraniciError),
+    #[error("category error: {0}")]
+    RouterCategoryError(#[from] CategoryError),
 
     #[error("article error: {0}")]
     RouterArticleError(#[from] ArticleError),
@@ -81,12 +90,21 @@ pub enum AppRouterError {
     #[error("form error: {0}")]
     RouterForm(#[from] FormArticleCreateError),
 
+    #[error("edit article error: {0}")]
+    RouterEditArticleError(#[from] EditArticleError),
+
+    #[error("article revisions error: {0}")]
+    RouterArticleRevisionsError(#[from] ArticleRevisionsError),
+
     #[error("admin article error: {0}")]
     RouterAdminArticleError(#[from] AdminArticleError),
 
     #[error("admin user error: {0}")]
     RouterAdminUserError(#[from] AdminUserError),
 
+    #[error("admin category error: {0}")]
+    RouterAdminCategoryError(#[from] AdminCategoryError),
+
     #[error("change password error: {0}")]
     RouterChangePasswordError(#[from] ChangePasswordError),
 
@@ -110,8 +128,12 @@ impl IntoResponse for AppRouterError {
 impl IntoResponse for ArticleError {
     fn into_response(self) -> Response {
         match self {
+            Self::ArticleCreate(ArticleCreateError::RelatedArticlesLookup(_)) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+            }
             Self::ArticleCreate(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
             Self::CategoryFailed(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+            Self::PublishForbidden(_) => (StatusCode::FORBIDDEN, self.to_string()).into_response(),
             Self::ImageProcessor(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
             Self::AudioProcessor(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
             Self::VideoProcessor(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
@@ -127,6 +149,63 @@ impl IntoResponse for FormArticleCreateError {
     }
 }
 
+impl IntoResponse for PreviewArticleError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::PreviewForbidden(_) => (StatusCode::FORBIDDEN, self.to_string()).into_response(),
+            Self::PreviewSurrealArticle(SurrealArticleError::ArticleNotFound(_)) => {
+                (StatusCode::NOT_FOUND, self.to_string()).into_response()
+            }
+            Self::PreviewArticleParser(ArticleCreateError::RelatedArticlesLookup(_)) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+            }
+            Self::PreviewCategoryFailed(_) | Self::PreviewArticleParser(_) | Self::PreviewImageProcessor(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+            }
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl IntoResponse for SuggestRelatedError {
+    fn into_response(self) -> Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+    }
+}
+
+impl IntoResponse for EditArticleError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Forbidden(_) | Self::PublishForbidden(_) => {
+                (StatusCode::FORBIDDEN, self.to_string()).into_response()
+            }
+            Self::EditSurrealArticle(SurrealArticleError::ArticleNotFound(_)) => {
+                (StatusCode::NOT_FOUND, self.to_string()).into_response()
+            }
+            Self::EditSurrealArticle(_)
+            | Self::EditArticleParser(ArticleCreateError::RelatedArticlesLookup(_))
+            | Self::EditSurrealSystem(_)
+            | Self::EditPublish(_)
+            | Self::EditRenderError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+            }
+            _ => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl IntoResponse for ArticleRevisionsError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Forbidden(_) => (StatusCode::FORBIDDEN, self.to_string()).into_response(),
+            Self::RevisionsSurrealArticle(SurrealArticleError::RevisionNotFound(_)) => {
+                (StatusCode::NOT_FOUND, self.to_string()).into_response()
+            }
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
+        }
+    }
+}
+
 impl IntoResponse for ChangePasswordError {
     fn into_response(self) -> Response {
         (StatusCode::BAD_REQUEST, self.to_string()).into_response()
@@ -139,6 +218,63 @@ impl IntoResponse for AccountError {
     }
 }
 
+impl IntoResponse for ReviewError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotInReview(_) | Self::CommentRequired => {
+                (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+            }
+            Self::ReviewSurrealArticle(SurrealArticleError::ArticleNotFound(_)) => {
+                (StatusCode::NOT_FOUND, self.to_string()).into_response()
+            }
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl IntoResponse for HomepageError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::HomepageSurreal(SurrealHomepageError::SlotNotFound(_)) => {
+                (StatusCode::NOT_FOUND, self.to_string()).into_response()
+            }
+            Self::HomepageSurreal(_)
+            | Self::HomepageSurrealArticle(_)
+            | Self::HomepageSurrealCategory(_)
+            | Self::HomepageRender(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
+            _ => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl IntoResponse for LiveBlogError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Forbidden(_) => (StatusCode::FORBIDDEN, self.to_string()).into_response(),
+            Self::LiveBlogSurrealArticle(SurrealArticleError::ArticleNotFound(_)) => {
+                (StatusCode::NOT_FOUND, self.to_string()).into_response()
+            }
+            Self::LiveBlogImageProcessor(_)
+            | Self::LiveBlogSurrealArticle(_)
+            | Self::LiveBlogSurrealSystem(_)
+            | Self::LiveBlogRender(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
+            _ => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl IntoResponse for CorrectionError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotPublished(_) | Self::TextRequired => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+            Self::CorrectionSurrealArticle(SurrealArticleError::ArticleNotFound(_)) => {
+                (StatusCode::NOT_FOUND, self.to_string()).into_response()
+            }
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
+        }
+    }
+}
+
 impl IntoResponse for AdminArticleError {
     fn into_response(self) -> Response {
         (StatusCode::BAD_REQUEST, self.to_string()).into_response()
@@ -151,6 +287,32 @@ impl IntoResponse for AdminUserError {
     }
 }
 
+impl IntoResponse for AdminCategoryError {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+    }
+}
+
+impl IntoResponse for AdminImageSourcesError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::InvalidMonth(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl IntoResponse for AdminBreakingNewsError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::BreakingNewsSurrealSystem(_)
+            | Self::BreakingNewsSurrealArticle(_)
+            | Self::BreakingNewsRender(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
+            _ => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+        }
+    }
+}
+
 impl ApplicationRouter {
     pub const fn init(state: TheState) -> Result<Self, AppRouterError> {
         Ok(Self { state })
@@ -174,12 +336,57 @@ impl ApplicationRouter {
         let admin_article_routes = Router::new()
             .route("/", get(admin_article::show_admin_articles))
             .route("/delete/{article_file_name}", post(admin_article::handle_delete_article))
-            .layer(middleware::from_fn(admin_middleware));
+            .layer(middleware::from_fn_with_state(Permission::Administer, permission_middleware));
         let admin_user_routes = Router::new()
             .route("/", get(admin_user::show_admin_users))
             .route("/create", get(admin_user::show_create_user_form).post(admin_user::handle_create_user))
             .route("/delete/{username}", post(admin_user::handle_delete_user))
-            .layer(middleware::from_fn(admin_middleware));
+            .layer(middleware::from_fn_with_state(Permission::Administer, permission_middleware));
+        let admin_category_routes = Router::new()
+            .route("/", get(admin_category::show_admin_categories))
+            .route("/create", get(admin_category::show_create_category_form).post(admin_category::handle_create_category))
+            .route("/edit/{slug}", get(admin_category::show_edit_category_form).post(admin_category::handle_edit_category))
+            .route("/delete/{slug}", post(admin_category::handle_delete_category))
+            .layer(middleware::from_fn_with_state(Permission::Administer, permission_middleware));
+        let admin_breaking_news_routes = Router::new()
+            .route("/", get(admin_breaking_news::show_admin_breaking_news))
+            .route("/set", post(admin_breaking_news::handle_set_breaking_news))
+            .route("/clear", post(admin_breaking_news::handle_clear_breaking_news))
+            .layer(middleware::from_fn_with_state(Permission::Administer, permission_middleware));
+        let admin_image_sources_routes = Router::new()
+            .route("/", get(admin_image_sources::show_admin_image_sources))
+            .layer(middleware::from_fn_with_state(Permission::Administer, permission_middleware));
+
+        /*
+         * review queue
+         */
+        let review_routes = Router::new()
+            .route("/", get(review::show_review_queue))
+            .route("/{article_file_name}/approve", post(review::handle_approve))
+            .route("/{article_file_name}/request-changes", post(review::handle_request_changes))
+            .route("/{article_file_name}/reject", post(review::handle_reject))
+            .layer(middleware::from_fn_with_state(Permission::ReviewArticle, permission_middleware));
+
+        /*
+         * corrections of published Articles
+         */
+        let correction_routes = Router::new()
+            .route("/{article_file_name}",
+                get(correction::show_correction_form)
+               .post(correction::handle_add_correction),
+            )
+            .layer(middleware::from_fn_with_state(Permission::PublishArticle, permission_middleware));
+
+        /*
+         * layout of index.html
+         */
+        let homepage_routes = Router::new()
+            .route("/", get(homepage::show_homepage_layout))
+            .route("/pin", post(homepage::handle_pin))
+            .route("/unpin/{uuid}", post(homepage::handle_unpin))
+            .route("/up/{uuid}", post(homepage::handle_move_up))
+            .route("/down/{uuid}", post(homepage::handle_move_down))
+            .layer(middleware::from_fn_with_state(Permission::ManageHomepage, permission_middleware));
 
         /*
          * protected routes
@@ -188,19 +395,42 @@ impl ApplicationRouter {
             // admin
             .nest("/admin_article", admin_article_routes)
             .nest("/admin_user", admin_user_routes)
+            .nest("/admin_category", admin_category_routes)
+            .nest("/admin_breaking_news", admin_breaking_news_routes)
+            .nest("/admin_image_sources", admin_image_sources_routes)
+            .nest("/review", review_routes)
+            .nest("/homepage", homepage_routes)
+            .nest("/correction", correction_routes)
             // application
             .route("/logout", get(login::handle_logout))
             .route("/form", get(create_article::show_article_create_form))
             .route("/create", post(create_article::create_article))
+            .route("/preview", post(preview_article::handle_preview_article))
+            .route("/suggest-related", post(suggest_related::handle_suggest_related))
+            .route("/edit/{article_file_name}",
+                get(edit_article::show_article_edit_form)
+               .post(edit_article::edit_article),
+            )
+            .route("/live-blog/{article_file_name}",
+                get(live_blog::show_live_blog_form)
+               .post(live_blog::handle_add_entry),
+            )
+            .route("/revisions/{article_file_name}", get(article_revisions::show_article_revisions))
+            .route("/revisions/{article_file_name}/diff", get(article_revisions::show_article_diff))
+            .route("/revisions/{article_file_name}/restore/{revision_id}",
+                post(article_revisions::restore_article_revision),
+            )
             .route("/change-password",
                 get(form_change_password::change_password::show_change_password)
                .post(form_change_password::change_password::handle_change_password),
             )
             .route("/account", get(account::show_account))
             .route("/account/update-author", post(account::handle_update_author_name))
+            .route("/account/update-bio", post(account::handle_update_bio))
+            .route("/account/update-photo", post(account::handle_update_photo))
             // system
             .route("/health", get(health::handle_health))
-            .layer(middleware::from_fn(auth_middleware));
+            .layer(middleware::from_fn_with_state(Permission::WriteArticle, permission_middleware));
 
         /*
          * unprotected routes
@@ -231,50 +461,33 @@ impl ApplicationRouter {
     }
 }
 
-async fn auth_middleware(auth_session: AuthSession, req: Request<Body>, next: Next) -> Response {
-    debug!("auth_middleware");
-    match auth_session.user {
-        Some(user) => {
-            // change password
-            if user.needs_password_change && req.uri().path() != "/change-password" {
-                info!("auth_middleware: needs_password_change redirect");
-                return Redirect::to("/change-password").into_response();
-            }
-
-            // continue
-            if user.role == Editor || user.role == Admin {
-                info!("auth_middleware: role={:?}, continue", user.role);
-                return next.run(req).await;
-            }
-            info!("auth_middleware: role NOT Editor/Admin: {:?}", user.role);
-        }
-        None => {
-            info!("auth_middleware: No user in session");
-            return Redirect::to("/login").into_response();
-        }
+/**
+ * every protected route requires a permission of the user's role
+ * - without a user in session, login first
+ * - the password has to be changed before anything else
+ */
+async fn permission_middleware(
+    State(permission): State<Permission>,
+    auth_session: AuthSession,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    debug!("permission_middleware: {:?}", permission);
+    let Some(user) = auth_session.user else {
+        info!("permission_middleware: No user in session");
+        return Redirect::to("/login").into_response();
+    };
+
+    if user.needs_password_change && req.uri().path() != "/change-password" {
+        info!("permission_middleware: needs_password_change redirect");
+        return Redirect::to("/change-password").into_response();
     }
 
-    // login
-    info!("auth_middleware: default redirect to /login");
-    Redirect::to("/login").into_response()
-}
-
-async fn admin_middleware(auth_session: AuthSession, req: Request<Body>, next: Next) -> Response {
-    debug!("admin_middleware");
-    match auth_session.user {
-        Some(user) => {
-            if user.role == Admin {
-                info!("admin_middleware: role=Admin, continue");
-                return next.run(req).await;
-            }
-            info!("admin_middleware: role NOT Admin: {:?}", user.role);
-            Redirect::to("/login").into_response()
-        }
-        None => {
-            info!("admin_middleware: No user in session");
-            Redirect::to("/login").into_response()
-        }
+    if !user.role.can(permission) {
+        info!("permission_middleware: role {:?} lacks {:?}", user.role, permission);
+        return (StatusCode::FORBIDDEN, "403; přístup odepřen".to_string()).into_response();
     }
+    next.run(req).await
 }
 
 async fn show_404() -> impl IntoResponse {
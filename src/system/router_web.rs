@@ -1,21 +1,24 @@
-use crate::application::category_finance::finance;
-use crate::application::category_finance::finance::FinanceError;
-use crate::application::category_republika::republika;
-use crate::application::category_republika::republika::RepublikaError;
-use crate::application::category_technologie::technologie;
-use crate::application::category_technologie::technologie::TechnologieError;
-use crate::application::category_veda::veda;
-use crate::application::category_veda::veda::VedaError;
-use crate::application::category_zahranici::zahranici;
-use crate::application::category_zahranici::zahranici::ZahraniciError;
 use crate::application::page_all_news::all_news;
 use crate::application::page_all_news::all_news::NewsError;
+use crate::application::page_archive::archive;
+use crate::application::page_archive::archive::{ArchiveError, ArchivePage};
 use crate::application::page_article::article;
 use crate::application::page_article::article::ArticleError;
+use crate::application::page_article::live_entries;
+use crate::application::page_article::live_entries::LiveEntriesError;
+use crate::application::page_author::author;
+use crate::application::page_author::author::AuthorError;
+use crate::application::page_category::category;
+use crate::application::page_category::category::CategoryError;
+use crate::application::page_corrections::corrections;
+use crate::application::page_corrections::corrections::{CorrectionsError, CORRECTIONS_FILE_NAME};
 use crate::application::page_index::index;
 use crate::application::page_index::index::IndexError;
 use crate::application::page_search::search;
+use crate::db::database_article::SurrealArticleError;
+use crate::db::database_category::SurrealCategoryError;
 use crate::db::database_system::{ArticleStatus, SurrealSystemError};
+use crate::db::database_user::SurrealUserError;
 use crate::system::data_system::DataSystemError;
 use crate::system::data_updates::DataUpdatesError;
 use crate::system::server::TheState;
@@ -25,7 +28,7 @@ use axum::response::{Html, IntoResponse, Response};
 use axum::routing::get;
 use axum::Router;
 use axum_core::extract::Request;
-use http::StatusCode;
+use http::{header, StatusCode};
 use thiserror::Error;
 use tower::ServiceExt;
 use tower_http::services::{ServeDir, ServeFile};
@@ -43,29 +46,35 @@ pub enum WebRouterError {
     #[error("index error: {0}")]
     RouterIndexError(#[from] IndexError),
 
-    #[error("finance error: {0}")]
-    RouterFinanceError(#[from] FinanceError),
-
     #[error("news error: {0}")]
     RouterNewsError(#[from] NewsError),
 
-    #[error("republika error: {0}")]
-    RouterRepublikaError(#[from] RepublikaError),
+    #[error("category error: {0}")]
+    RouterCategoryError(#[from] CategoryError),
 
-    #[error("technologie error: {0}")]
-    RouterTechnologieError(#[from] TechnologieError),
+    #[error("article error: {0}")]
+    RouterArticleError(#[from] ArticleError),
 
-    #[error("veda error: {0}")]
-    RouterVedaError(#[from] VedaError),
+    #[error("archive error: {0}")]
+    RouterArchiveError(#[from] ArchiveError),
 
-    #[error("zahranici error: {0}")]
-    RouterZahraniciError(#[from] ZahraniciError),
+    #[error("author error: {0}")]
+    RouterAuthorError(#[from] AuthorError),
 
-    #[error("article error: {0}")]
-    RouterArticleError(#[from] ArticleError),
+    #[error("corrections error: {0}")]
+    RouterCorrectionsError(#[from] CorrectionsError),
 
     #[error("surreal system error: {0}")]
     SurrealSystem(#[from] SurrealSystemError),
+
+    #[error("surreal article error: {0}")]
+    SurrealArticle(#[from] SurrealArticleError),
+
+    #[error("surreal category error: {0}")]
+    SurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("surreal user error: {0}")]
+    SurrealUser(#[from] SurrealUserError),
 }
 
 pub struct WebRouter {
@@ -84,6 +93,18 @@ impl IntoResponse for WebRouterError {
     }
 }
 
+impl IntoResponse for LiveEntriesError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::LiveBlogNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()).into_response(),
+            Self::InvalidSince(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+            Self::LiveEntriesSurrealArticle(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+            }
+        }
+    }
+}
+
 impl WebRouter {
     #[rustfmt::skip]
     pub async fn start_web_router(&self) -> Router {
@@ -94,6 +115,8 @@ impl WebRouter {
          */
         let ret = Router::new()
             .route("/search", get(search::handle_search))
+            // new entries of a live blog, the page polls for them
+            .route("/live/{article_file_name}", get(live_entries::handle_live_entries))
             // serve static directories (nest service)
             .nest_service("/image", ServeDir::new("web/image"))
             .nest_service("/css", ServeDir::new("web/css"))
@@ -147,14 +170,6 @@ impl WebRouter {
                 }
                 serve_this("/index.html", request).await
             }
-            "/finance.html" => {
-                if !state.dv.finance_valid() {
-                    state.dv.finance_validate();
-
-                    finance::render_finance(&state).await?;
-                }
-                serve_this(&url, request).await
-            }
             "/news.html" => {
                 if !state.dv.news_valid() {
                     state.dv.news_validate();
@@ -163,41 +178,62 @@ impl WebRouter {
                 }
                 serve_this(&url, request).await
             }
-            "/republika.html" => {
-                if !state.dv.republika_valid() {
-                    state.dv.republika_validate();
+            "/opravy.html" => {
+                if !state.dv.corrections_valid() {
+                    state.dv.corrections_validate();
 
-                    republika::render_republika(&state).await?;
+                    corrections::render_corrections(&state).await?;
                 }
-                serve_this(&url, request).await
+                serve_this(&format!("/{}", CORRECTIONS_FILE_NAME), request).await
             }
-            "/technologie.html" => {
-                if !state.dv.technologie_valid() {
-                    state.dv.technologie_validate();
+            _ => {
+                // remove the leading slash
+                let real_article_name = real_filename(&url);
 
-                    technologie::render_technologie(&state).await?;
+                // archive page
+                if let Some(archive_page) = ArchivePage::from_path(real_article_name) {
+                    let year = archive_page.year();
+                    if !state.dv.archive_valid(year, &url) {
+                        if !archive::render_archive(&state, archive_page).await? {
+                            debug!("Archive page is empty, give 404");
+                            return serve_404().await;
+                        }
+                        state.dv.archive_validate(year, &url);
+                    }
+                    return serve_this(&url, request).await;
                 }
-                serve_this(&url, request).await
-            }
-            "/veda.html" => {
-                if !state.dv.veda_valid() {
-                    state.dv.veda_validate();
 
-                    veda::render_veda(&state).await?;
+                // author page
+                if let Some((username, page)) = author::author_page_from_path(real_article_name)
+                    && let Some(user) = state.dbu.get_user_by_name(username).await?
+                {
+                    if !state.dv.author_valid(username, page) {
+                        if page > author::author_page_count(&state, username).await? {
+                            debug!("Author page doesn't exist, give 404");
+                            return serve_404().await;
+                        }
+                        state.dv.author_validate(username, page);
+
+                        author::render_author(&state, &user, page).await?;
+                    }
+                    return serve_this(&url, request).await;
                 }
-                serve_this(&url, request).await
-            }
-            "/zahranici.html" => {
-                if !state.dv.zahranici_valid() {
-                    state.dv.zahranici_validate();
 
-                    zahranici::render_zahranici(&state).await?;
+                // category page
+                if let Some((slug, page)) = category::category_page_from_path(real_article_name)
+                    && let Some(category) = state.dbc.category_by_slug(slug).await?
+                {
+                    if !state.dv.category_valid(slug, page) {
+                        if page > category::category_page_count(&state, slug).await? {
+                            debug!("Category page doesn't exist, give 404");
+                            return serve_404().await;
+                        }
+                        state.dv.category_validate(slug, page);
+
+                        category::render_category(&state, &category, page).await?;
+                    }
+                    return serve_this(&url, request).await;
                 }
-                serve_this(&url, request).await
-            }
-            _ => {
-                // remove the leading slash
-                let real_article_name = real_filename(&url);
 
                 // 404 or Article
                 match state.dbs.read_article_validity(real_article_name).await? {
@@ -221,7 +257,22 @@ impl WebRouter {
                         state.dbs.validate_article(real_article_name.to_string()).await?;
                         serve_this(&format!("/{}", real_article_name), request).await
                     }
+                    ArticleStatus::Gone => {
+                        debug!("Article expired, give 410");
+                        let mut response = serve_this(&format!("/{}", real_article_name), request).await?;
+                        if response.status() == StatusCode::OK {
+                            *response.status_mut() = StatusCode::GONE;
+                        }
+                        Ok(response)
+                    }
                     ArticleStatus::DoesNotExist => {
+                        // renamed article, redirect the old url permanently
+                        if let Some(article) = state.dba.article_by_previous_file_name(real_article_name).await?
+                            && article.is_published()
+                        {
+                            debug!("Article renamed, redirect to {}", article.article_file_name);
+                            return Ok(redirect_permanent(&article.article_file_name));
+                        }
                         debug!("Article doesn't exist, give 404");
                         // requested url doesn't exist
                         serve_404().await
@@ -236,6 +287,13 @@ fn real_filename(article_file_name: &str) -> &str {
     article_file_name.strip_prefix('/').unwrap_or(article_file_name)
 }
 
+/*
+ * 301, search engines move the old url to the new one
+ */
+fn redirect_permanent(article_file_name: &str) -> Response {
+    (StatusCode::MOVED_PERMANENTLY, [(header::LOCATION, format!("/{}", article_file_name))]).into_response()
+}
+
 async fn serve_this(path: &str, request: Request<Body>) -> Result<Response, WebRouterError> {
     // path already begins with /
     trace!("serve_this: web{}", path);
@@ -1,4 +1,6 @@
 use crate::db::database_article::DatabaseArticle;
+use crate::db::database_category::DatabaseCategory;
+use crate::db::database_homepage::DatabaseHomepage;
 use crate::db::database_system::DatabaseSystem;
 use crate::db::database_user::DatabaseUser;
 use crate::system::configuration::ApplicationSettings;
@@ -52,6 +54,8 @@ pub struct TheState {
     pub dba: Arc<DatabaseArticle>,
     pub dbu: Arc<DatabaseUser>,
     pub dbs: Arc<DatabaseSystem>,
+    pub dbc: Arc<DatabaseCategory>,
+    pub dbh: Arc<DatabaseHomepage>,
     // data system e.g.: temperature
     pub ds: Arc<DataSystem>,
     // data updates e.g.: is index.html valid
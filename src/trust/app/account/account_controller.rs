@@ -73,6 +73,24 @@ impl AccountController {
         Ok(response_verifier)
     }
 
+    pub async fn update_bio(&self, auth_cookie: &str, bio: &str) -> Result<ResponseVerifier, TrustError> {
+        debug!("update bio");
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/account/update-bio")
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .header(header::COOKIE, auth_cookie.to_string())
+                    .body(Body::from(format!("bio={}", bio)))?,
+            )
+            .await;
+
+        debug!("update bio done");
+        Ok(ResponseVerifier::from_r(response_r))
+    }
+
     pub async fn get(&self, auth_cookie: &str) -> Result<ResponseVerifier, TrustError> {
         debug!("get account page");
         let response_r = (*self.app_router)
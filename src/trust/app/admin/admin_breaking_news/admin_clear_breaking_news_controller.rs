@@ -0,0 +1,37 @@
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::me::TrustError;
+use axum::body::Body;
+use axum::Router;
+use http::{header, Request};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug)]
+pub struct AdminClearBreakingNewsController {
+    app_router: Arc<Router>,
+    user_cookie: Arc<RwLock<Option<String>>>,
+}
+
+impl AdminClearBreakingNewsController {
+    pub const fn new(app_router: Arc<Router>, user_cookie: Arc<RwLock<Option<String>>>) -> Self {
+        Self { app_router, user_cookie }
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/admin_breaking_news/clear")
+                    .header(header::COOKIE, cookie)
+                    .body(Body::empty())?,
+            )
+            .await;
+
+        Ok(ResponseVerifier::from_r(response_r))
+    }
+}
@@ -0,0 +1,96 @@
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::me::TrustError;
+use axum::body::Body;
+use axum::Router;
+use http::{header, Request};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug)]
+pub struct AdminSetBreakingNewsController {
+    app_router: Arc<Router>,
+    user_cookie: Arc<RwLock<Option<String>>>,
+    text: Arc<RwLock<Option<String>>>,
+    article_file_name: Arc<RwLock<Option<String>>>,
+    severity: Arc<RwLock<Option<String>>>,
+    expires: Arc<RwLock<Option<String>>>,
+}
+
+impl AdminSetBreakingNewsController {
+    pub fn new(app_router: Arc<Router>, user_cookie: Arc<RwLock<Option<String>>>) -> Self {
+        Self {
+            app_router,
+            user_cookie,
+            text: Arc::new(RwLock::new(None)),
+            article_file_name: Arc::new(RwLock::new(None)),
+            severity: Arc::new(RwLock::new(None)),
+            expires: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /**
+     * sent as is, use only characters which don't need url encoding
+     */
+    pub fn text(&self, text: &str) -> &Self {
+        *self.text.write() = Some(text.to_string());
+        self
+    }
+
+    pub fn article_file_name(&self, article_file_name: &str) -> &Self {
+        *self.article_file_name.write() = Some(article_file_name.to_string());
+        self
+    }
+
+    /**
+     * info, important or critical
+     */
+    pub fn severity(&self, severity: &str) -> &Self {
+        *self.severity.write() = Some(severity.to_string());
+        self
+    }
+
+    /**
+     * Prague time, e.g. 2026-01-31T18:00
+     */
+    pub fn expires(&self, expires: &str) -> &Self {
+        *self.expires.write() = Some(expires.to_string());
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+        let body = format!(
+            "text={}&article_file_name={}&severity={}&expires={}",
+            self.text.read().clone().unwrap_or_default(),
+            self.article_file_name.read().clone().unwrap_or_default(),
+            self.severity.read().clone().unwrap_or_default(),
+            self.expires.read().clone().unwrap_or_default()
+        );
+
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/admin_breaking_news/set")
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .header(header::COOKIE, cookie)
+                    .body(Body::from(body))?,
+            )
+            .await;
+
+        let response_verifier = ResponseVerifier::from_r(response_r);
+
+        if response_verifier.response.status().is_success()
+            || response_verifier.response.status().is_redirection()
+        {
+            *self.text.write() = None;
+            *self.article_file_name.write() = None;
+            *self.severity.write() = None;
+            *self.expires.write() = None;
+        }
+
+        Ok(response_verifier)
+    }
+}
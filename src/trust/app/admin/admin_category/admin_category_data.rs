@@ -0,0 +1,87 @@
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Default)]
+pub struct AdminCategoryData {
+    pub slug: Option<String>,
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub position: Option<i64>,
+    pub in_navigation: bool,
+    pub homepage_label: Option<String>,
+}
+
+impl AdminCategoryData {
+    pub const fn new() -> Self {
+        Self {
+            slug: None,
+            name: None,
+            title: None,
+            description: None,
+            position: None,
+            in_navigation: false,
+            homepage_label: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AdminCategoryFluent {
+    pub data: Arc<RwLock<AdminCategoryData>>,
+}
+
+impl Default for AdminCategoryFluent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdminCategoryFluent {
+    pub fn new() -> Self {
+        Self { data: Arc::new(RwLock::new(AdminCategoryData::new())) }
+    }
+
+    pub fn slug(&self, slug: &str) -> &Self {
+        self.data.write().slug = Some(slug.to_string());
+        self
+    }
+
+    pub fn name(&self, name: &str) -> &Self {
+        self.data.write().name = Some(name.to_string());
+        self
+    }
+
+    pub fn title(&self, title: &str) -> &Self {
+        self.data.write().title = Some(title.to_string());
+        self
+    }
+
+    pub fn description(&self, description: &str) -> &Self {
+        self.data.write().description = Some(description.to_string());
+        self
+    }
+
+    pub fn position(&self, position: i64) -> &Self {
+        self.data.write().position = Some(position);
+        self
+    }
+
+    pub fn in_navigation(&self, in_navigation: bool) -> &Self {
+        self.data.write().in_navigation = in_navigation;
+        self
+    }
+
+    pub fn homepage_label(&self, homepage_label: &str) -> &Self {
+        self.data.write().homepage_label = Some(homepage_label.to_string());
+        self
+    }
+
+    pub fn get_data(&self) -> AdminCategoryData {
+        self.data.read().clone()
+    }
+
+    pub fn reset(&self) {
+        *self.data.write() = AdminCategoryData::new();
+    }
+}
@@ -0,0 +1,101 @@
+use crate::trust::app::admin::admin_category::admin_category_data::AdminCategoryFluent;
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::me::TrustError;
+use axum::body::Body;
+use axum::Router;
+use http::{header, Request};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug)]
+pub struct AdminCreateCategoryController {
+    app_router: Arc<Router>,
+    user_cookie: Arc<RwLock<Option<String>>>,
+    category_fluent: AdminCategoryFluent,
+}
+
+impl AdminCreateCategoryController {
+    pub fn new(app_router: Arc<Router>, user_cookie: Arc<RwLock<Option<String>>>) -> Self {
+        Self {
+            app_router,
+            user_cookie,
+            category_fluent: AdminCategoryFluent::new(),
+        }
+    }
+
+    pub fn slug(&self, slug: &str) -> &Self {
+        self.category_fluent.slug(slug);
+        self
+    }
+
+    pub fn name(&self, name: &str) -> &Self {
+        self.category_fluent.name(name);
+        self
+    }
+
+    pub fn title(&self, title: &str) -> &Self {
+        self.category_fluent.title(title);
+        self
+    }
+
+    pub fn description(&self, description: &str) -> &Self {
+        self.category_fluent.description(description);
+        self
+    }
+
+    pub fn position(&self, position: i64) -> &Self {
+        self.category_fluent.position(position);
+        self
+    }
+
+    pub fn in_navigation(&self, in_navigation: bool) -> &Self {
+        self.category_fluent.in_navigation(in_navigation);
+        self
+    }
+
+    pub fn homepage_label(&self, homepage_label: &str) -> &Self {
+        self.category_fluent.homepage_label(homepage_label);
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let data = self.category_fluent.get_data();
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+
+        let mut body = format!(
+            "slug={}&name={}&title={}&description={}&position={}&homepage_label={}",
+            data.slug.unwrap_or_default(),
+            data.name.unwrap_or_default(),
+            data.title.unwrap_or_default(),
+            data.description.unwrap_or_default(),
+            data.position.unwrap_or_default(),
+            data.homepage_label.unwrap_or_default()
+        );
+        if data.in_navigation {
+            body.push_str("&in_navigation=on");
+        }
+
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/admin_category/create")
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .header(header::COOKIE, cookie)
+                    .body(Body::from(body))?,
+            )
+            .await;
+
+        let response_verifier = ResponseVerifier::from_r(response_r);
+
+        if response_verifier.response.status().is_success()
+            || response_verifier.response.status().is_redirection()
+        {
+            self.category_fluent.reset();
+        }
+
+        Ok(response_verifier)
+    }
+}
@@ -1,4 +1,7 @@
 use crate::trust::app::admin::admin_article::admin_delete_article_controller::AdminDeleteArticleController;
+use crate::trust::app::admin::admin_breaking_news::admin_clear_breaking_news_controller::AdminClearBreakingNewsController;
+use crate::trust::app::admin::admin_breaking_news::admin_set_breaking_news_controller::AdminSetBreakingNewsController;
+use crate::trust::app::admin::admin_category::admin_create_category_controller::AdminCreateCategoryController;
 use crate::trust::app::admin::admin_user::admin_create_user_controller::AdminCreateUserController;
 use crate::trust::app::admin::admin_user::admin_delete_user_controller::AdminDeleteUserController;
 use axum::Router;
@@ -11,6 +14,9 @@ pub struct AdminController {
     create_user_controller: AdminCreateUserController,
     delete_user_controller: AdminDeleteUserController,
     delete_article_controller: AdminDeleteArticleController,
+    create_category_controller: AdminCreateCategoryController,
+    set_breaking_news_controller: AdminSetBreakingNewsController,
+    clear_breaking_news_controller: AdminClearBreakingNewsController,
 }
 
 impl AdminController {
@@ -27,7 +33,19 @@ impl AdminController {
                 app_router.clone(),
                 cookie.clone(),
             ),
-            delete_article_controller: AdminDeleteArticleController::new(app_router, cookie),
+            delete_article_controller: AdminDeleteArticleController::new(
+                app_router.clone(),
+                cookie.clone(),
+            ),
+            create_category_controller: AdminCreateCategoryController::new(
+                app_router.clone(),
+                cookie.clone(),
+            ),
+            set_breaking_news_controller: AdminSetBreakingNewsController::new(
+                app_router.clone(),
+                cookie.clone(),
+            ),
+            clear_breaking_news_controller: AdminClearBreakingNewsController::new(app_router, cookie),
         }
     }
 
@@ -46,4 +64,16 @@ impl AdminController {
     pub const fn delete_article(&self) -> &AdminDeleteArticleController {
         &self.delete_article_controller
     }
+
+    pub const fn create_category(&self) -> &AdminCreateCategoryController {
+        &self.create_category_controller
+    }
+
+    pub const fn set_breaking_news(&self) -> &AdminSetBreakingNewsController {
+        &self.set_breaking_news_controller
+    }
+
+    pub const fn clear_breaking_news(&self) -> &AdminClearBreakingNewsController {
+        &self.clear_breaking_news_controller
+    }
 }
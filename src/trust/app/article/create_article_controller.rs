@@ -1,4 +1,4 @@
-use crate::trust::app::article::create_article_data::{ArticleData, ArticleFluent};
+use crate::trust::app::article::create_article_data::{ArticleData, ArticleFluent, GalleryImageData, SourceData};
 use crate::trust::data::media_data::BOUNDARY;
 use crate::trust::data::response_verifier::ResponseVerifier;
 use crate::trust::data::utils::content_type_with_boundary;
@@ -70,6 +70,11 @@ impl CreateArticleController {
         self
     }
 
+    pub fn is_live_blog(&self, is_live_blog: bool) -> &Self {
+        self.input.is_live_blog(is_live_blog);
+        self
+    }
+
     pub fn image(&self, data: Vec<u8>, ext: &str) -> &Self {
         self.input.image(data, ext);
         self
@@ -80,11 +85,36 @@ impl CreateArticleController {
         self
     }
 
+    pub fn image_alt(&self, alt: &str) -> &Self {
+        self.input.image_alt(alt);
+        self
+    }
+
+    pub fn image_credit(&self, credit: &str, source: &str) -> &Self {
+        self.input.image_credit(credit, source);
+        self
+    }
+
     pub fn related_articles(&self, related: &str) -> &Self {
         self.input.related_articles(related);
         self
     }
 
+    pub fn publish_state(&self, publish_state: &str) -> &Self {
+        self.input.publish_state(publish_state);
+        self
+    }
+
+    pub fn publish_at(&self, publish_at: &str) -> &Self {
+        self.input.publish_at(publish_at);
+        self
+    }
+
+    pub fn expires_at(&self, expires_at: &str) -> &Self {
+        self.input.expires_at(expires_at);
+        self
+    }
+
     pub fn image_any_png(&self) -> Result<&Self, TrustError> {
         let image_data = std::fs::read("tests/data/image_1024.png")?;
         self.image(image_data, "png");
@@ -94,9 +124,50 @@ impl CreateArticleController {
         Ok(self)
     }
 
+    pub fn gallery_image_any_png(
+        &self,
+        number: u32,
+        caption: &str,
+        credit: &str,
+        source: &str,
+    ) -> Result<&Self, TrustError> {
+        self.input.gallery_image(GalleryImageData {
+            number,
+            data: Some(std::fs::read("tests/data/image_1024.png")?),
+            ext: "png".into(),
+            caption: caption.into(),
+            alt: caption.into(),
+            credit: credit.into(),
+            source: source.into(),
+            remove: false,
+        });
+        Ok(self)
+    }
+
+    pub fn source(&self, number: u32, title: &str, url: &str, accessed: &str) -> &Self {
+        self.input.source(SourceData {
+            number,
+            title: title.into(),
+            url: url.into(),
+            accessed: accessed.into(),
+        });
+        self
+    }
+
     pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        self.post("/create").await
+    }
+
+    /**
+     * same form, sent to the preview button target
+     */
+    pub async fn preview(&self) -> Result<ResponseVerifier, TrustError> {
+        self.post("/preview").await
+    }
+
+    async fn post(&self, uri: &str) -> Result<ResponseVerifier, TrustError> {
         let data = self.input.get_data();
-        let body = self.build_multipart_body(data)?;
+        let body = article_multipart_body(data)?;
         let cookie = self.user_cookie.read().clone().unwrap_or_default();
 
         let response_r = self
@@ -106,7 +177,7 @@ impl CreateArticleController {
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/create")
+                    .uri(uri)
                     .header(header::CONTENT_TYPE, content_type_with_boundary())
                     .header(header::COOKIE, cookie)
                     .body(Body::from(body))?,
@@ -124,92 +195,137 @@ impl CreateArticleController {
 
         Ok(response_verifier)
     }
+}
 
-    fn build_multipart_body(&self, data: ArticleData) -> Result<Vec<u8>, TrustError> {
-        let mut body = Vec::new();
-
-        let title = data.title.clone().unwrap_or_default();
-        let author = data.author.clone().unwrap_or_default();
-        let text = data.text.clone().unwrap_or_default();
-        let category = data.category.clone().unwrap_or_default();
-        let short_text = data.short_text.clone().unwrap_or_else(|| text.clone());
-        let mini_text = data.mini_text.clone().unwrap_or_else(|| short_text.clone());
-
-        self.add_field(&mut body, "title", &title)?;
-        self.add_field(&mut body, "author", &author)?;
-        self.add_field(&mut body, "category", &category)?;
-        self.add_field(&mut body, "text", &text)?;
-        self.add_field(&mut body, "short_text", &short_text)?;
-        self.add_field(&mut body, "mini_text", &mini_text)?;
-
-        if data.is_main {
-            self.add_field(&mut body, "is_main", "on")?;
-        }
-        if data.is_exclusive {
-            self.add_field(&mut body, "is_exclusive", "on")?;
-        }
+/**
+ * multipart body of the create and the edit form
+ */
+pub fn article_multipart_body(data: ArticleData) -> Result<Vec<u8>, TrustError> {
+    let mut body = Vec::new();
+
+    let title = data.title.clone().unwrap_or_default();
+    let author = data.author.clone().unwrap_or_default();
+    let text = data.text.clone().unwrap_or_default();
+    let category = data.category.clone().unwrap_or_default();
+    let short_text = data.short_text.clone().unwrap_or_else(|| text.clone());
+    let mini_text = data.mini_text.clone().unwrap_or_else(|| short_text.clone());
+
+    add_field(&mut body, "title", &title)?;
+    add_field(&mut body, "author", &author)?;
+    add_field(&mut body, "category", &category)?;
+    add_field(&mut body, "text", &text)?;
+    add_field(&mut body, "short_text", &short_text)?;
+    add_field(&mut body, "mini_text", &mini_text)?;
+
+    if data.is_main {
+        add_field(&mut body, "is_main", "on")?;
+    }
+    if data.is_exclusive {
+        add_field(&mut body, "is_exclusive", "on")?;
+    }
+    if data.is_live_blog {
+        add_field(&mut body, "is_live_blog", "on")?;
+    }
 
-        if let Some(image_desc) = data.image_desc {
-            self.add_field(&mut body, "image_desc", &image_desc)?;
-        }
+    if let Some(image_alt) = data.image_alt {
+        add_field(&mut body, "image_alt", &image_alt)?;
+    }
+    if let Some(image_credit) = data.image_credit {
+        add_field(&mut body, "image_credit", &image_credit)?;
+    }
+    if let Some(image_source) = data.image_source {
+        add_field(&mut body, "image_source", &image_source)?;
+    }
+    if let Some(image_desc) = data.image_desc {
+        add_field(&mut body, "image_desc", &image_desc)?;
+    }
 
-        let related = data.related_articles.join("\n");
-        if !related.is_empty() {
-            self.add_field(&mut body, "related_articles", &related)?;
-        }
+    if let Some(publish_state) = data.publish_state {
+        add_field(&mut body, "publish_state", &publish_state)?;
+    }
+    if let Some(publish_at) = data.publish_at {
+        add_field(&mut body, "publish_at", &publish_at)?;
+    }
+    if let Some(expires_at) = data.expires_at {
+        add_field(&mut body, "expires_at", &expires_at)?;
+    }
 
-        if let Some(image_data) = data.image_data {
-            let ext = data.image_ext.unwrap_or_else(|| "png".to_string());
-            let mime = if ext == "jpg" || ext == "jpeg" { "image/jpeg" } else { "image/png" };
-            self.add_file(&mut body, "image", &format!("image.{}", ext), mime, &image_data)?;
-        }
+    let related = data.related_articles.join("\n");
+    if !related.is_empty() {
+        add_field(&mut body, "related_articles", &related)?;
+    }
 
-        if let Some(audio_data) = data.audio_data {
-            let ext = data.audio_ext.unwrap_or_else(|| "mp3".to_string());
-            self.add_file(
-                &mut body,
-                "audio",
-                &format!("audio.{}", ext),
-                "audio/mpeg",
-                &audio_data,
-            )?;
-        }
+    if let Some(image_data) = data.image_data {
+        let ext = data.image_ext.unwrap_or_else(|| "png".to_string());
+        let mime = if ext == "jpg" || ext == "jpeg" { "image/jpeg" } else { "image/png" };
+        add_file(&mut body, "image", &format!("image.{}", ext), mime, &image_data)?;
+    }
 
-        if let Some(video_data) = data.video_data {
-            let ext = data.video_ext.unwrap_or_else(|| "mp4".to_string());
-            self.add_file(&mut body, "video", &format!("video.{}", ext), "video/mp4", &video_data)?;
+    for image in data.gallery {
+        add_field(&mut body, &format!("gallery_caption_{}", image.number), &image.caption)?;
+        add_field(&mut body, &format!("gallery_alt_{}", image.number), &image.alt)?;
+        add_field(&mut body, &format!("gallery_credit_{}", image.number), &image.credit)?;
+        add_field(&mut body, &format!("gallery_source_{}", image.number), &image.source)?;
+        if image.remove {
+            add_field(&mut body, &format!("gallery_remove_{}", image.number), "on")?;
         }
+        // the browser sends an empty file input too
+        let ext = image.ext;
+        let mime = if ext == "jpg" || ext == "jpeg" { "image/jpeg" } else { "image/png" };
+        let file_name = if image.data.is_some() { format!("gallery.{}", ext) } else { String::new() };
+        add_file(
+            &mut body,
+            &format!("gallery_image_{}", image.number),
+            &file_name,
+            mime,
+            &image.data.unwrap_or_default(),
+        )?;
+    }
 
-        write!(body, "--{}--\r\n", BOUNDARY)?;
-        Ok(body)
+    for source in data.sources {
+        add_field(&mut body, &format!("source_title_{}", source.number), &source.title)?;
+        add_field(&mut body, &format!("source_url_{}", source.number), &source.url)?;
+        add_field(&mut body, &format!("source_accessed_{}", source.number), &source.accessed)?;
     }
 
-    fn add_field(&self, body: &mut Vec<u8>, name: &str, value: &str) -> Result<(), TrustError> {
-        write!(
-            body,
-            "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
-            BOUNDARY, name, value
-        )?;
-        Ok(())
+    if let Some(audio_data) = data.audio_data {
+        let ext = data.audio_ext.unwrap_or_else(|| "mp3".to_string());
+        add_file(&mut body, "audio", &format!("audio.{}", ext), "audio/mpeg", &audio_data)?;
     }
 
-    fn add_file(
-        &self,
-        body: &mut Vec<u8>,
-        name: &str,
-        filename: &str,
-        mime: &str,
-        data: &[u8],
-    ) -> Result<(), TrustError> {
-        write!(body, "--{}\r\n", BOUNDARY)?;
-        write!(
-            body,
-            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
-            name, filename
-        )?;
-        write!(body, "Content-Type: {}\r\n\r\n", mime)?;
-        body.extend_from_slice(data);
-        write!(body, "\r\n")?;
-        Ok(())
+    if let Some(video_data) = data.video_data {
+        let ext = data.video_ext.unwrap_or_else(|| "mp4".to_string());
+        add_file(&mut body, "video", &format!("video.{}", ext), "video/mp4", &video_data)?;
     }
+
+    write!(body, "--{}--\r\n", BOUNDARY)?;
+    Ok(body)
+}
+
+pub fn add_field(body: &mut Vec<u8>, name: &str, value: &str) -> Result<(), TrustError> {
+    write!(
+        body,
+        "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+        BOUNDARY, name, value
+    )?;
+    Ok(())
+}
+
+pub fn add_file(
+    body: &mut Vec<u8>,
+    name: &str,
+    filename: &str,
+    mime: &str,
+    data: &[u8],
+) -> Result<(), TrustError> {
+    write!(body, "--{}\r\n", BOUNDARY)?;
+    write!(
+        body,
+        "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+        name, filename
+    )?;
+    write!(body, "Content-Type: {}\r\n\r\n", mime)?;
+    body.extend_from_slice(data);
+    write!(body, "\r\n")?;
+    Ok(())
 }
@@ -14,14 +14,49 @@ pub struct ArticleData {
     pub mini_text: Option<String>,
     pub is_main: bool,
     pub is_exclusive: bool,
+    pub is_live_blog: bool,
     pub image_data: Option<Vec<u8>>,
     pub image_ext: Option<String>,
     pub image_desc: Option<String>,
+    pub image_alt: Option<String>,
+    pub image_credit: Option<String>,
+    pub image_source: Option<String>,
     pub audio_data: Option<Vec<u8>>,
     pub audio_ext: Option<String>,
     pub video_data: Option<Vec<u8>>,
     pub video_ext: Option<String>,
+    pub gallery: Vec<GalleryImageData>,
+    pub sources: Vec<SourceData>,
     pub related_articles: Vec<String>,
+    pub publish_state: Option<String>,
+    pub publish_at: Option<String>,
+    pub expires_at: Option<String>,
+}
+
+/**
+ * additional image, without data the edit form changes only the texts
+ */
+#[derive(Debug, Clone)]
+pub struct GalleryImageData {
+    pub number: u32,
+    pub data: Option<Vec<u8>>,
+    pub ext: String,
+    pub caption: String,
+    pub alt: String,
+    pub credit: String,
+    pub source: String,
+    pub remove: bool,
+}
+
+/**
+ * source row of the form, empty texts remove the source
+ */
+#[derive(Debug, Clone)]
+pub struct SourceData {
+    pub number: u32,
+    pub title: String,
+    pub url: String,
+    pub accessed: String,
 }
 
 #[derive(Clone, Debug)]
@@ -46,14 +81,23 @@ impl ArticleData {
             mini_text: None,
             is_main: false,
             is_exclusive: false,
+            is_live_blog: false,
             image_data: None,
             image_ext: None,
             image_desc: None,
+            image_alt: None,
+            image_credit: None,
+            image_source: None,
             audio_data: None,
             audio_ext: None,
             video_data: None,
             video_ext: None,
+            gallery: Vec::new(),
+            sources: Vec::new(),
             related_articles: Vec::new(),
+            publish_state: None,
+            publish_at: None,
+            expires_at: None,
         }
     }
 }
@@ -119,6 +163,12 @@ impl ArticleFluent {
         self
     }
 
+    pub fn is_live_blog(&self, is_live_blog: bool) -> &Self {
+        let mut guard = self.data.write();
+        guard.is_live_blog = is_live_blog;
+        self
+    }
+
     pub fn image(&self, data: Vec<u8>, ext: &str) -> &Self {
         let mut guard = self.data.write();
         guard.image_data = Some(data);
@@ -132,6 +182,19 @@ impl ArticleFluent {
         self
     }
 
+    pub fn image_alt(&self, alt: &str) -> &Self {
+        let mut guard = self.data.write();
+        guard.image_alt = Some(alt.to_string());
+        self
+    }
+
+    pub fn image_credit(&self, credit: &str, source: &str) -> &Self {
+        let mut guard = self.data.write();
+        guard.image_credit = Some(credit.to_string());
+        guard.image_source = Some(source.to_string());
+        self
+    }
+
     pub fn audio(&self, data: Vec<u8>, ext: &str) -> &Self {
         let mut guard = self.data.write();
         guard.audio_data = Some(data);
@@ -146,12 +209,42 @@ impl ArticleFluent {
         self
     }
 
+    pub fn gallery_image(&self, image: GalleryImageData) -> &Self {
+        let mut guard = self.data.write();
+        guard.gallery.push(image);
+        self
+    }
+
+    pub fn source(&self, source: SourceData) -> &Self {
+        let mut guard = self.data.write();
+        guard.sources.push(source);
+        self
+    }
+
     pub fn related_articles(&self, related: &str) -> &Self {
         let mut guard = self.data.write();
         guard.related_articles.push(related.to_string());
         self
     }
 
+    pub fn publish_state(&self, publish_state: &str) -> &Self {
+        let mut guard = self.data.write();
+        guard.publish_state = Some(publish_state.to_string());
+        self
+    }
+
+    pub fn publish_at(&self, publish_at: &str) -> &Self {
+        let mut guard = self.data.write();
+        guard.publish_at = Some(publish_at.to_string());
+        self
+    }
+
+    pub fn expires_at(&self, expires_at: &str) -> &Self {
+        let mut guard = self.data.write();
+        guard.expires_at = Some(expires_at.to_string());
+        self
+    }
+
     // Safe read access (no poison, no unwrap)
     pub fn get_data(&self) -> ArticleData {
         let guard = self.data.read();
@@ -164,14 +257,23 @@ impl ArticleFluent {
             mini_text: guard.mini_text.clone(),
             is_main: guard.is_main,
             is_exclusive: guard.is_exclusive,
+            is_live_blog: guard.is_live_blog,
             image_data: guard.image_data.clone(),
             image_ext: guard.image_ext.clone(),
             image_desc: guard.image_desc.clone(),
+            image_alt: guard.image_alt.clone(),
+            image_credit: guard.image_credit.clone(),
+            image_source: guard.image_source.clone(),
             audio_data: guard.audio_data.clone(),
             audio_ext: guard.audio_ext.clone(),
             video_data: guard.video_data.clone(),
             video_ext: guard.video_ext.clone(),
+            gallery: guard.gallery.clone(),
+            sources: guard.sources.clone(),
             related_articles: guard.related_articles.clone(),
+            publish_state: guard.publish_state.clone(),
+            publish_at: guard.publish_at.clone(),
+            expires_at: guard.expires_at.clone(),
         }
     }
 }
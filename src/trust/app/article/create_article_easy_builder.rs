@@ -1,6 +1,8 @@
-use crate::data::library::safe_article_file_name;
-use crate::db::database_article_data::Article;
-use chrono::Utc;
+use crate::data::library;
+use crate::data::slug::slugify;
+use crate::data::text_processor::word_count;
+use crate::db::database_article_data::{Article, PublishState};
+use chrono::{DateTime, Utc};
 use surrealdb_types::Uuid;
 
 pub struct ArticleBuilder {
@@ -11,6 +13,8 @@ pub struct ArticleBuilder {
     short_text: String,
     mini_text: String,
     image_desc: String,
+    image_credit: String,
+    image_source: String,
     category: String,
 
     has_video: bool,
@@ -18,9 +22,16 @@ pub struct ArticleBuilder {
     has_audio: bool,
     audio_path: String,
 
-    related_articles: Vec<String>,
+    related_articles: Vec<Uuid>,
     is_main: bool,
     is_exclusive: bool,
+    is_live_blog: bool,
+
+    publish_state: PublishState,
+    publish_at: Option<DateTime<Utc>>,
+    expires_at: Option<DateTime<Utc>>,
+    created: Option<DateTime<Utc>>,
+    updated: Option<DateTime<Utc>>,
 }
 
 impl ArticleBuilder {
@@ -33,6 +44,8 @@ impl ArticleBuilder {
             short_text: "short text here".to_string(),
             mini_text: "mini text".to_string(),
             image_desc: "desc".to_string(),
+            image_credit: "credit".to_string(),
+            image_source: "source".to_string(),
             category: "cat".to_string(),
 
             has_video: false,
@@ -43,6 +56,13 @@ impl ArticleBuilder {
             related_articles: Vec::new(),
             is_main: false,
             is_exclusive: false,
+            is_live_blog: false,
+
+            publish_state: PublishState::Published,
+            publish_at: None,
+            expires_at: None,
+            created: None,
+            updated: None,
         }
     }
 
@@ -75,6 +95,12 @@ impl ArticleBuilder {
         self
     }
 
+    pub fn image_credit(mut self, credit: impl Into<String>, source: impl Into<String>) -> Self {
+        self.image_credit = credit.into();
+        self.image_source = source.into();
+        self
+    }
+
     pub fn category(mut self, value: impl Into<String>) -> Self {
         self.category = value.into();
         self
@@ -92,7 +118,7 @@ impl ArticleBuilder {
         self
     }
 
-    pub fn related_articles(mut self, articles: Vec<String>) -> Self {
+    pub fn related_articles(mut self, articles: Vec<Uuid>) -> Self {
         self.related_articles = articles;
         self
     }
@@ -107,29 +133,80 @@ impl ArticleBuilder {
         self
     }
 
+    pub const fn live_blog(mut self, value: bool) -> Self {
+        self.is_live_blog = value;
+        self
+    }
+
+    pub const fn draft(mut self) -> Self {
+        self.publish_state = PublishState::Draft;
+        self.publish_at = None;
+        self
+    }
+
+    pub const fn in_review(mut self) -> Self {
+        self.publish_state = PublishState::InReview;
+        self.publish_at = None;
+        self
+    }
+
+    pub const fn scheduled(mut self, publish_at: DateTime<Utc>) -> Self {
+        self.publish_state = PublishState::Scheduled;
+        self.publish_at = Some(publish_at);
+        self
+    }
+
+    pub const fn expires(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    pub const fn created(mut self, created: DateTime<Utc>) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub const fn updated(mut self, updated: DateTime<Utc>) -> Self {
+        self.updated = Some(updated);
+        self
+    }
+
     pub fn build(self) -> Article {
-        let now = Utc::now();
-        let base = safe_article_file_name(&self.title);
+        let now = self.created.unwrap_or_else(Utc::now);
+        let base = slugify(&self.title);
+        let words = word_count(&self.text);
 
         Article {
             uuid: Uuid::new(),
             author: self.author.clone(),
             username: self.author,
             created: now,
+            updated: self.updated.unwrap_or(now),
+            word_count: words,
+            reading_minutes: library::reading_minutes(words),
 
             title: self.title,
+            text_raw: self.text.clone(),
             text: self.text,
+            short_text_raw: self.short_text.clone(),
             short_text: self.short_text,
+            mini_text_raw: self.mini_text.clone(),
             mini_text: self.mini_text,
 
             article_file_name: format!("{}.html", base),
+            previous_file_names: Vec::new(),
 
+            image_alt: format!("alt {}", self.image_desc),
             image_desc: self.image_desc,
+            image_credit: self.image_credit,
+            image_source: self.image_source,
 
             image_50_path: format!("u/{}_image_50.png", base),
             image_288_path: format!("u/{}_image_288.png", base),
             image_440_path: format!("u/{}_image_440.png", base),
             image_820_path: format!("u/{}_image_820.png", base),
+            images: Vec::new(),
+            sources: Vec::new(),
 
             has_video: self.has_video,
             video_path: self.video_path,
@@ -141,6 +218,12 @@ impl ArticleBuilder {
 
             is_main: self.is_main,
             is_exclusive: self.is_exclusive,
+            is_live_blog: self.is_live_blog,
+
+            publish_state: self.publish_state,
+            publish_at: self.publish_at,
+            expires_at: self.expires_at,
+            state_transitions: Vec::new(),
         }
     }
 }
@@ -0,0 +1,204 @@
+use crate::trust::app::article::create_article_controller::article_multipart_body;
+use crate::trust::app::article::create_article_data::{ArticleData, ArticleFluent, GalleryImageData, SourceData};
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::data::utils::content_type_with_boundary;
+use crate::trust::me::TrustError;
+use axum::body::Body;
+use axum::Router;
+use http::{header, Request};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug, Clone)]
+pub struct EditArticleController {
+    app_router: Arc<Router>,
+    article_file_name: Arc<RwLock<Option<String>>>,
+    input: ArticleFluent,
+    user_cookie: Arc<RwLock<Option<String>>>,
+}
+
+impl EditArticleController {
+    pub fn new(app_router: Arc<Router>) -> Self {
+        Self {
+            app_router,
+            article_file_name: Arc::new(RwLock::new(None)),
+            input: ArticleFluent::new(),
+            user_cookie: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn set_cookie(&self, cookie: Option<String>) {
+        *self.user_cookie.write() = cookie;
+    }
+
+    pub fn article_file_name(&self, article_file_name: &str) -> &Self {
+        *self.article_file_name.write() = Some(article_file_name.to_string());
+        self
+    }
+
+    pub fn title(&self, title: &str) -> &Self {
+        self.input.title(title);
+        self
+    }
+
+    pub fn text(&self, text: &str) -> &Self {
+        self.input.text(text);
+        self
+    }
+
+    pub fn author(&self, author: &str) -> &Self {
+        self.input.author(author);
+        self
+    }
+
+    pub fn category(&self, category: &str) -> &Self {
+        self.input.category(category);
+        self
+    }
+
+    pub fn short_text(&self, short_text: &str) -> &Self {
+        self.input.short_text(short_text);
+        self
+    }
+
+    pub fn image_desc(&self, desc: &str) -> &Self {
+        self.input.image_desc(desc);
+        self
+    }
+
+    pub fn image_alt(&self, alt: &str) -> &Self {
+        self.input.image_alt(alt);
+        self
+    }
+
+    pub fn image_credit(&self, credit: &str, source: &str) -> &Self {
+        self.input.image_credit(credit, source);
+        self
+    }
+
+    pub fn related_articles(&self, related: &str) -> &Self {
+        self.input.related_articles(related);
+        self
+    }
+
+    pub fn publish_state(&self, publish_state: &str) -> &Self {
+        self.input.publish_state(publish_state);
+        self
+    }
+
+    pub fn image_any_jpg(&self) -> Result<&Self, TrustError> {
+        let image_data = std::fs::read("tests/data/image_1024.jpg")?;
+        self.input.image(image_data, "jpg");
+        Ok(self)
+    }
+
+    pub fn gallery_image_any_jpg(
+        &self,
+        number: u32,
+        caption: &str,
+        credit: &str,
+        source: &str,
+    ) -> Result<&Self, TrustError> {
+        self.input.gallery_image(GalleryImageData {
+            number,
+            data: Some(std::fs::read("tests/data/image_1024.jpg")?),
+            ext: "jpg".into(),
+            caption: caption.into(),
+            alt: caption.into(),
+            credit: credit.into(),
+            source: source.into(),
+            remove: false,
+        });
+        Ok(self)
+    }
+
+    /*
+     * keep the image file, change the caption and credit
+     */
+    pub fn gallery_caption(&self, number: u32, caption: &str, credit: &str, source: &str) -> &Self {
+        self.input.gallery_image(GalleryImageData {
+            number,
+            data: None,
+            ext: String::new(),
+            caption: caption.into(),
+            alt: caption.into(),
+            credit: credit.into(),
+            source: source.into(),
+            remove: false,
+        });
+        self
+    }
+
+    pub fn gallery_remove(&self, number: u32) -> &Self {
+        self.input.gallery_image(GalleryImageData {
+            number,
+            data: None,
+            ext: String::new(),
+            caption: String::new(),
+            alt: String::new(),
+            credit: String::new(),
+            source: String::new(),
+            remove: true,
+        });
+        self
+    }
+
+    /*
+     * the form sends all sources, one left out is removed
+     */
+    pub fn source(&self, number: u32, title: &str, url: &str, accessed: &str) -> &Self {
+        self.input.source(SourceData {
+            number,
+            title: title.into(),
+            url: url.into(),
+            accessed: accessed.into(),
+        });
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let name = self.article_file_name.read().clone().unwrap_or_default();
+        self.post(&format!("/edit/{}", name)).await
+    }
+
+    /**
+     * same form, sent to the preview button target
+     */
+    pub async fn preview(&self) -> Result<ResponseVerifier, TrustError> {
+        let name = self.article_file_name.read().clone().unwrap_or_default();
+        self.post(&format!("/preview?edit={}", name)).await
+    }
+
+    async fn post(&self, uri: &str) -> Result<ResponseVerifier, TrustError> {
+        let data = self.input.get_data();
+        let body = article_multipart_body(data)?;
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+
+        let response_r = self
+            .app_router
+            .as_ref()
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header(header::CONTENT_TYPE, content_type_with_boundary())
+                    .header(header::COOKIE, cookie)
+                    .body(Body::from(body))?,
+            )
+            .await;
+
+        let response_verifier = ResponseVerifier::from_r(response_r);
+
+        // Clear input after execution if successful
+        if response_verifier.response.status().is_success()
+            || response_verifier.response.status().is_redirection()
+        {
+            *self.input.data.write() = ArticleData::new();
+            *self.article_file_name.write() = None;
+        }
+
+        Ok(response_verifier)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::me::TrustError;
+use axum::body::Body;
+use axum::Router;
+use http::{header, Request};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug)]
+pub struct RestoreRevisionController {
+    app_router: Arc<Router>,
+    article_file_name: Arc<RwLock<Option<String>>>,
+    revision_id: Arc<RwLock<Option<String>>>,
+    user_cookie: Arc<RwLock<Option<String>>>,
+}
+
+impl RestoreRevisionController {
+    pub fn new(app_router: Arc<Router>) -> Self {
+        Self {
+            app_router,
+            article_file_name: Arc::new(RwLock::new(None)),
+            revision_id: Arc::new(RwLock::new(None)),
+            user_cookie: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn set_cookie(&self, cookie: Option<String>) {
+        *self.user_cookie.write() = cookie;
+    }
+
+    pub fn article_file_name(&self, article_file_name: &str) -> &Self {
+        *self.article_file_name.write() = Some(article_file_name.to_string());
+        self
+    }
+
+    pub fn revision_id(&self, revision_id: &str) -> &Self {
+        *self.revision_id.write() = Some(revision_id.to_string());
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let name = self.article_file_name.read().clone().unwrap_or_default();
+        let revision_id = self.revision_id.read().clone().unwrap_or_default();
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/revisions/{}/restore/{}", name, revision_id))
+                    .header(header::COOKIE, cookie)
+                    .body(Body::empty())?,
+            )
+            .await;
+
+        let response_verifier = ResponseVerifier::from_r(response_r);
+
+        if response_verifier.response.status().is_success()
+            || response_verifier.response.status().is_redirection()
+        {
+            *self.article_file_name.write() = None;
+            *self.revision_id.write() = None;
+        }
+
+        Ok(response_verifier)
+    }
+}
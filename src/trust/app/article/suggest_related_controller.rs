@@ -0,0 +1,91 @@
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::me::TrustError;
+use axum::Router;
+use axum::body::Body;
+use http::{Request, header};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug)]
+pub struct SuggestRelatedController {
+    app_router: Arc<Router>,
+    title: Arc<RwLock<Option<String>>>,
+    text: Arc<RwLock<Option<String>>>,
+    article_file_name: Arc<RwLock<Option<String>>>,
+    user_cookie: Arc<RwLock<Option<String>>>,
+}
+
+impl SuggestRelatedController {
+    pub fn new(app_router: Arc<Router>) -> Self {
+        Self {
+            app_router,
+            title: Arc::new(RwLock::new(None)),
+            text: Arc::new(RwLock::new(None)),
+            article_file_name: Arc::new(RwLock::new(None)),
+            user_cookie: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn set_cookie(&self, cookie: Option<String>) {
+        *self.user_cookie.write() = cookie;
+    }
+
+    /**
+     * sent as is, use only characters which don't need url encoding
+     */
+    pub fn title(&self, title: &str) -> &Self {
+        *self.title.write() = Some(title.to_string());
+        self
+    }
+
+    /**
+     * sent as is, use only characters which don't need url encoding
+     */
+    pub fn text(&self, text: &str) -> &Self {
+        *self.text.write() = Some(text.to_string());
+        self
+    }
+
+    /**
+     * the edited Article, left out of the suggestions
+     */
+    pub fn article_file_name(&self, article_file_name: &str) -> &Self {
+        *self.article_file_name.write() = Some(article_file_name.to_string());
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let title = self.title.read().clone().unwrap_or_default();
+        let text = self.text.read().clone().unwrap_or_default();
+        let name = self.article_file_name.read().clone();
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+
+        let mut body = format!("title={}&text={}", title, text);
+        if let Some(name) = name {
+            body.push_str(&format!("&article_file_name={}", name));
+        }
+
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/suggest-related")
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .header(header::COOKIE, cookie)
+                    .body(Body::from(body))?,
+            )
+            .await;
+
+        let response_verifier = ResponseVerifier::from_r(response_r);
+
+        if response_verifier.response.status().is_success() {
+            *self.title.write() = None;
+            *self.text.write() = None;
+            *self.article_file_name.write() = None;
+        }
+
+        Ok(response_verifier)
+    }
+}
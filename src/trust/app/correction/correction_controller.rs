@@ -0,0 +1,73 @@
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::me::TrustError;
+use axum::body::Body;
+use axum::Router;
+use http::{header, Request};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug)]
+pub struct CorrectionController {
+    app_router: Arc<Router>,
+    article_file_name: Arc<RwLock<Option<String>>>,
+    text: Arc<RwLock<Option<String>>>,
+    user_cookie: Arc<RwLock<Option<String>>>,
+}
+
+impl CorrectionController {
+    pub fn new(app_router: Arc<Router>) -> Self {
+        Self {
+            app_router,
+            article_file_name: Arc::new(RwLock::new(None)),
+            text: Arc::new(RwLock::new(None)),
+            user_cookie: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn set_cookie(&self, cookie: Option<String>) {
+        *self.user_cookie.write() = cookie;
+    }
+
+    pub fn article_file_name(&self, article_file_name: &str) -> &Self {
+        *self.article_file_name.write() = Some(article_file_name.to_string());
+        self
+    }
+
+    /**
+     * sent as is, use only characters which don't need url encoding
+     */
+    pub fn text(&self, text: &str) -> &Self {
+        *self.text.write() = Some(text.to_string());
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let name = self.article_file_name.read().clone().unwrap_or_default();
+        let text = self.text.read().clone().unwrap_or_default();
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/correction/{}", name))
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .header(header::COOKIE, cookie)
+                    .body(Body::from(format!("text={}", text)))?,
+            )
+            .await;
+
+        let response_verifier = ResponseVerifier::from_r(response_r);
+
+        if response_verifier.response.status().is_success()
+            || response_verifier.response.status().is_redirection()
+        {
+            *self.article_file_name.write() = None;
+            *self.text.write() = None;
+        }
+
+        Ok(response_verifier)
+    }
+}
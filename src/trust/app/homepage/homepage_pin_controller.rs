@@ -0,0 +1,88 @@
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::me::TrustError;
+use axum::body::Body;
+use axum::Router;
+use http::{header, Request};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug)]
+pub struct HomepagePinController {
+    app_router: Arc<Router>,
+    slot: Arc<RwLock<Option<String>>>,
+    article_file_name: Arc<RwLock<Option<String>>>,
+    pinned_until: Arc<RwLock<Option<String>>>,
+    user_cookie: Arc<RwLock<Option<String>>>,
+}
+
+impl HomepagePinController {
+    pub fn new(app_router: Arc<Router>) -> Self {
+        Self {
+            app_router,
+            slot: Arc::new(RwLock::new(None)),
+            article_file_name: Arc::new(RwLock::new(None)),
+            pinned_until: Arc::new(RwLock::new(None)),
+            user_cookie: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn set_cookie(&self, cookie: Option<String>) {
+        *self.user_cookie.write() = cookie;
+    }
+
+    /**
+     * main, second, third or the slug of a section
+     */
+    pub fn slot(&self, slot: &str) -> &Self {
+        *self.slot.write() = Some(slot.to_string());
+        self
+    }
+
+    pub fn article_file_name(&self, article_file_name: &str) -> &Self {
+        *self.article_file_name.write() = Some(article_file_name.to_string());
+        self
+    }
+
+    /**
+     * Prague time, e.g. 2026-01-31T18:00
+     */
+    pub fn pinned_until(&self, pinned_until: &str) -> &Self {
+        *self.pinned_until.write() = Some(pinned_until.to_string());
+        self
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let slot = self.slot.read().clone().unwrap_or_default();
+        let name = self.article_file_name.read().clone().unwrap_or_default();
+        let pinned_until = self.pinned_until.read().clone().unwrap_or_default();
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/homepage/pin")
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .header(header::COOKIE, cookie)
+                    .body(Body::from(format!(
+                        "slot={}&article_file_name={}&pinned_until={}",
+                        slot, name, pinned_until
+                    )))?,
+            )
+            .await;
+
+        let response_verifier = ResponseVerifier::from_r(response_r);
+
+        if response_verifier.response.status().is_success()
+            || response_verifier.response.status().is_redirection()
+        {
+            *self.slot.write() = None;
+            *self.article_file_name.write() = None;
+            *self.pinned_until.write() = None;
+        }
+
+        Ok(response_verifier)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::trust::app::article::create_article_controller::{add_field, add_file};
+use crate::trust::data::media_data::{BOUNDARY, JPEG};
+use crate::trust::data::response_verifier::ResponseVerifier;
+use crate::trust::data::utils::content_type_with_boundary;
+use crate::trust::me::TrustError;
+use axum::body::Body;
+use axum::Router;
+use http::{header, Request};
+use parking_lot::RwLock;
+use std::io::Write;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[derive(Debug)]
+pub struct LiveBlogController {
+    app_router: Arc<Router>,
+    article_file_name: Arc<RwLock<Option<String>>>,
+    text: Arc<RwLock<Option<String>>>,
+    image_data: Arc<RwLock<Option<Vec<u8>>>>,
+    image_desc: Arc<RwLock<Option<String>>>,
+    user_cookie: Arc<RwLock<Option<String>>>,
+}
+
+impl LiveBlogController {
+    pub fn new(app_router: Arc<Router>) -> Self {
+        Self {
+            app_router,
+            article_file_name: Arc::new(RwLock::new(None)),
+            text: Arc::new(RwLock::new(None)),
+            image_data: Arc::new(RwLock::new(None)),
+            image_desc: Arc::new(RwLock::new(None)),
+            user_cookie: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn set_cookie(&self, cookie: Option<String>) {
+        *self.user_cookie.write() = cookie;
+    }
+
+    pub fn article_file_name(&self, article_file_name: &str) -> &Self {
+        *self.article_file_name.write() = Some(article_file_name.to_string());
+        self
+    }
+
+    pub fn text(&self, text: &str) -> &Self {
+        *self.text.write() = Some(text.to_string());
+        self
+    }
+
+    pub fn image_desc(&self, desc: &str) -> &Self {
+        *self.image_desc.write() = Some(desc.to_string());
+        self
+    }
+
+    pub fn image_any_jpg(&self) -> Result<&Self, TrustError> {
+        *self.image_data.write() = Some(std::fs::read("tests/data/image_1024.jpg")?);
+        Ok(self)
+    }
+
+    pub async fn execute(&self) -> Result<ResponseVerifier, TrustError> {
+        let name = self.article_file_name.read().clone().unwrap_or_default();
+        let cookie = self.user_cookie.read().clone().unwrap_or_default();
+        let text = self.text.read().clone().unwrap_or_default();
+        let image_desc = self.image_desc.read().clone();
+        let image_data = self.image_data.read().clone();
+
+        let mut body = Vec::new();
+        add_field(&mut body, "text", &text)?;
+        if let Some(image_desc) = image_desc {
+            add_field(&mut body, "image_desc", &image_desc)?;
+        }
+        if let Some(image_data) = image_data {
+            add_file(&mut body, "image", "image.jpg", JPEG, &image_data)?;
+        }
+        write!(body, "--{}--\r\n", BOUNDARY)?;
+
+        let response_r = (*self.app_router)
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/live-blog/{}", name))
+                    .header(header::CONTENT_TYPE, content_type_with_boundary())
+                    .header(header::COOKIE, cookie)
+                    .body(Body::from(body))?,
+            )
+            .await;
+
+        let response_verifier = ResponseVerifier::from_r(response_r);
+
+        if response_verifier.response.status().is_success()
+            || response_verifier.response.status().is_redirection()
+        {
+            *self.article_file_name.write() = None;
+            *self.text.write() = None;
+            *self.image_data.write() = None;
+            *self.image_desc.write() = None;
+        }
+
+        Ok(response_verifier)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::db::database_user::Role;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
@@ -6,11 +7,12 @@ pub struct LoginData {
     pub username: Option<String>,
     pub password: Option<String>,
     pub needs_password_change: bool,
+    pub role: Option<Role>,
 }
 
 impl LoginData {
     pub(crate) const fn new() -> Self {
-        Self { username: None, password: None, needs_password_change: false }
+        Self { username: None, password: None, needs_password_change: false, role: None }
     }
 }
 
@@ -51,12 +53,22 @@ impl LoginFluent {
         self
     }
 
+    /**
+     * default Editor, override if needed
+     */
+    pub fn role(&self, role: Role) -> &Self {
+        let mut guard = self.data.write();
+        guard.role = Some(role);
+        self
+    }
+
     pub fn get_data(&self) -> LoginData {
         let guard = self.data.read();
         LoginData {
             username: guard.username.clone(),
             password: guard.password.clone(),
             needs_password_change: guard.needs_password_change,
+            role: guard.role.clone(),
         }
     }
 }
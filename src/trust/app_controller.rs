@@ -1,5 +1,7 @@
 use crate::db::database;
 use crate::db::database_article::DatabaseArticle;
+use crate::db::database_category::DatabaseCategory;
+use crate::db::database_homepage::DatabaseHomepage;
 use crate::db::database_system::DatabaseSystem;
 use crate::db::database_user::DatabaseUser;
 use crate::system::server::TheState;
@@ -7,14 +9,23 @@ use crate::system::{configuration, data_system, data_updates, logger, server};
 use crate::trust::app::account::account_controller::AccountController;
 use crate::trust::app::admin::admin_controller::AdminController;
 use crate::trust::app::article::create_article_controller::CreateArticleController;
+use crate::trust::app::article::edit_article_controller::EditArticleController;
+use crate::trust::app::article::restore_revision_controller::RestoreRevisionController;
+use crate::trust::app::article::suggest_related_controller::SuggestRelatedController;
 use crate::trust::app::change_password::change_password_controller::ChangePasswordController;
+use crate::trust::app::correction::correction_controller::CorrectionController;
+use crate::trust::app::homepage::homepage_pin_controller::HomepagePinController;
+use crate::trust::app::live_blog::live_blog_controller::LiveBlogController;
 use crate::trust::app::login::login_controller::LoginController;
+use crate::trust::app::review::review_controller::ReviewController;
 use crate::trust::db::db_article_controller::DatabaseArticleController;
 use crate::trust::db::db_system_controller::DatabaseSystemController;
 use crate::trust::db::db_user_controller::DatabaseUserController;
 use crate::trust::me::TrustError;
 use crate::trust::web::auth_web_controller::AuthorizedWebController;
 use crate::trust::web::web_controller::WebController;
+use crate::trust::command::command_controller::CommandController;
+use crate::trust::worker::worker_controller::WorkerController;
 use std::sync::Arc;
 use tracing::debug;
 
@@ -24,8 +35,15 @@ pub struct AppController {
     account: Arc<AccountController>,
     admin: Arc<AdminController>,
     article: Arc<CreateArticleController>,
+    edit_article: Arc<EditArticleController>,
+    restore_revision: Arc<RestoreRevisionController>,
+    suggest_related: Arc<SuggestRelatedController>,
     change_password: Arc<ChangePasswordController>,
+    correction: Arc<CorrectionController>,
+    homepage_pin: Arc<HomepagePinController>,
+    live_blog: Arc<LiveBlogController>,
     login: Arc<LoginController>,
+    review: Arc<ReviewController>,
     web_auth: Arc<AuthorizedWebController>,
     // web
     web: Arc<WebController>,
@@ -33,6 +51,10 @@ pub struct AppController {
     db_article_controller: Arc<DatabaseArticleController>,
     db_user_controller: Arc<DatabaseUserController>,
     db_system_controller: Arc<DatabaseSystemController>,
+    // workers
+    worker: Arc<WorkerController>,
+    // command line
+    command: Arc<CommandController>,
 }
 
 impl AppController {
@@ -46,6 +68,8 @@ impl AppController {
         let dba = Arc::new(DatabaseArticle::new(surreal.clone()));
         let dbu = Arc::new(DatabaseUser::new(surreal.clone()));
         let dbs = Arc::new(DatabaseSystem::new(surreal.clone()));
+        let dbc = Arc::new(DatabaseCategory::new(surreal.clone()));
+        let dbh = Arc::new(DatabaseHomepage::new(surreal.clone()));
 
         // if there are no articles at all, create the table
         surreal.db.query("DEFINE TABLE article SCHEMALESS;").await?;
@@ -60,6 +84,8 @@ impl AppController {
             dba: dba.clone(),
             dbu: dbu.clone(),
             dbs: dbs.clone(),
+            dbc,
+            dbh,
             ds,
             dv,
             start_time: chrono::Utc::now(),
@@ -80,8 +106,15 @@ impl AppController {
             account: Arc::new(AccountController::new(app_router.clone())),
             admin: Arc::new(AdminController::new(app_router.clone())),
             article: Arc::new(CreateArticleController::new(app_router.clone())),
+            edit_article: Arc::new(EditArticleController::new(app_router.clone())),
+            restore_revision: Arc::new(RestoreRevisionController::new(app_router.clone())),
+            suggest_related: Arc::new(SuggestRelatedController::new(app_router.clone())),
             change_password: Arc::new(ChangePasswordController::new(app_router.clone())),
+            correction: Arc::new(CorrectionController::new(app_router.clone())),
+            homepage_pin: Arc::new(HomepagePinController::new(app_router.clone())),
+            live_blog: Arc::new(LiveBlogController::new(app_router.clone())),
             login: Arc::new(LoginController::new(app_router.clone())),
+            review: Arc::new(ReviewController::new(app_router.clone())),
             web_auth: Arc::new(AuthorizedWebController::new(app_router.clone())),
             // web
             web: Arc::new(WebController::new(web_router)),
@@ -89,6 +122,10 @@ impl AppController {
             db_article_controller: Arc::new(DatabaseArticleController::new(dba.clone())),
             db_user_controller: Arc::new(DatabaseUserController::new(dbu.clone())),
             db_system_controller: Arc::new(DatabaseSystemController::new(dbs.clone())),
+            // workers
+            worker: Arc::new(WorkerController::new(state.clone())),
+            // command line
+            command: Arc::new(CommandController::new(state)),
         })
     }
 
@@ -97,6 +134,41 @@ impl AppController {
         self.article.clone()
     }
 
+    pub fn edit_article(&self, auth: &str) -> Arc<EditArticleController> {
+        self.edit_article.set_cookie(Some(auth.to_string()));
+        self.edit_article.clone()
+    }
+
+    pub fn restore_revision(&self, auth: &str) -> Arc<RestoreRevisionController> {
+        self.restore_revision.set_cookie(Some(auth.to_string()));
+        self.restore_revision.clone()
+    }
+
+    pub fn suggest_related(&self, auth: &str) -> Arc<SuggestRelatedController> {
+        self.suggest_related.set_cookie(Some(auth.to_string()));
+        self.suggest_related.clone()
+    }
+
+    pub fn correction(&self, auth: &str) -> Arc<CorrectionController> {
+        self.correction.set_cookie(Some(auth.to_string()));
+        self.correction.clone()
+    }
+
+    pub fn homepage_pin(&self, auth: &str) -> Arc<HomepagePinController> {
+        self.homepage_pin.set_cookie(Some(auth.to_string()));
+        self.homepage_pin.clone()
+    }
+
+    pub fn live_blog(&self, auth: &str) -> Arc<LiveBlogController> {
+        self.live_blog.set_cookie(Some(auth.to_string()));
+        self.live_blog.clone()
+    }
+
+    pub fn review(&self, auth: &str) -> Arc<ReviewController> {
+        self.review.set_cookie(Some(auth.to_string()));
+        self.review.clone()
+    }
+
     pub fn change_password(&self, auth: &str) -> Arc<ChangePasswordController> {
         self.change_password.set_cookie(Some(auth.to_string()));
         self.change_password.clone()
@@ -135,6 +207,14 @@ impl AppController {
     pub fn db_system(&self) -> Arc<DatabaseSystemController> {
         self.db_system_controller.clone()
     }
+
+    pub fn worker(&self) -> Arc<WorkerController> {
+        self.worker.clone()
+    }
+
+    pub fn command(&self) -> Arc<CommandController> {
+        self.command.clone()
+    }
 }
 
 #[cfg(test)]
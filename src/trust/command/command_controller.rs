@@ -0,0 +1,48 @@
+use crate::system::backup;
+use crate::system::backup::BackupManifest;
+use crate::system::import;
+use crate::system::import::ImportReport;
+use crate::system::server::TheState;
+use crate::trust::me::TrustError;
+use std::fmt;
+use std::path::Path;
+
+/**
+ * run a command line action, as if the application was started with it
+ */
+pub struct CommandController {
+    state: TheState,
+}
+
+impl fmt::Debug for CommandController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandController").finish_non_exhaustive()
+    }
+}
+
+impl CommandController {
+    pub const fn new(state: TheState) -> Self {
+        Self { state }
+    }
+
+    /*
+     * axiomatik-web import <directory>
+     */
+    pub async fn import(&self, dir: &str) -> Result<ImportReport, TrustError> {
+        Ok(import::import_directory(&self.state, Path::new(dir)).await?)
+    }
+
+    /*
+     * axiomatik-web backup <file>, the media directory is separate so tests don't touch web/u
+     */
+    pub async fn backup(&self, archive: &str, media_dir: &str) -> Result<BackupManifest, TrustError> {
+        Ok(backup::backup(&self.state, Path::new(archive), Path::new(media_dir)).await?)
+    }
+
+    /*
+     * axiomatik-web restore <file>
+     */
+    pub async fn restore(&self, archive: &str, media_dir: &str) -> Result<BackupManifest, TrustError> {
+        Ok(backup::restore(&self.state, Path::new(archive), Path::new(media_dir)).await?)
+    }
+}
@@ -15,6 +15,7 @@ pub struct ResponseData {
     pub cookies: Vec<Vec<String>>,
     pub body: Option<String>,
     pub body_contains: Vec<String>,
+    pub body_not_contains: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +58,12 @@ impl ResponseFluent {
         self
     }
 
+    pub fn body_not_contains(&self, text: &str) -> &Self {
+        let mut guard = self.data.write();
+        guard.body_not_contains.push(text.to_string());
+        self
+    }
+
     pub fn cookie(&self, properties: &[&str]) -> &Self {
         let mut guard = self.data.write();
         guard.cookies.push(properties.iter().map(|s| s.to_string()).collect());
@@ -112,6 +119,11 @@ impl ResponseVerifier {
         self
     }
 
+    pub fn body_not_contains(self, text: &str) -> Self {
+        self.expected.body_not_contains(text);
+        self
+    }
+
     pub async fn verify(self) -> Result<(), TrustError> {
         let mut errors: Vec<String> = Vec::new();
         let expected = self.expected.get_data();
@@ -136,6 +148,12 @@ impl ResponseVerifier {
             }
         }
 
+        for not_exp in &expected.body_not_contains {
+            if real_body.contains(not_exp) {
+                errors.push(error("body_not_contains", not_exp.clone(), &real_body));
+            }
+        }
+
         if !errors.is_empty() {
             tracing::error!("Real body: {}", real_body);
         }
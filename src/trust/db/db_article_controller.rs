@@ -1,9 +1,10 @@
 use crate::db::database_article::DatabaseArticle;
 use crate::system::logger;
-use crate::db::database_article_data::Article;
+use crate::db::database_article_data::{Article, ArticleRevision};
 use crate::trust::app::article::create_article_easy_builder::ArticleBuilder;
 use crate::trust::db::db_article_verifier::DatabaseArticleVerifier;
 use crate::trust::me::TrustError;
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -35,6 +36,28 @@ impl DatabaseArticleController {
         Ok(())
     }
 
+    pub async fn db_setup_article_in_category(&self, title: &str, category: &str) -> Result<(), TrustError> {
+        #[rustfmt::skip]
+        self.dba.create_article(ArticleBuilder::article()
+            .title(title)
+            .text("Content of the article")
+            .category(category)
+            .build()
+        ).await?;
+        Ok(())
+    }
+
+    pub async fn db_setup_article_created(&self, title: &str, created: DateTime<Utc>) -> Result<(), TrustError> {
+        #[rustfmt::skip]
+        self.dba.create_article(ArticleBuilder::article()
+            .title(title)
+            .text("Content of the article")
+            .created(created)
+            .build()
+        ).await?;
+        Ok(())
+    }
+
     pub async fn must_see(
         &self,
         article_file_html: &str,
@@ -47,6 +70,14 @@ impl DatabaseArticleController {
         Ok(DatabaseArticleVerifier::new(real))
     }
 
+    /*
+     * revisions of the article, newest first
+     */
+    pub async fn revisions(&self, article_file_html: &str) -> Result<Vec<ArticleRevision>, TrustError> {
+        let article = self.dba.article_by_file_name(article_file_html).await?;
+        Ok(self.dba.revisions_by_article(article.uuid).await?)
+    }
+
     pub async fn must_not_see(
         &self,
         article_file_html: &str,
@@ -88,6 +88,8 @@ impl SetupAdminController {
                 username: username.to_string(),
                 author_name: username.to_string(),
                 password_hash: hash(password, DEFAULT_COST)?,
+                bio: String::new(),
+                photo_path: String::new(),
                 needs_password_change: false,
                 role: Role::Admin,
             })
@@ -133,6 +135,11 @@ impl SetupUserController {
         self
     }
 
+    pub fn role(&self, role: Role) -> &Self {
+        self.input.role(role);
+        self
+    }
+
     pub async fn execute(&self) -> Result<(), TrustError> {
         let data = self.input.get_data();
         let username = data.username.unwrap_or_default();
@@ -144,8 +151,10 @@ impl SetupUserController {
                 username: username.to_string(),
                 author_name: username.to_string(),
                 password_hash: hash(password, DEFAULT_COST)?,
+                bio: String::new(),
+                photo_path: String::new(),
                 needs_password_change: data.needs_password_change,
-                role: Role::Editor,
+                role: data.role.unwrap_or(Role::Editor),
             })
             .await?;
         Ok(())
@@ -1,10 +1,15 @@
+use crate::application::page_article::article::ArticleError;
 use crate::data::image_processor::ImageProcessorError;
 use crate::db::database::SurrealError;
 use crate::db::database_article::SurrealArticleError;
+use crate::db::database_category::SurrealCategoryError;
+use crate::db::database_homepage::SurrealHomepageError;
 use crate::db::database_system::SurrealSystemError;
 use crate::db::database_user::SurrealUserError;
 use crate::system::commands::CommandError;
 use crate::system::configuration::ConfigurationError;
+use crate::system::backup::BackupError;
+use crate::system::import::ImportError;
 use crate::system::server::ServerError;
 use http::header;
 use image::ImageError;
@@ -71,11 +76,26 @@ pub enum TrustError {
     #[error("db system error")]
     SurrealArticle(#[from] SurrealArticleError),
 
+    #[error("db category error")]
+    SurrealCategory(#[from] SurrealCategoryError),
+
+    #[error("db homepage error")]
+    SurrealHomepage(#[from] SurrealHomepageError),
+
     #[error("image error")]
     TrustImage(#[from] ImageError),
 
     #[error("image processor error")]
     ImageProcessor(#[from] ImageProcessorError),
+
+    #[error("article error {0}")]
+    TrustArticle(#[from] ArticleError),
+
+    #[error("import error {0}")]
+    TrustImport(#[from] ImportError),
+
+    #[error("backup error {0}")]
+    TrustBackup(#[from] BackupError),
 }
 
 pub fn path_exists(path: &str) -> Result<(), TrustError> {
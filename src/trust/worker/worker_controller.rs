@@ -0,0 +1,52 @@
+use crate::system::server::TheState;
+use crate::trust::me::TrustError;
+use crate::worker::{breaking_news_worker, expire_worker, homepage_worker, publish_worker};
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/**
+ * run a single action of a worker, without waiting for its schedule
+ */
+pub struct WorkerController {
+    state: TheState,
+}
+
+impl fmt::Debug for WorkerController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkerController").finish_non_exhaustive()
+    }
+}
+
+impl WorkerController {
+    pub const fn new(state: TheState) -> Self {
+        Self { state }
+    }
+
+    /*
+     * returns the number of published articles
+     */
+    pub async fn publish(&self) -> Result<usize, TrustError> {
+        Ok(publish_worker::publish_scheduled(&self.state).await?)
+    }
+
+    /*
+     * returns the number of unpinned articles
+     */
+    pub async fn expire_pins(&self) -> Result<usize, TrustError> {
+        Ok(homepage_worker::expire_pins(&self.state).await?)
+    }
+
+    /*
+     * as if it was already the given time, returns true if the banner was removed
+     */
+    pub async fn expire_breaking_news(&self, now: DateTime<Utc>) -> Result<bool, TrustError> {
+        Ok(breaking_news_worker::expire_breaking_news(&self.state, now).await?)
+    }
+
+    /*
+     * as if it was already the given time, returns the number of expired articles
+     */
+    pub async fn expire_articles(&self, now: DateTime<Utc>) -> Result<usize, TrustError> {
+        Ok(expire_worker::expire_articles(&self.state, now).await?)
+    }
+}
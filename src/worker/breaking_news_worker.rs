@@ -0,0 +1,23 @@
+use crate::application::admin_form_breaking_news::admin_breaking_news::update_breaking_news;
+use crate::db::database_system::SurrealSystemError;
+use crate::system::server::TheState;
+use crate::worker::interval_worker;
+use chrono::{DateTime, Utc};
+
+/**
+ * remove the banner once its time has passed
+ * - returns true if the banner was removed
+ */
+pub async fn expire_breaking_news(state: &TheState, now: DateTime<Utc>) -> Result<bool, SurrealSystemError> {
+    let expired = state.ds.breaking_news().is_some_and(|news| news.is_expired(now));
+    if expired {
+        update_breaking_news(state, None).await?;
+    }
+    Ok(expired)
+}
+
+pub fn start_breaking_news_worker(state: TheState) {
+    interval_worker::start_interval_worker("breaking news", state, |state| async move {
+        expire_breaking_news(&state, Utc::now()).await.map(usize::from)
+    });
+}
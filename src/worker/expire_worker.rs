@@ -0,0 +1,51 @@
+use crate::application::page_archive::archive;
+use crate::application::page_article::article;
+use crate::application::page_article::article::ArticleError;
+use crate::db::database_article_data::PublishState;
+use crate::system::server::TheState;
+use crate::worker::interval_worker;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+/**
+ * take down published Articles whose expiry moment has passed
+ * - the Article leaves every listing, the rendered HTML is replaced and served with 410 Gone
+ * - related Articles link to it, they are rendered again without it
+ */
+pub async fn expire_articles(state: &TheState, now: DateTime<Utc>) -> Result<usize, ArticleError> {
+    let articles = state.dba.articles_to_expire(now).await?;
+
+    for expired in &articles {
+        let article_file_name = expired.article_file_name.clone();
+        info!("expire action: {}", article_file_name);
+
+        let mut article = expired.clone();
+        article.transition(PublishState::Expired, "system", "");
+        state.dba.update_article(article).await?;
+
+        article::render_article_gone(&article_file_name)?;
+        state.dbs.gone_article(article_file_name).await?;
+
+        for related_article in state.dba.article_file_names_by_ids(&expired.related_articles).await? {
+            info!("invalidate related article {}", related_article);
+            state.dbs.invalidate_article(related_article).await?;
+        }
+
+        state.dv.category_invalidate(&expired.category);
+        archive::invalidate_archive(state, expired.created);
+        state.dv.author_invalidate(&expired.username);
+    }
+
+    if !articles.is_empty() {
+        state.dv.index_invalidate();
+        state.dv.news_invalidate();
+        state.dv.corrections_invalidate();
+    }
+    Ok(articles.len())
+}
+
+pub fn start_expire_worker(state: TheState) {
+    interval_worker::start_interval_worker("expire", state, |state| async move {
+        expire_articles(&state, Utc::now()).await
+    });
+}
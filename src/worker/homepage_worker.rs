@@ -0,0 +1,22 @@
+use crate::db::database_homepage::SurrealHomepageError;
+use crate::system::server::TheState;
+use crate::worker::interval_worker;
+use chrono::Utc;
+
+/**
+ * unpin Articles whose pinned time has passed
+ * - index.html is rendered again with the next Articles
+ */
+pub async fn expire_pins(state: &TheState) -> Result<usize, SurrealHomepageError> {
+    let removed = state.dbh.delete_expired(Utc::now()).await?;
+    if removed > 0 {
+        state.dv.index_invalidate();
+    }
+    Ok(removed)
+}
+
+pub fn start_homepage_worker(state: TheState) {
+    interval_worker::start_interval_worker("homepage", state, |state| async move {
+        expire_pins(&state).await
+    });
+}
@@ -0,0 +1,37 @@
+use crate::system::server::TheState;
+use std::fmt::Display;
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{error, info, trace};
+
+// publish, expiry and pinned times have minute precision
+const INTERVAL: Duration = Duration::from_secs(30);
+
+/**
+ * run the action every half a minute
+ * - the action returns how many records it changed
+ * - not spawned, a slow run must not overlap with the next one
+ */
+pub fn start_interval_worker<F, Fut, E>(name: &'static str, state: TheState, action: F)
+where
+    F: Fn(TheState) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<usize, E>> + Send,
+    E: Display,
+{
+    info!("start {} worker", name);
+
+    // loop thread
+    tokio::spawn(async move {
+        let mut interval = interval(INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match action(state.clone()).await {
+                Ok(0) => trace!("{} action: nothing to do", name),
+                Ok(count) => info!("{} action: {} changed", name, count),
+                Err(e) => error!("{} action failed: {}", name, e),
+            }
+        }
+    });
+}
@@ -0,0 +1,35 @@
+use crate::application::form_create_article::create_article;
+use crate::application::page_article::article::ArticleError;
+use crate::db::database_article_data::Article;
+use crate::system::server::TheState;
+use crate::worker::interval_worker;
+use chrono::Utc;
+use tracing::info;
+
+/**
+ * publish scheduled Articles whose publish moment has passed
+ * - publish_at is the Prague local time chosen by the editor, stored as UTC
+ */
+pub async fn publish_scheduled(state: &TheState) -> Result<usize, ArticleError> {
+    let now = Utc::now();
+    let articles = state.dba.articles_to_publish(now).await?;
+
+    for article in &articles {
+        info!("publish action: {}", article.article_file_name);
+        state.dba.publish_article(article.uuid, now).await?;
+        // created and updated are the moment of publishing
+        let published = Article {
+            created: now,
+            updated: now,
+            ..article.clone()
+        };
+        create_article::publish_article(state, &published).await?;
+    }
+    Ok(articles.len())
+}
+
+pub fn start_publish_worker(state: TheState) {
+    interval_worker::start_interval_worker("publish", state, |state| async move {
+        publish_scheduled(&state).await
+    });
+}
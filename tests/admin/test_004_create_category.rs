@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use http::StatusCode;
+
+    #[tokio::test]
+    async fn test_create_category() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_admin_user()
+            .username("admin4")
+            .password("strong*admin*password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("admin4")
+            .password("strong*admin*password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.admin(&auth).create_category()
+            .slug("sport")
+            .name("Sport")
+            .title("Ze sportu")
+            .description("Výsledky a reportáže.")
+            .position(6)
+            .in_navigation(true)
+            .homepage_label("Více sportu")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .header_location("/admin_category")
+                .verify().await?;
+
+        // pages of the web router can't be a category
+        #[rustfmt::skip]
+        ac.admin(&auth).create_category()
+            .slug("news")
+            .name("News")
+            .title("News")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        // nor a directory of the web router
+        #[rustfmt::skip]
+        ac.admin(&auth).create_category()
+            .slug("u")
+            .name("U")
+            .title("U")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Category Sport")
+            .author("Author")
+            .category("sport")
+            .text("Sport text.")
+            .short_text("Sport short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/sport.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Ze sportu")
+            .body_contains("Výsledky a reportáže.")
+            .body_contains("Sport short text.")
+            .body_contains("href=\"/sport.html\"")
+            .verify().await?;
+
+        // the category has its own section on the homepage
+        #[rustfmt::skip]
+        ac.web().get_url("/index.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("<a class=\"button\" href=\"sport.html\">Více sportu</a>")
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/sport.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-category-sport_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use chrono::{Duration, Utc};
+    use http::StatusCode;
+
+    #[tokio::test]
+    async fn test_breaking_news() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_admin_user()
+            .username("admin5")
+            .password("strong*admin*password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("admin5")
+            .password("strong*admin*password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Breaking Story")
+            .author("Admin")
+            .category("republika")
+            .text("Breaking text.")
+            .short_text("Breaking short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // rendered before the banner
+        #[rustfmt::skip]
+        ac.web().get_url("/test-breaking-story.html").await?
+            .must_see_response(StatusCode::OK)
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.admin(&auth).set_breaking_news()
+            .text("Test+breaking+banner")
+            .article_file_name("test-breaking-story.html")
+            .severity("critical")
+            .expires("2099-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .header_location("/admin_breaking_news")
+                .verify().await?;
+
+        // all HTML was invalidated
+        for url in ["/index.html", "/news.html", "/republika.html", "/test-breaking-story.html"] {
+            #[rustfmt::skip]
+            ac.web().get_url(url).await?
+                .must_see_response(StatusCode::OK)
+                .body_contains("breaking-critical")
+                .body_contains("Test breaking banner")
+                .verify().await?;
+        }
+
+        #[rustfmt::skip]
+        ac.admin(&auth).set_breaking_news()
+            .text("Test+expired")
+            .article_file_name("test-breaking-story.html")
+            .severity("critical")
+            .expires("2020-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.admin(&auth).set_breaking_news()
+            .text("Test+unknown+severity")
+            .article_file_name("test-breaking-story.html")
+            .severity("panic")
+            .expires("2099-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.admin(&auth).set_breaking_news()
+            .text("Test+missing+article")
+            .article_file_name("test-not-existing.html")
+            .severity("info")
+            .expires("2099-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        // the worker removes the banner once expired
+        assert!(!ac.worker().expire_breaking_news(Utc::now()).await?);
+        assert!(ac.worker().expire_breaking_news(Utc::now() + Duration::days(365 * 100)).await?);
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/admin_breaking_news").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Žádná mimořádná zpráva")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.admin(&auth).set_breaking_news()
+            .text("Test+cleared+banner")
+            .article_file_name("test-breaking-story.html")
+            .severity("info")
+            .expires("2099-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.admin(&auth).clear_breaking_news()
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .header_location("/admin_breaking_news")
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/admin_breaking_news").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Žádná mimořádná zpráva")
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-breaking-story.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-breaking-story_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
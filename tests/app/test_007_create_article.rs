@@ -33,6 +33,8 @@ mod tests {
             .text("This is a test article text.\n\nMore text.")
             .short_text("Short text.")
             .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
             .execute().await?
                 .must_see_response(StatusCode::SEE_OTHER)
                 .header_location("/account")
@@ -34,6 +34,8 @@ mod tests {
             .short_text("Short text")
             .image_any_png()?
             .image_desc("Description")
+            .image_alt("Description")
+            .image_credit("Axiomatik", "vlastní")
             .execute().await?
                 .must_see_response(StatusCode::SEE_OTHER)
                 .verify().await?;
@@ -49,6 +49,22 @@ mod tests {
             .author_name("Updated Author")
             .verify()?;
 
+        // the related article
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Related Test Article")
+            .author("Updated Author")
+            .category("zahranici")
+            .text("Related content")
+            .short_text("Related short")
+            .image_any_png()?
+            .image_desc("related image description")
+            .image_alt("related image description")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
         // Create an article with this user
         #[rustfmt::skip]
         ac.create_article(&auth)
@@ -59,6 +75,8 @@ mod tests {
             .short_text("Short")
             .image_any_png()?
             .image_desc("test image description")
+            .image_alt("test image description")
+            .image_credit("Axiomatik", "vlastní")
             .related_articles("related-test-article.html")
             .execute().await?
                 .must_see_response(StatusCode::SEE_OTHER)
@@ -106,6 +124,10 @@ mod tests {
         trust::me::remove_file("web/u/test-user-article_image_50.png")?;
         trust::me::remove_file("web/u/test-user-article_image_288.png")?;
         trust::me::remove_file("web/u/test-user-article_image_440.png")?;
+        trust::me::remove_file("web/u/related-test-article_image_820.png")?;
+        trust::me::remove_file("web/u/related-test-article_image_50.png")?;
+        trust::me::remove_file("web/u/related-test-article_image_288.png")?;
+        trust::me::remove_file("web/u/related-test-article_image_440.png")?;
 
         Ok(())
     }
@@ -34,6 +34,8 @@ mod tests {
             .is_exclusive(true)
             .image_any_png()?
             .image_desc("anything")
+            .image_alt("anything")
+            .image_credit("Axiomatik", "vlastní")
             .execute().await?
                 .must_see_response(StatusCode::SEE_OTHER)
                 .verify().await?;
@@ -34,6 +34,8 @@ mod tests {
             .short_text("Short text of newest article")
             .image_any_png()?
             .image_desc("test description")
+            .image_alt("test description")
+            .image_credit("Axiomatik", "vlastní")
             .execute().await?
                 .must_see_response(StatusCode::SEE_OTHER)
                 .verify().await?;
@@ -56,6 +58,8 @@ mod tests {
             .short_text("Short text of newest article")
             .image_any_png()?
             .image_desc("test description")
+            .image_alt("test description")
+            .image_credit("Axiomatik", "vlastní")
             .related_articles("test-related.html")
             .execute().await?
                 .must_see_response(StatusCode::SEE_OTHER)
@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_edit_article() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user15")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user15")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Edit Article")
+            .author("Test Author")
+            .category("republika")
+            .text("Original text with a typo.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // render the original article
+        #[rustfmt::skip]
+        ac.web().get_url("/test-edit-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Original text with a typo.")
+            .verify().await?;
+
+        // the edit form is prefilled
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/edit/test-edit-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Original text with a typo.")
+            .verify().await?;
+
+        // edit the article and replace the image
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-edit-article.html")
+            .title("Test Edit Article Fixed")
+            .author("Test Author")
+            .category("veda")
+            .text("Fixed text without a typo.")
+            .short_text("Short text.")
+            .image_desc("new image")
+            .image_alt("new image")
+            .image_credit("Axiomatik", "vlastní")
+            .image_any_jpg()?
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .header_location("/account")
+                .verify().await?;
+
+        // the file name follows the new title
+        #[rustfmt::skip]
+        ac.db_article().must_see("test-edit-article-fixed.html").await?
+            .title("Test Edit Article Fixed")
+            .verify()?;
+
+        // the article was invalidated and rendered again
+        #[rustfmt::skip]
+        ac.web().get_url("/test-edit-article-fixed.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Edit Article Fixed")
+            .body_contains("Fixed text without a typo.")
+            .verify().await?;
+
+        // the old HTML and the replaced image were removed
+        trust::me::path_doesnt_exists("web/test-edit-article.html")?;
+        trust::me::path_doesnt_exists("web/u/test-edit-article_image_820.png")?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-edit-article-fixed.html")?;
+        trust::me::remove_file("web/u/test-edit-article-fixed_image_50.jpg")?;
+        trust::me::remove_file("web/u/test-edit-article-fixed_image_288.jpg")?;
+        trust::me::remove_file("web/u/test-edit-article-fixed_image_440.jpg")?;
+        trust::me::remove_file("web/u/test-edit-article-fixed_image_820.jpg")?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_edit_article_of_other_user() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user15a")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user15b")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth_a = ac.login()
+            .username("user15a")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        let auth_b = ac.login()
+            .username("user15b")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth_a)
+            .title("Test Edit Foreign")
+            .author("Author A")
+            .category("republika")
+            .text("Text of A.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.edit_article(&auth_b)
+            .article_file_name("test-edit-foreign.html")
+            .title("Test Edit Foreign")
+            .author("Author B")
+            .category("republika")
+            .text("Text of B.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::FORBIDDEN)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.db_article().must_see("test-edit-foreign.html").await?
+            .text("<div class=\"container\"><p>Text of A.</p></div>")
+            .verify()?;
+
+        // Cleanup
+        trust::me::remove_file("web/u/test-edit-foreign_image_50.png")?;
+        trust::me::remove_file("web/u/test-edit-foreign_image_288.png")?;
+        trust::me::remove_file("web/u/test-edit-foreign_image_440.png")?;
+        trust::me::remove_file("web/u/test-edit-foreign_image_820.png")?;
+        Ok(())
+    }
+}
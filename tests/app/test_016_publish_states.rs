@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_draft_article() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user16")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user16")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Draft Article")
+            .author("Test Author")
+            .category("finance")
+            .text("Draft text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("draft")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // stored, but not published
+        #[rustfmt::skip]
+        ac.db_article().must_see("test-draft-article.html").await?
+            .title("Test Draft Article")
+            .verify()?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-draft-article.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.account().get(&auth).await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("koncept")
+            .verify().await?;
+
+        // publish the draft through the edit form
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-draft-article.html")
+            .title("Test Draft Article")
+            .author("Test Author")
+            .category("finance")
+            .text("Draft text, now published.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("published")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-draft-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Draft text, now published.")
+            // published now, not updated since
+            .body_not_contains("aktualizováno")
+            .verify().await?;
+
+        // the form has no state select for a published article, a crafted one is refused
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-draft-article.html")
+            .title("Test Draft Article")
+            .author("Test Author")
+            .category("finance")
+            .text("Back to a draft.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("draft")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-draft-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Draft text, now published.")
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-draft-article.html")?;
+        trust::me::remove_file("web/u/test-draft-article_image_50.png")?;
+        trust::me::remove_file("web/u/test-draft-article_image_288.png")?;
+        trust::me::remove_file("web/u/test-draft-article_image_440.png")?;
+        trust::me::remove_file("web/u/test-draft-article_image_820.png")?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_article() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user16s")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user16s")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // the publish moment has already passed
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Scheduled Article")
+            .author("Test Author")
+            .category("technologie")
+            .text("Scheduled text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("scheduled")
+            .publish_at("2025-01-01T08:00")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // the far future
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Scheduled Later")
+            .author("Test Author")
+            .category("technologie")
+            .text("Scheduled later text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("scheduled")
+            .publish_at("2099-01-01T08:00")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-scheduled-article.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        // publish worker action
+        let published = ac.worker().publish().await?;
+        assert_eq!(published, 1);
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-scheduled-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Scheduled text.")
+            // it wasn't updated after going live
+            .body_not_contains("aktualizováno")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-scheduled-later.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-scheduled-article.html")?;
+        for name in ["test-scheduled-article", "test-scheduled-later"] {
+            for size in [50, 288, 440, 820] {
+                trust::me::remove_file(&format!("web/u/{}_image_{}.png", name, size))?;
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_article_requires_time() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user16t")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user16t")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Scheduled No Time")
+            .author("Test Author")
+            .category("technologie")
+            .text("Text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("scheduled")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_article_revisions() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user17")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user17")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Revision Article")
+            .author("Test Author")
+            .category("republika")
+            .text("The first version of the text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-revision-article.html")
+            .title("Test Revision Article")
+            .author("Test Author")
+            .category("republika")
+            .text("The second version of the text.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // the first version was kept
+        let revisions = ac.db_article().revisions("test-revision-article.html").await?;
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].editor, "user17");
+        let first_id = revisions[0].revision_id.clone();
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/revisions/test-revision-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains(&first_id)
+            .verify().await?;
+
+        // word level diff against the current version
+        #[rustfmt::skip]
+        ac.web_app(&auth)
+            .get_url(&format!("/revisions/test-revision-article.html/diff?from={}&to=current", first_id)).await?
+                .must_see_response(StatusCode::OK)
+                .body_contains("<del>first</del>")
+                .body_contains("<ins>second</ins>")
+                .verify().await?;
+
+        // render the edited article
+        #[rustfmt::skip]
+        ac.web().get_url("/test-revision-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("The second version of the text.")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.restore_revision(&auth)
+            .article_file_name("test-revision-article.html")
+            .revision_id(&first_id)
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .header_location("/revisions/test-revision-article.html")
+                .verify().await?;
+
+        // restored and rendered again
+        #[rustfmt::skip]
+        ac.web().get_url("/test-revision-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("The first version of the text.")
+            .verify().await?;
+
+        // the replaced version is kept as a revision too
+        let revisions = ac.db_article().revisions("test-revision-article.html").await?;
+        assert_eq!(revisions.len(), 2);
+
+        // Cleanup
+        trust::me::remove_file("web/test-revision-article.html")?;
+        trust::me::remove_file("web/u/test-revision-article_image_50.png")?;
+        trust::me::remove_file("web/u/test-revision-article_image_288.png")?;
+        trust::me::remove_file("web/u/test-revision-article_image_440.png")?;
+        trust::me::remove_file("web/u/test-revision-article_image_820.png")?;
+        Ok(())
+    }
+}
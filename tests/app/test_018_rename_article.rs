@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_rename_article() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user18")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user18")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Rename Related")
+            .author("Author")
+            .category("republika")
+            .text("Related text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Rename Before")
+            .author("Author")
+            .category("republika")
+            .text("Renamed text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .related_articles("test-rename-related.html")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-rename-before.html").await?
+            .must_see_response(StatusCode::OK)
+            .verify().await?;
+
+        // a new title renames the article
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-rename-before.html")
+            .title("Test Rename After")
+            .author("Author")
+            .category("republika")
+            .text("Renamed text.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .related_articles("test-rename-related.html")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // the old url is redirected permanently
+        #[rustfmt::skip]
+        ac.web().get_url("/test-rename-before.html").await?
+            .must_see_response(StatusCode::MOVED_PERMANENTLY)
+            .header_location("/test-rename-after.html")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-rename-after.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Rename After")
+            .body_contains("Test Rename Related")
+            .verify().await?;
+
+        // the related article links to the new url
+        #[rustfmt::skip]
+        ac.web().get_url("/test-rename-related.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("test-rename-after.html")
+            .verify().await?;
+
+        // media keep their original names
+        trust::me::path_exists("web/u/test-rename-before_image_820.png")?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-rename-after.html")?;
+        trust::me::remove_file("web/test-rename-related.html")?;
+        for name in ["test-rename-before", "test-rename-related"] {
+            for size in ["50", "288", "440", "820"] {
+                trust::me::remove_file(&format!("web/u/{}_image_{}.png", name, size))?;
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rename_to_taken_file_name() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user18b")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user18b")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        for title in ["Test Slug Taken", "Test Slug Other"] {
+            #[rustfmt::skip]
+            ac.create_article(&auth)
+                .title(title)
+                .author("Author")
+                .category("republika")
+                .text("Text.")
+                .short_text("Short text.")
+                .image_any_png()?
+                .image_alt("test image alt")
+                .image_credit("Axiomatik", "vlastní")
+                .execute().await?
+                    .must_see_response(StatusCode::SEE_OTHER)
+                    .verify().await?;
+        }
+
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-slug-other.html")
+            .title("Test Slug Taken")
+            .author("Author")
+            .category("republika")
+            .text("Text.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // the file name of the other article isn't taken over
+        #[rustfmt::skip]
+        ac.db_article().must_see("test-slug-taken-2.html").await?
+            .title("Test Slug Taken")
+            .verify()?;
+
+        #[rustfmt::skip]
+        ac.db_article().must_see("test-slug-taken.html").await?
+            .text("<div class=\"container\"><p>Text.</p></div>")
+            .verify()?;
+
+        // Cleanup
+        for name in ["test-slug-taken", "test-slug-other"] {
+            for size in ["50", "288", "440", "820"] {
+                trust::me::remove_file(&format!("web/u/{}_image_{}.png", name, size))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_create_article_same_title() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user19")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user19")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Stejný   titulek!")
+            .author("Author")
+            .category("republika")
+            .text("First text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Stejný titulek")
+            .author("Author")
+            .category("republika")
+            .text("Second text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.db_article().must_see("stejny-titulek.html").await?
+            .text("<div class=\"container\"><p>First text.</p></div>")
+            .verify()?;
+
+        #[rustfmt::skip]
+        ac.db_article().must_see("stejny-titulek-2.html").await?
+            .text("<div class=\"container\"><p>Second text.</p></div>")
+            .verify()?;
+
+        // each article has its own media
+        trust::me::path_exists("web/u/stejny-titulek_image_820.png")?;
+        trust::me::path_exists("web/u/stejny-titulek-2_image_820.png")?;
+
+        // Cleanup
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/stejny-titulek_image_{}.png", size))?;
+            trust::me::remove_file(&format!("web/u/stejny-titulek-2_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_category_pages() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        // one article more than fits on the first page
+        for i in 1..=21 {
+            #[rustfmt::skip]
+            ac.db_article().db_setup_article_in_category(&format!("Test Page Veda {:02}", i), "veda").await?;
+        }
+
+        #[rustfmt::skip]
+        ac.web().get_url("/veda.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Page Veda 21")
+            .body_contains("Test Page Veda 02")
+            .body_contains("href=\"/veda/2.html\"")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/veda/2.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Page Veda 01")
+            .body_contains("href=\"/veda.html\" rel=\"prev\"")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/veda/3.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/veda/1.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/veda.html")?;
+        trust::me::remove_file("web/veda/2.html")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+    use chrono::{TimeZone, Utc};
+
+    #[tokio::test]
+    async fn test_archive_pages() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        // Prague is UTC+1 in November
+        let db = ac.db_article();
+        let morning = Utc.with_ymd_and_hms(2025, 11, 17, 9, 0, 0).unwrap();
+        let after_midnight = Utc.with_ymd_and_hms(2025, 11, 16, 23, 30, 0).unwrap();
+        let late_evening = Utc.with_ymd_and_hms(2025, 11, 16, 22, 30, 0).unwrap();
+        db.db_setup_article_created("Test Archive Morning", morning).await?;
+        db.db_setup_article_created("Test Archive After Midnight", after_midnight).await?;
+        db.db_setup_article_created("Test Archive Late Evening", late_evening).await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/archiv/2025/11/17.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Archiv — 17. listopadu 2025")
+            .body_contains("Test Archive Morning")
+            .body_contains("Test Archive After Midnight")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/archiv/2025/11/16.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Archive Late Evening")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/archiv/2025/listopad.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("href=\"/archiv/2025/11/16.html\"")
+            .body_contains("href=\"/archiv/2025/11/17.html\"")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/archiv/2025.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Listopad (3)")
+            .verify().await?;
+
+        // nothing published
+        #[rustfmt::skip]
+        ac.web().get_url("/archiv/2025/12/01.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        // not the canonical url
+        #[rustfmt::skip]
+        ac.web().get_url("/archiv/2025/11/017.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/archiv/2025/11/17.html")?;
+        trust::me::remove_file("web/archiv/2025/11/16.html")?;
+        trust::me::remove_file("web/archiv/2025/listopad.html")?;
+        trust::me::remove_file("web/archiv/2025.html")?;
+        Ok(())
+    }
+}
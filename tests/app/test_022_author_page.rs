@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_author_page() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user22")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user22")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Author Page")
+            .author("user22")
+            .category("republika")
+            .text("Author text.")
+            .short_text("Author short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.account().update_bio(&auth, "Píše o vědě.").await?
+            .must_see_response(StatusCode::SEE_OTHER)
+            .header_location("/account")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/autor/user22.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("user22")
+            .body_contains("Píše o vědě.")
+            .body_contains("Author short text.")
+            .verify().await?;
+
+        // the byline links to the author page
+        #[rustfmt::skip]
+        ac.web().get_url("/test-author-page.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("href=\"/autor/user22.html\"")
+            .verify().await?;
+
+        // a new author name is shown
+        #[rustfmt::skip]
+        ac.account().update_author_name(&auth)
+            .author_name("Jana Novak")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/autor/user22.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Jana Novak")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/autor/user22/2.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/autor/nobody.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/autor/user22.html")?;
+        trust::me::remove_file("web/test-author-page.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-author-page_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::db::database_user::Role;
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_review_workflow() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user23c")
+            .password("password")
+            .role(Role::Contributor)
+            .execute().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user23r")
+            .password("password")
+            .role(Role::Reviewer)
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let contributor = ac.login()
+            .username("user23c")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // a contributor can't publish directly
+        #[rustfmt::skip]
+        ac.create_article(&contributor)
+            .title("Test Review Article")
+            .author("Contributor")
+            .category("zahranici")
+            .text("Submitted text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("published")
+            .execute().await?
+                .must_see_response(StatusCode::FORBIDDEN)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&contributor)
+            .title("Test Review Article")
+            .author("Contributor")
+            .category("zahranici")
+            .text("Submitted text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("review")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-review-article.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        // only reviewers see the queue
+        #[rustfmt::skip]
+        ac.web_app(&contributor).get_url("/review").await?
+            .must_see_response(StatusCode::FORBIDDEN)
+            .verify().await?;
+
+        #[rustfmt::skip]
+        let reviewer = ac.login()
+            .username("user23r")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&reviewer).get_url("/review").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Review Article")
+            .verify().await?;
+
+        // the author has to know why
+        #[rustfmt::skip]
+        ac.review(&reviewer)
+            .article_file_name("test-review-article.html")
+            .request_changes()
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.review(&reviewer)
+            .article_file_name("test-review-article.html")
+            .request_changes()
+            .comment("Add+sources")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.account().get(&contributor).await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("vráceno k úpravám")
+            .body_contains("Add sources")
+            .verify().await?;
+
+        // submitted again
+        #[rustfmt::skip]
+        ac.edit_article(&contributor)
+            .article_file_name("test-review-article.html")
+            .title("Test Review Article")
+            .author("Contributor")
+            .category("zahranici")
+            .text("Submitted text with sources.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("review")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.review(&reviewer)
+            .article_file_name("test-review-article.html")
+            .approve()
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-review-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Submitted text with sources.")
+            .verify().await?;
+
+        // a live article can't be changed without a review
+        #[rustfmt::skip]
+        ac.edit_article(&contributor)
+            .article_file_name("test-review-article.html")
+            .title("Test Review Article")
+            .author("Contributor")
+            .category("zahranici")
+            .text("Changed without review.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::FORBIDDEN)
+                .verify().await?;
+
+        // admin pages stay closed
+        #[rustfmt::skip]
+        ac.web_app(&reviewer).get_url("/admin_user").await?
+            .must_see_response(StatusCode::FORBIDDEN)
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-review-article.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-review-article_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::db::database_user::Role;
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_homepage_slots() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user24")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user24c")
+            .password("password")
+            .role(Role::Contributor)
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user24")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // neither main nor in a homepage section, only a pin brings it to the index
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Pinned Finance")
+            .author("Editor")
+            .category("finance")
+            .text("Pinned text.")
+            .short_text("Pinned short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.homepage_pin(&auth)
+            .slot("main")
+            .article_file_name("test-pinned-finance.html")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/index.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Pinned Finance")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.homepage_pin(&auth)
+            .slot("republika")
+            .article_file_name("test-pinned-finance.html")
+            .pinned_until("2099-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/homepage").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Hlavní článek")
+            .body_contains("Test Pinned Finance")
+            .verify().await?;
+
+        // a pin must expire in the future
+        #[rustfmt::skip]
+        ac.homepage_pin(&auth)
+            .slot("second")
+            .article_file_name("test-pinned-finance.html")
+            .pinned_until("2020-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.homepage_pin(&auth)
+            .slot("sidebar")
+            .article_file_name("test-pinned-finance.html")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.homepage_pin(&auth)
+            .slot("second")
+            .article_file_name("test-not-existing.html")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        // nothing expired yet
+        assert_eq!(ac.worker().expire_pins().await?, 0);
+
+        #[rustfmt::skip]
+        let contributor = ac.login()
+            .username("user24c")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&contributor).get_url("/homepage").await?
+            .must_see_response(StatusCode::FORBIDDEN)
+            .verify().await?;
+
+        // trigger render article
+        #[rustfmt::skip]
+        ac.web().get_url("/test-pinned-finance.html").await?
+            .must_see_response(StatusCode::OK)
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-pinned-finance.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-pinned-finance_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
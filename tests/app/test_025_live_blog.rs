@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_live_blog() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user25")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user25")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Live Election")
+            .author("Editor")
+            .category("republika")
+            .text("Election night introduction.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .is_live_blog(true)
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Not Live")
+            .author("Editor")
+            .category("republika")
+            .text("Ordinary text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.live_blog(&auth)
+            .article_file_name("test-live-election.html")
+            .text("First polling stations closed.")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.live_blog(&auth)
+            .article_file_name("test-live-election.html")
+            .text("First results are in.")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // an entry needs a text
+        #[rustfmt::skip]
+        ac.live_blog(&auth)
+            .article_file_name("test-live-election.html")
+            .text(" ")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        // an image needs a description
+        #[rustfmt::skip]
+        ac.live_blog(&auth)
+            .article_file_name("test-live-election.html")
+            .text("Photo from the headquarters.")
+            .image_any_jpg()?
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.live_blog(&auth)
+            .article_file_name("test-not-live.html")
+            .text("Wrong article.")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/live-blog/test-live-election.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("First results are in.")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-live-election.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Election night introduction.")
+            .body_contains("First polling stations closed.")
+            .body_contains("First results are in.")
+            .body_contains("data-entries=\"/live/test-live-election.html\"")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/live/test-live-election.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("\"text\":\"First results are in.\"")
+            .body_contains("\"text\":\"First polling stations closed.\"")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/live/test-live-election.html?since=2099-01-01T00:00:00Z").await?
+            .must_see_response(StatusCode::OK)
+            .body("[]")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/live/test-live-election.html?since=yesterday").await?
+            .must_see_response(StatusCode::BAD_REQUEST)
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/live/test-not-live.html").await?
+            .must_see_response(StatusCode::NOT_FOUND)
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-not-live.html").await?
+            .must_see_response(StatusCode::OK)
+            .verify().await?;
+
+        // Cleanup
+        for name in ["test-live-election", "test-not-live"] {
+            trust::me::remove_file(&format!("web/{}.html", name))?;
+            for size in ["50", "288", "440", "820"] {
+                trust::me::remove_file(&format!("web/u/{}_image_{}.png", name, size))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::db::database_user::Role;
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_corrections() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user26")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user26c")
+            .password("password")
+            .role(Role::Contributor)
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user26")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Corrected Article")
+            .author("Editor")
+            .category("republika")
+            .text("The bridge opened in 1928.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Draft Correction")
+            .author("Editor")
+            .category("republika")
+            .text("Draft text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .publish_state("draft")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-corrected-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/opravy.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Zatím žádné opravy.")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.correction(&auth)
+            .article_file_name("test-corrected-article.html")
+            .text("The+bridge+opened+in+1932,+not+1928.")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // the Article and the list of corrections were rendered again
+        #[rustfmt::skip]
+        ac.web().get_url("/test-corrected-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("class=\"corrections\"")
+            .body_contains("The bridge opened in 1932, not 1928.")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/opravy.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Corrected Article")
+            .body_contains("The bridge opened in 1932, not 1928.")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/correction/test-corrected-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("The bridge opened in 1932, not 1928.")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.correction(&auth)
+            .article_file_name("test-corrected-article.html")
+            .text("+")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        // a draft is simply edited
+        #[rustfmt::skip]
+        ac.correction(&auth)
+            .article_file_name("test-draft-correction.html")
+            .text("Not+needed.")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.correction(&auth)
+            .article_file_name("test-unknown-correction.html")
+            .text("Unknown.")
+            .execute().await?
+                .must_see_response(StatusCode::NOT_FOUND)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        let contributor = ac.login()
+            .username("user26c")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.correction(&contributor)
+            .article_file_name("test-corrected-article.html")
+            .text("Contributor+fix.")
+            .execute().await?
+                .must_see_response(StatusCode::FORBIDDEN)
+                .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/opravy.html")?;
+        trust::me::remove_file("web/test-corrected-article.html")?;
+        for name in ["test-corrected-article", "test-draft-correction"] {
+            for size in ["50", "288", "440", "820"] {
+                trust::me::remove_file(&format!("web/u/{}_image_{}.png", name, size))?;
+            }
+        }
+        Ok(())
+    }
+}
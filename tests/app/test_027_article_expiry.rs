@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+    use chrono::{TimeZone, Utc};
+
+    #[tokio::test]
+    async fn test_article_expiry() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user27")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user27")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // expiry must be in the future
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Sponsored Past")
+            .author("Editor")
+            .category("finance")
+            .text("Sponsored text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .expires_at("2020-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Sponsored Piece")
+            .author("Editor")
+            .category("finance")
+            .text("Sponsored text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .expires_at("2099-01-01T10:00")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Sponsored Neighbour")
+            .author("Editor")
+            .category("finance")
+            .text("Neighbour text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .related_articles("test-sponsored-piece.html")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-sponsored-piece.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Sponsored text.")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/finance.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Sponsored Piece")
+            .verify().await?;
+
+        assert_eq!(ac.worker().expire_articles(Utc::now()).await?, 0);
+
+        let after_expiry = Utc.with_ymd_and_hms(2099, 1, 1, 10, 0, 0).unwrap();
+        assert_eq!(ac.worker().expire_articles(after_expiry).await?, 1);
+        assert_eq!(ac.worker().expire_articles(after_expiry).await?, 0);
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-sponsored-piece.html").await?
+            .must_see_response(StatusCode::GONE)
+            .body_contains("Obsah již není dostupný")
+            .verify().await?;
+
+        // the related Article is rendered again, without the expired one
+        #[rustfmt::skip]
+        ac.web().get_url("/test-sponsored-neighbour.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Neighbour text.")
+            .verify().await?;
+        let neighbour = std::fs::read_to_string("web/test-sponsored-neighbour.html")?;
+        assert!(!neighbour.contains("Test Sponsored Piece"));
+
+        #[rustfmt::skip]
+        ac.account().get(&auth).await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("staženo, platnost vypršela")
+            .verify().await?;
+
+        // Cleanup
+        for name in ["test-sponsored-piece", "test-sponsored-neighbour"] {
+            trust::me::remove_file(&format!("web/{}.html", name))?;
+            for size in ["50", "288", "440", "820"] {
+                trust::me::remove_file(&format!("web/u/{}_image_{}.png", name, size))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_related_suggestions() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user28")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user28")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Brewery Yeast")
+            .author("Author")
+            .category("republika")
+            .text("The brewery ferments lager with its own yeast.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Sunny Weekend")
+            .author("Author")
+            .category("republika")
+            .text("Meteorologists expect sunshine everywhere.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.suggest_related(&auth)
+            .title("New+brewery")
+            .text("Lager+and+yeast+shortage.")
+            .execute().await?
+                .must_see_response(StatusCode::OK)
+                .body_contains("\"article_file_name\":\"test-brewery-yeast.html\"")
+                .body_contains("\"title\":\"Test Brewery Yeast\"")
+                .verify().await?;
+
+        // the edited Article is not related to itself, the other one has nothing in common
+        #[rustfmt::skip]
+        ac.suggest_related(&auth)
+            .title("New+brewery")
+            .text("Lager+and+yeast+shortage.")
+            .article_file_name("test-brewery-yeast.html")
+            .execute().await?
+                .must_see_response(StatusCode::OK)
+                .body("[]")
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Known Related")
+            .author("Author")
+            .category("republika")
+            .text("Text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .related_articles("test-brewery-yeast.html")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-known-related.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Brewery Yeast")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-brewery-yeast.html").await?
+            .must_see_response(StatusCode::OK)
+            .verify().await?;
+
+        // every related article must exist
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Unknown Related")
+            .author("Author")
+            .category("republika")
+            .text("Text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .related_articles("test-brewery-yeast.html,test-no-such-article.html")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-known-related.html")?;
+        trust::me::remove_file("web/test-brewery-yeast.html")?;
+        for name in ["test-brewery-yeast", "test-sunny-weekend", "test-known-related"] {
+            for size in ["50", "288", "440", "820"] {
+                trust::me::remove_file(&format!("web/u/{}_image_{}.png", name, size))?;
+            }
+        }
+        Ok(())
+    }
+}
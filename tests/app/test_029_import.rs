@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+    use std::fs;
+
+    const OLD_MD: &str = "---
+title: Test Imported Old
+author: Jan Import
+username: jimport
+category: republika
+created: 2019-05-01T10:00:00Z
+short_text: Short text of the old article.
+image: old.png
+image_desc: old image
+related_articles: test-imported-json.html, test-imported-later.html
+---
+The old text, first paragraph.
+
+The second paragraph.
+";
+
+    const JSON: &str = r#"{
+        "title": "Test Imported Json",
+        "author": "Eva Import",
+        "category": "zahranici",
+        "created": "2019-06-02T08:30:00Z",
+        "text": "The json text.",
+        "short_text": "Short text of the json article.",
+        "image": "json.jpg",
+        "image_desc": "json image"
+    }"#;
+
+    // the same title, another Article of the previous system
+    const SAME_TITLE_JSON: &str = r#"{
+        "source_id": "old-7",
+        "title": "Test Imported Json",
+        "author": "Eva Import",
+        "category": "zahranici",
+        "created": "2020-01-02T08:30:00Z",
+        "text": "Another json text.",
+        "short_text": "Short text of another json article.",
+        "image": "json.jpg",
+        "image_desc": "json image"
+    }"#;
+
+    const ESCAPE_MD: &str = "---
+title: Test Imported Escape
+author: Jan Import
+category: republika
+created: 2019-08-04T10:00:00Z
+short_text: Short.
+image: ../old.png
+image_desc: escape
+---
+Text.
+";
+
+    // a footnote without a source
+    const FOOTNOTE_MD: &str = "---
+title: Test Imported Footnote
+author: Jan Import
+category: republika
+created: 2019-08-06T10:00:00Z
+short_text: Short.
+image: old.png
+image_desc: footnote
+---
+Inflation rose[^1].
+";
+
+    const LATER_MD: &str = "---
+title: Test Imported Later
+author: Jan Import
+category: republika
+created: 2019-09-05T10:00:00Z
+short_text: Short text of the later article.
+image: old.png
+image_desc: later
+---
+Exported after the first import.
+";
+
+    const BROKEN_MD: &str = "---
+title: Test Imported Broken
+author: Jan Import
+category: no-such-category
+created: 2019-07-03T10:00:00Z
+short_text: Short.
+image: old.png
+image_desc: broken
+---
+Text.
+";
+
+    #[tokio::test]
+    async fn test_import() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        let dir = std::env::temp_dir().join("axiomatik-test-029-import");
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("a-old.md"), OLD_MD)?;
+        fs::write(dir.join("b-json.json"), JSON)?;
+        fs::write(dir.join("c-broken.md"), BROKEN_MD)?;
+        fs::write(dir.join("d-escape.md"), ESCAPE_MD)?;
+        fs::write(dir.join("e-same-title.json"), SAME_TITLE_JSON)?;
+        fs::write(dir.join("e-w-footnote.md"), FOOTNOTE_MD)?;
+        fs::write(dir.join("notes.txt"), "not a bundle")?;
+        fs::copy("tests/data/image_1024.png", dir.join("old.png"))?;
+        fs::copy("tests/data/image_1024.jpg", dir.join("json.jpg"))?;
+        let dir_name = dir.to_string_lossy().to_string();
+
+        let report = ac.command().import(&dir_name).await?;
+        assert_eq!(
+            report.imported,
+            ["test-imported-old.html", "test-imported-json.html", "test-imported-json-2.html"]
+        );
+        assert!(report.skipped.is_empty());
+        assert_eq!(report.failed.len(), 4);
+        assert_eq!(report.failed[0].0, "c-broken.md");
+        assert_eq!(report.failed[1].0, "d-escape.md");
+        assert!(report.failed[1].1.contains("outside of the bundle directory"));
+        assert_eq!(report.failed[2].0, "e-w-footnote.md");
+        assert!(report.failed[2].1.contains("invalid text"));
+        // the later article isn't there yet
+        assert_eq!(report.failed[3].0, "a-old.md");
+        assert!(report.failed[3].1.contains("test-imported-later.html"));
+
+        // already imported articles are skipped, their related articles are linked again
+        fs::write(dir.join("f-later.md"), LATER_MD)?;
+        let report = ac.command().import(&dir_name).await?;
+        assert_eq!(report.imported, ["test-imported-later.html"]);
+        assert_eq!(
+            report.skipped,
+            ["test-imported-old.html", "test-imported-json.html", "test-imported-json-2.html"]
+        );
+        assert_eq!(report.failed.len(), 3);
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-imported-old.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Jan Import")
+            .body_contains("The second paragraph.")
+            // related both ways, in any order of the bundles
+            .body_contains("Test Imported Json")
+            .body_contains("Test Imported Later")
+            // the previous system didn't track changes
+            .body_not_contains("aktualizováno")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-imported-later.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Imported Old")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-imported-json-2.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Another json text.")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-imported-json.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Imported Old")
+            .verify().await?;
+
+        // the original date is kept
+        #[rustfmt::skip]
+        ac.web().get_url("/archiv/2019/05/01.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Imported Old")
+            .verify().await?;
+
+        // Cleanup
+        fs::remove_dir_all(&dir)?;
+        trust::me::remove_file("web/archiv/2019/05/01.html")?;
+        trust::me::remove_file("web/test-imported-old.html")?;
+        trust::me::remove_file("web/test-imported-json.html")?;
+        trust::me::remove_file("web/test-imported-json-2.html")?;
+        trust::me::remove_file("web/test-imported-later.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-imported-old_image_{}.png", size))?;
+            trust::me::remove_file(&format!("web/u/test-imported-json_image_{}.jpg", size))?;
+            trust::me::remove_file(&format!("web/u/test-imported-json-2_image_{}.jpg", size))?;
+            trust::me::remove_file(&format!("web/u/test-imported-later_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
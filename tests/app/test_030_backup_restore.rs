@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::system::backup::BackupError;
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+    use std::fs;
+
+    #[tokio::test]
+    async fn test_backup_restore() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user30")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user30")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Backup Article")
+            .author("Author")
+            .category("republika")
+            .text("Text of the backed up article.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // media of this test only, web/u is shared by all tests
+        let dir = std::env::temp_dir().join("axiomatik-test-030-backup");
+        let media = dir.join("media");
+        let restored_media = dir.join("restored-media");
+        fs::create_dir_all(media.join("2019"))?;
+        fs::copy("tests/data/image_1024.png", media.join("2019/backup.png"))?;
+        let archive = dir.join("backup.tar.gz").to_string_lossy().to_string();
+
+        let manifest = ac.command().backup(&archive, &media.to_string_lossy()).await?;
+        assert_eq!(manifest.files.len(), 2);
+        assert!(manifest.files.iter().any(|file| file.path == "media/2019/backup.png"));
+
+        // only into an empty instance
+        let result = ac.command().restore(&archive, &restored_media.to_string_lossy()).await;
+        assert!(matches!(result, Err(TrustError::TrustBackup(BackupError::NotEmpty))));
+
+        let restored = AppController::new().await?;
+        restored.command().restore(&archive, &restored_media.to_string_lossy()).await?;
+        assert_eq!(fs::read(restored_media.join("2019/backup.png"))?, fs::read("tests/data/image_1024.png")?);
+
+        // users and articles are back, the page is rendered again
+        #[rustfmt::skip]
+        restored.login()
+            .username("user30")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        restored.web().get_url("/test-backup-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Test Backup Article")
+            .verify().await?;
+
+        // a damaged archive isn't restored
+        fs::write(&archive, "not an archive")?;
+        let empty = AppController::new().await?;
+        assert!(empty.command().restore(&archive, &restored_media.to_string_lossy()).await.is_err());
+
+        // Cleanup
+        fs::remove_dir_all(&dir)?;
+        trust::me::remove_file("web/test-backup-article.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-backup-article_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_article_markup() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user31")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user31")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Markup Target")
+            .author("Author")
+            .category("republika")
+            .text("Target text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Markup Source")
+            .author("Author")
+            .category("republika")
+            .text("## Heading\n\n**bold** and [[test-markup-target]]\n\n- first\n- second\n\n<script>x</script>")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-markup-source.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("<h2>Heading</h2>")
+            .body_contains("<strong>bold</strong>")
+            .body_contains("<a href=\"/test-markup-target.html\">Test Markup Target</a>")
+            .body_contains("<ul><li>first</li><li>second</li></ul>")
+            .body_contains("&lt;script&gt;x&lt;/script&gt;")
+            .verify().await?;
+
+        // a link to a missing Article isn't saved
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Markup Broken")
+            .author("Author")
+            .category("republika")
+            .text("See [[test-markup-missing]]")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-markup-source.html")?;
+        for name in ["test-markup-target", "test-markup-source"] {
+            for size in ["50", "288", "440", "820"] {
+                trust::me::remove_file(&format!("web/u/{}_image_{}.png", name, size))?;
+            }
+        }
+        Ok(())
+    }
+}
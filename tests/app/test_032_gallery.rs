@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_gallery() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user32")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user32")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Gallery Article")
+            .author("Author")
+            .category("republika")
+            .text("First paragraph.\n\n[[image:1]]\n\nSecond paragraph.\n\n[[gallery:1,2]]")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .gallery_image_any_png(1, "The first image", "Jan Novák", "ČTK")?
+            .gallery_image_any_png(2, "The second image", "Reuters", "Reuters")?
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        for size in ["50", "288", "440", "820"] {
+            trust::me::path_exists(&format!("web/u/test-gallery-article_gallery_1_image_{}.png", size))?;
+            trust::me::path_exists(&format!("web/u/test-gallery-article_gallery_2_image_{}.png", size))?;
+        }
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-gallery-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("<img src=\"u/test-gallery-article_gallery_1_image_820.png\" alt=\"The first image\"")
+            .body_contains("<span class=\"image-credit\">Foto: Jan Novák, ČTK</span>")
+            .body_contains("The second image <span class=\"image-credit\">Foto: Reuters</span>")
+            .body_contains("<div class=\"gallery\">")
+            .body_contains("<span class=\"gallery-count\">2 / 2</span> The second image")
+            .verify().await?;
+
+        // the second image removed, the first one keeps its file
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-gallery-article.html")
+            .title("Test Gallery Article")
+            .author("Author")
+            .category("republika")
+            .text("First paragraph.\n\n[[image:1]]")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .gallery_caption(1, "The renamed image", "Jan Novák", "ČTK")
+            .gallery_remove(2)
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        trust::me::path_exists("web/u/test-gallery-article_gallery_1_image_820.png")?;
+        trust::me::path_doesnt_exists("web/u/test-gallery-article_gallery_2_image_820.png")?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-gallery-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("The renamed image")
+            .body_not_contains("gallery_2")
+            .verify().await?;
+
+        // the text may place only images of the article
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-gallery-article.html")
+            .title("Test Gallery Article")
+            .author("Author")
+            .category("republika")
+            .text("[[image:2]]")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-gallery-article.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-gallery-article_image_{}.png", size))?;
+            trust::me::remove_file(&format!("web/u/test-gallery-article_gallery_1_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
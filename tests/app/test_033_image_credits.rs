@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::db::database_user::Role;
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_image_credits() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user33")
+            .password("password")
+            .role(Role::Admin)
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user33")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Credited Article")
+            .author("Author")
+            .category("technologie")
+            .text("First paragraph.\n\n[[image:1]]")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("Bridge at dawn")
+            .image_alt("Stone bridge over a river, morning fog")
+            .image_credit("Jan Novák", "ČTK")
+            .gallery_image_any_png(1, "Detail", "Reuters", "Reuters")?
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-credited-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("alt=\"Stone bridge over a river, morning fog\"")
+            .body_contains("<span class=\"image-credit\">Foto: Jan Novák, ČTK</span>")
+            .body_contains("Detail <span class=\"image-credit\">Foto: Reuters</span>")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/technologie.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("alt=\"Stone bridge over a river, morning fog\"")
+            .body_contains("<span class=\"image-credit thumb-credit\">Foto: Jan Novák, ČTK</span>")
+            .verify().await?;
+
+        // published this month, the main image and the gallery image
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/admin_image_sources").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Celkem obrázků: 2")
+            .body_contains("<td>ČTK</td>")
+            .body_contains("<td>Reuters</td>")
+            .body_contains("<td>Jan Novák</td>")
+            .body_contains("test-credited-article.html")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/admin_image_sources?month=2019-01").await?
+            .must_see_response(StatusCode::OK)
+            .body_not_contains("test-credited-article.html")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/admin_image_sources?month=leden").await?
+            .must_see_response(StatusCode::BAD_REQUEST)
+            .verify().await?;
+
+        // the image must be described for readers who don't see it
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Undescribed Article")
+            .author("Author")
+            .category("technologie")
+            .text("Text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_credit("Jan Novák", "ČTK")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        trust::me::path_doesnt_exists("web/u/test-undescribed-article_image_820.png")?;
+
+        // the agency must be credited
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Uncredited Article")
+            .author("Author")
+            .category("technologie")
+            .text("Text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("Stone bridge over a river")
+            .image_credit("", "ČTK")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        trust::me::path_doesnt_exists("web/u/test-uncredited-article_image_820.png")?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-credited-article.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-credited-article_image_{}.png", size))?;
+            trust::me::remove_file(&format!("web/u/test-credited-article_gallery_1_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
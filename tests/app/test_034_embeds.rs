@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_embeds() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user34")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user34")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Embed Article")
+            .author("Author")
+            .category("veda")
+            .text("Video.\n\n{{youtube:dQw4w9WgXcQ}}\n\n{{map:50.0875,14.4213,12}}\n\nNot an embed {{x:1}} inline.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // nothing of the provider is loaded before the click
+        #[rustfmt::skip]
+        ac.web().get_url("/test-embed-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("<div class=\"embed embed-youtube\"")
+            .body_contains("data-embed-src=\"https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ\"")
+            .body_contains("<div class=\"embed embed-map\"")
+            .body_contains("Not an embed {{x:1}} inline.")
+            .body_contains("js/embed.js")
+            .body_not_contains("<iframe")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Embed Invalid")
+            .author("Author")
+            .category("veda")
+            .text("Video.\n\n{{youtube:\"onload=\"alert(1)}}")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Embed Unknown")
+            .author("Author")
+            .category("veda")
+            .text("Video.\n\n{{vimeo:123456}}")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        trust::me::path_doesnt_exists("web/test-embed-invalid.html")?;
+        trust::me::path_doesnt_exists("web/test-embed-unknown.html")?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-embed-article.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-embed-article_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
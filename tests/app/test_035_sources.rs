@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_sources() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user35")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user35")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Sourced Article")
+            .author("Author")
+            .category("finance")
+            .text("Inflation rose[^1] while wages fell[^2].\n\nAs reported before[^1].")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .source(1, "Inflace, spotřebitelské ceny", "https://www.czso.cz/csu/czso/inflace", "2025-03-01")
+            .source(2, "Průměrné mzdy", "https://www.czso.cz/csu/czso/mzdy", "2025-03-02")
+            .source(3, "Zpráva o měnové politice", "https://www.cnb.cz/cs/menova-politika/", "2025-03-03")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // a source without a footnote is listed too, without a back-link
+        #[rustfmt::skip]
+        ac.web().get_url("/test-sourced-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\">1</a></sup>")
+            .body_contains("<sup class=\"footnote-ref\"><a href=\"#fn-1\">1</a></sup>")
+            .body_contains("<h3>Zdroje</h3>")
+            .body_contains("<li id=\"fn-2\" value=\"2\">")
+            .body_contains("href=\"https://www.czso.cz/csu/czso/mzdy\"")
+            .body_contains("navštíveno 2. 3. 2025")
+            .body_contains("<a href=\"#fnref-2\" class=\"footnote-back\"")
+            .body_contains("Zpráva o měnové politice")
+            .body_not_contains("#fnref-3")
+            .verify().await?;
+
+        // the form sends all sources, the second one was removed
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-sourced-article.html")
+            .title("Test Sourced Article")
+            .author("Author")
+            .category("finance")
+            .text("Inflation rose[^1].")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .source(1, "Inflace, spotřebitelské ceny", "https://www.czso.cz/csu/czso/inflace", "2025-03-01")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-sourced-article.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("<li id=\"fn-1\" value=\"1\">")
+            .body_not_contains("Průměrné mzdy")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.edit_article(&auth)
+            .article_file_name("test-sourced-article.html")
+            .title("Test Sourced Article")
+            .author("Author")
+            .category("finance")
+            .text("Inflation rose[^1] while wages fell[^2].")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .source(1, "Inflace, spotřebitelské ceny", "https://www.czso.cz/csu/czso/inflace", "2025-03-01")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Sourced Invalid")
+            .author("Author")
+            .category("finance")
+            .text("Claim[^1].")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .source(1, "Evil", "javascript:alert(1)", "2025-03-01")
+            .execute().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+
+        trust::me::path_doesnt_exists("web/test-sourced-invalid.html")?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-sourced-article.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-sourced-article_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
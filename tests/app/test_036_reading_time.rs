@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::db::database_user::Role;
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_reading_time() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user36")
+            .password("password")
+            .role(Role::Admin)
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user36")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // 450 words
+        let text = vec!["Slovo a ještě jedno slovo."; 90].join(" ");
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Reading Time")
+            .author("Author")
+            .category("veda")
+            .text(&text)
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web().get_url("/test-reading-time.html").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("<span class=\"reading-time\">3 min čtení</span>")
+            .body_not_contains("aktualizováno")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/admin_article?sort=words").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("<td>450</td>")
+            .body_contains("<td>3 min čtení</td>")
+            .body_contains("href=\"/admin_article?sort=words&#38;order=asc\">Slov ▼</a>")
+            .body_contains("href=\"/admin_article?sort=title&#38;order=asc\">Titulek</a>")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/admin_article?sort=title&order=desc").await?
+            .must_see_response(StatusCode::OK)
+            .body_contains("Titulek ▼")
+            .verify().await?;
+
+        #[rustfmt::skip]
+        ac.web_app(&auth).get_url("/admin_article?sort=password").await?
+            .must_see_response(StatusCode::BAD_REQUEST)
+            .verify().await?;
+
+        // Cleanup
+        trust::me::remove_file("web/test-reading-time.html")?;
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-reading-time_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
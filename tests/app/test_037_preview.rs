@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use axiomatik_web::trust;
+    use axiomatik_web::trust::app_controller::AppController;
+    use axiomatik_web::trust::me::TrustError;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_preview() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user37")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth = ac.login()
+            .username("user37")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Preview Article")
+            .author("Author")
+            .category("veda")
+            .text("Preview text[^1].")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .source(1, "Preview source", "https://www.czso.cz/csu/czso/inflace", "2025-03-01")
+            .preview().await?
+                .must_see_response(StatusCode::OK)
+                .body_contains("Test Preview Article")
+                .body_contains("Preview source")
+                .body_contains("data:image/png;base64,")
+                .verify().await?;
+
+        // nothing was stored
+        ac.db_article().must_not_see("test-preview-article.html").await?.verify()?;
+        trust::me::path_doesnt_exists("web/test-preview-article.html")?;
+        trust::me::path_doesnt_exists("web/u/test-preview-article_image_820.png")?;
+
+        // validated as the form
+        #[rustfmt::skip]
+        ac.create_article(&auth)
+            .title("Test Preview Article")
+            .author("Author")
+            .category("no-such-category")
+            .text("Preview text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .preview().await?
+                .must_see_response(StatusCode::BAD_REQUEST)
+                .verify().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_preview_edit() -> Result<(), TrustError> {
+        let ac = AppController::new().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user37a")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        ac.db_user().setup_user()
+            .username("user37b")
+            .password("password")
+            .execute().await?;
+
+        #[rustfmt::skip]
+        let auth_a = ac.login()
+            .username("user37a")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        let auth_b = ac.login()
+            .username("user37b")
+            .password("password")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.create_article(&auth_a)
+            .title("Test Preview Edit")
+            .author("Author A")
+            .category("veda")
+            .text("Stored text.")
+            .short_text("Short text.")
+            .image_any_png()?
+            .image_alt("test image alt")
+            .image_credit("Axiomatik", "vlastní")
+            .execute().await?
+                .must_see_response(StatusCode::SEE_OTHER)
+                .verify().await?;
+
+        // the author sees the edit before saving it
+        #[rustfmt::skip]
+        ac.edit_article(&auth_a)
+            .article_file_name("test-preview-edit.html")
+            .title("Test Preview Edit")
+            .author("Author A")
+            .category("veda")
+            .text("Edited text.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .preview().await?
+                .must_see_response(StatusCode::OK)
+                .body_contains("Edited text.")
+                .verify().await?;
+
+        #[rustfmt::skip]
+        ac.db_article().must_see("test-preview-edit.html").await?
+            .text("<div class=\"container\"><p>Stored text.</p></div>")
+            .verify()?;
+
+        // an unknown Article
+        #[rustfmt::skip]
+        ac.edit_article(&auth_a)
+            .article_file_name("test-no-such-article.html")
+            .title("Test Preview Edit")
+            .author("Author A")
+            .category("veda")
+            .text("Edited text.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .preview().await?
+                .must_see_response(StatusCode::NOT_FOUND)
+                .verify().await?;
+
+        // another contributor may not see it
+        #[rustfmt::skip]
+        ac.edit_article(&auth_b)
+            .article_file_name("test-preview-edit.html")
+            .title("Test Preview Edit")
+            .author("Author B")
+            .category("veda")
+            .text("Text of B.")
+            .short_text("Short text.")
+            .image_desc("desc")
+            .image_alt("desc")
+            .image_credit("Axiomatik", "vlastní")
+            .preview().await?
+                .must_see_response(StatusCode::FORBIDDEN)
+                .verify().await?;
+
+        // Cleanup
+        for size in ["50", "288", "440", "820"] {
+            trust::me::remove_file(&format!("web/u/test-preview-edit_image_{}.png", size))?;
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@ pub mod admin {
     pub mod test_001_admin_login;
     pub mod test_002_create_and_delete_user;
     pub mod test_003_delete_article;
+    pub mod test_004_create_category;
+    pub mod test_005_breaking_news;
 }
 pub mod app {
     pub mod test_001_login;
@@ -18,6 +20,29 @@ pub mod app {
     pub mod test_012_create_article_validation;
     pub mod test_013_create_article_zahranici_integration;
     pub mod test_014_create_article_related_articles;
+    pub mod test_015_edit_article;
+    pub mod test_016_publish_states;
+    pub mod test_017_article_revisions;
+    pub mod test_018_rename_article;
+    pub mod test_019_create_article_same_title;
+    pub mod test_020_category_pages;
+    pub mod test_021_archive_pages;
+    pub mod test_022_author_page;
+    pub mod test_023_review_workflow;
+    pub mod test_024_homepage_slots;
+    pub mod test_025_live_blog;
+    pub mod test_026_corrections;
+    pub mod test_027_article_expiry;
+    pub mod test_028_related_suggestions;
+    pub mod test_029_import;
+    pub mod test_030_backup_restore;
+    pub mod test_031_article_markup;
+    pub mod test_032_gallery;
+    pub mod test_033_image_credits;
+    pub mod test_034_embeds;
+    pub mod test_035_sources;
+    pub mod test_036_reading_time;
+    pub mod test_037_preview;
 }
 pub mod db {
     pub mod test_001_db;
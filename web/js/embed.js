@@ -0,0 +1,19 @@
+document.addEventListener('DOMContentLoaded', function () {
+    document.querySelectorAll('.embed').forEach(function (embed) {
+        const button = embed.querySelector('.embed-load');
+        if (!button) {
+            return;
+        }
+        button.addEventListener('click', function () {
+            const iframe = document.createElement('iframe');
+            iframe.src = embed.dataset.embedSrc;
+            iframe.title = embed.dataset.embedTitle;
+            iframe.loading = 'lazy';
+            iframe.referrerPolicy = 'strict-origin-when-cross-origin';
+            iframe.setAttribute('sandbox', 'allow-scripts allow-same-origin allow-popups allow-presentation');
+            iframe.setAttribute('allowfullscreen', '');
+            embed.replaceChildren(iframe);
+            embed.classList.add('embed-loaded');
+        });
+    });
+});
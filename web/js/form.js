@@ -2,6 +2,10 @@ const form = document.getElementById('article-form');
 form.setAttribute('novalidate', true);
 
 form.addEventListener('submit', async function(e) {
+    // preview button posts the form natively into a new tab
+    if (e.submitter && e.submitter.hasAttribute('formaction')) {
+        return;
+    }
     e.preventDefault();
     const form = e.target;
     let isValid = true;
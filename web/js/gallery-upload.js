@@ -0,0 +1,40 @@
+document.addEventListener('DOMContentLoaded', function () {
+    const gallery = document.querySelector('.gallery-upload');
+    if (!gallery) {
+        return;
+    }
+    const rows = gallery.querySelector('.gallery-rows');
+    let nextNumber = parseInt(gallery.dataset.nextNumber, 10) || 1;
+
+    function input(type, name, placeholder) {
+        const element = document.createElement('input');
+        element.type = type;
+        element.name = name;
+        if (type === 'file') {
+            element.accept = 'image/*';
+        } else {
+            element.placeholder = placeholder;
+        }
+        if (name.startsWith('gallery_source_')) {
+            element.setAttribute('list', 'image-sources');
+        }
+        return element;
+    }
+
+    gallery.querySelector('.gallery-add').addEventListener('click', function () {
+        const number = nextNumber++;
+        const row = document.createElement('div');
+        row.className = 'gallery-row';
+        const label = document.createElement('b');
+        label.textContent = number;
+        row.append(
+            label,
+            input('file', 'gallery_image_' + number),
+            input('text', 'gallery_caption_' + number, 'Popisek'),
+            input('text', 'gallery_alt_' + number, 'Alternativní text'),
+            input('text', 'gallery_credit_' + number, 'Autor'),
+            input('text', 'gallery_source_' + number, 'Zdroj')
+        );
+        rows.appendChild(row);
+    });
+});
@@ -0,0 +1,50 @@
+document.addEventListener('DOMContentLoaded', function () {
+    const section = document.querySelector('section.live-blog');
+    if (!section) {
+        return;
+    }
+    const list = section.querySelector('.live-entries');
+    let since = section.dataset.since;
+
+    function entryElement(entry) {
+        const div = document.createElement('div');
+        div.className = 'live-entry';
+
+        const meta = document.createElement('div');
+        meta.className = 'live-entry-meta';
+        meta.textContent = entry.created_display + ' • ' + entry.author;
+        div.appendChild(meta);
+
+        const text = document.createElement('div');
+        text.className = 'live-entry-text';
+        text.textContent = entry.text;
+        div.appendChild(text);
+
+        if (entry.image_path) {
+            const img = document.createElement('img');
+            img.className = 'live-entry-image';
+            img.src = entry.image_path;
+            img.alt = entry.image_desc;
+            div.appendChild(img);
+        }
+        return div;
+    }
+
+    function poll() {
+        fetch(section.dataset.entries + '?since=' + encodeURIComponent(since))
+            .then(response => response.ok ? response.json() : [])
+            .then(entries => {
+                if (entries.length === 0) {
+                    return;
+                }
+                // newest first, the newest one goes on top
+                for (const entry of entries.slice().reverse()) {
+                    list.prepend(entryElement(entry));
+                }
+                since = entries[0].created;
+            })
+            .catch(() => {});
+    }
+
+    setInterval(poll, 30000);
+});
@@ -0,0 +1,52 @@
+document.addEventListener('DOMContentLoaded', function () {
+    const button = document.getElementById('suggest-related');
+    if (!button) {
+        return;
+    }
+    const form = button.form;
+    const related = form.querySelector('textarea[name="related_articles"]');
+    const list = form.querySelector('.related-suggestions');
+
+    function relatedNames() {
+        return related.value.split(/[,\n]/).map(name => name.trim()).filter(name => name !== '');
+    }
+
+    function suggestionElement(suggestion) {
+        const li = document.createElement('li');
+        const link = document.createElement('a');
+        link.href = '#';
+        link.textContent = suggestion.title + ' (' + suggestion.article_file_name + ')';
+        link.addEventListener('click', function (e) {
+            e.preventDefault();
+            const names = relatedNames();
+            if (!names.includes(suggestion.article_file_name)) {
+                names.push(suggestion.article_file_name);
+                related.value = names.join(', ');
+            }
+            li.remove();
+        });
+        li.appendChild(link);
+        return li;
+    }
+
+    button.addEventListener('click', function () {
+        const body = new URLSearchParams();
+        body.set('title', form.querySelector('[name="title"]').value);
+        body.set('text', form.querySelector('[name="text"]').value);
+        if (button.dataset.articleFileName) {
+            body.set('article_file_name', button.dataset.articleFileName);
+        }
+        fetch('/suggest-related', { method: 'POST', body: body })
+            .then(response => response.ok ? response.json() : [])
+            .then(suggestions => {
+                list.replaceChildren();
+                const names = relatedNames();
+                for (const suggestion of suggestions) {
+                    if (!names.includes(suggestion.article_file_name)) {
+                        list.appendChild(suggestionElement(suggestion));
+                    }
+                }
+            })
+            .catch(() => {});
+    });
+});
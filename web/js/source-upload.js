@@ -0,0 +1,35 @@
+document.addEventListener('DOMContentLoaded', function () {
+    const sources = document.querySelector('.source-upload');
+    if (!sources) {
+        return;
+    }
+    const rows = sources.querySelector('.source-rows');
+    let nextNumber = parseInt(sources.dataset.nextNumber, 10) || 1;
+
+    function input(type, name, placeholder) {
+        const element = document.createElement('input');
+        element.type = type;
+        element.name = name;
+        if (placeholder) {
+            element.placeholder = placeholder;
+        }
+        return element;
+    }
+
+    sources.querySelector('.source-add').addEventListener('click', function () {
+        const number = nextNumber++;
+        const row = document.createElement('div');
+        row.className = 'source-row';
+        const label = document.createElement('b');
+        label.textContent = number;
+        const accessed = input('date', 'source_accessed_' + number);
+        accessed.valueAsDate = new Date();
+        row.append(
+            label,
+            input('text', 'source_title_' + number, 'Název'),
+            input('url', 'source_url_' + number, 'https://...'),
+            accessed
+        );
+        rows.appendChild(row);
+    });
+});